@@ -1,12 +1,12 @@
 use std::borrow::Cow;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::{self, Write};
 
 use gl_generator::{Api, Binding, Cmd, Registry};
 use indoc::{indoc, writedoc};
 
-use crate::rename::{rename_function, rename_lib_type, rename_parameter};
-use crate::STRUCT_NAME;
+use crate::rename::{rename_function, rename_group, rename_group_members, rename_lib_type, rename_parameter};
+use crate::{error_checking, tracing, STRUCT_NAME};
 
 
 /// Output raw a `GLenum` for all applicable types in the registry.
@@ -65,6 +65,76 @@ pub fn write_enum_values(registry: &Registry, dest: &mut impl Write) -> io::Resu
 }
 
 
+/// Output a `gl_enum!` or `gl_bitfield!` declaration for each of the registry's groups that are on the allow-list in
+/// [`rename_group`].
+///
+/// Only members that are part of the registry's API version and profile are included. Members with the same value as
+/// one that came before them (usually vendor aliases) are skipped, since enums can't have duplicate discriminants.
+pub fn write_enum_groups(registry: &Registry, dest: &mut impl Write) -> io::Result<()> {
+    // Look up the values of each enum that made it into the registry, so that aliases can be spotted.
+    let values = registry
+        .enums
+        .iter()
+        .map(|e| (e.ident.as_str(), parse_enum_value(&e.value)))
+        .collect::<BTreeMap<_, _>>();
+
+    for group in registry.groups.values() {
+        let Some(type_name) = rename_group(&group.ident) else {
+            continue;
+        };
+
+        let bitmask = group.enums_type.as_deref() == Some("bitmask");
+
+        // Filter out any members that aren't a part of our registry, and sort the rest by value.
+        let mut members = group
+            .enums
+            .iter()
+            .filter_map(|ident| values.get(ident.as_str()).map(|&value| (value, ident.as_str())))
+            .collect::<Vec<_>>();
+
+        members.sort();
+        members.dedup_by_key(|&mut (value, _)| value);
+
+        // Groups can end up empty when all of their values come from later versions or from other extensions.
+        if members.is_empty() {
+            continue;
+        }
+
+        let idents = members.iter().map(|&(_, ident)| ident).collect::<Vec<_>>();
+        let names = rename_group_members(&idents, bitmask);
+
+        writeln!(dest, "{}! {{", if bitmask { "gl_bitfield" } else { "gl_enum" })?;
+        writeln!(dest, "    /// The `{}` group from the OpenGL registry.", group.ident)?;
+
+        if bitmask {
+            writeln!(dest, "    pub struct {type_name} {{")?;
+            for (name, ident) in names.iter().zip(&idents) {
+                writeln!(dest, "        /// `GL_{ident}`")?;
+                writeln!(dest, "        pub const {name} = {ident};")?;
+            }
+        } else {
+            writeln!(dest, "    pub enum {type_name} {{")?;
+            for (name, ident) in names.iter().zip(&idents) {
+                writeln!(dest, "        /// `GL_{ident}`")?;
+                writeln!(dest, "        {name} => {ident},")?;
+            }
+        }
+
+        writeln!(dest, "    }}\n}}\n")?;
+    }
+
+    Ok(())
+}
+
+/// Parses the value of an enum from the registry, which is written either in hex or in decimal.
+fn parse_enum_value(value: &str) -> Option<u64> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+
 /// Output the declaration for the struct of function pointers.
 pub fn write_struct_decl(registry: &Registry, dest: &mut impl Write) -> io::Result<()> {
     writedoc!(
@@ -80,6 +150,14 @@ pub fn write_struct_decl(registry: &Registry, dest: &mut impl Write) -> io::Resu
         writeln!(dest, "    {new_name}: VoidPtr,", new_name = rename_function(&cmd.proto.ident))?;
     }
 
+    if error_checking() {
+        writeln!(dest, "    error_check_mode: ::core::cell::Cell<ErrorCheckMode>,")?;
+    }
+
+    if tracing() {
+        writeln!(dest, "    tracer: ::core::cell::RefCell<Option<Tracer>>,")?;
+    }
+
     writeln!(dest, "}}")?;
     Ok(())
 }
@@ -177,12 +255,23 @@ pub fn write_struct_ctor(registry: &Registry, dest: &mut impl Write) -> io::Resu
         writeln!(dest, "        {new_name}: load_ptr(&mut loader_fn, {load_name}, {fallbacks}, failure_mode)?,")?;
     }
 
+    if error_checking() {
+        writeln!(dest, "        error_check_mode: ::core::cell::Cell::new(ErrorCheckMode::Log),")?;
+    }
+
+    if tracing() {
+        writeln!(dest, "        tracer: ::core::cell::RefCell::new(None),")?;
+    }
+
     writeln!(dest, "    }})")?; // Close Ok(Self {...})
     writeln!(dest, "}}\n}}")?; // Close fn and impl
     Ok(())
 }
 
 /// Write the `impl` block for the function pointer struct, where the raw void-pointer dereferences/calls are performed.
+///
+/// With the `error-checking` feature enabled, each function also checks `glGetError` after making its call. With the
+/// `trace` feature enabled, each function also records its call to the current trace, if there is one.
 pub fn write_struct_impl(registry: &Registry, dest: &mut impl Write) -> io::Result<()> {
     // Doesn't need any `write!` formatting
     let macro_str = indoc! {r#"
@@ -216,7 +305,38 @@ pub fn write_struct_impl(registry: &Registry, dest: &mut impl Write) -> io::Resu
             write!(dest, " -> {ret_type}")?;
         }
 
-        writeln!(dest, " {{ ({fn_cast})({args}) }}")?;
+        // `glGetError` can't check itself without recursing forever. It isn't traced either, since error checking
+        // calls it after every other function.
+        let checking = error_checking() && cmd.proto.ident != "GetError";
+        let tracing = tracing() && cmd.proto.ident != "GetError";
+
+        if !checking && !tracing {
+            writeln!(dest, " {{ ({fn_cast})({args}) }}")?;
+            continue;
+        }
+
+        writeln!(dest, " {{")?;
+        if ret_type != "()" {
+            writeln!(dest, "        let result = ({fn_cast})({args});")?;
+        } else {
+            writeln!(dest, "        ({fn_cast})({args});")?;
+        }
+
+        // Calls are traced after they are made, so that the names written by `glGen*` functions can be recorded.
+        if tracing {
+            write_trace_call(cmd, dest)?;
+        }
+
+        if checking {
+            let gl_name = format!("gl{}", cmd.proto.ident);
+            let describe = make_call_description(&gl_name, &cmd.params);
+            writeln!(dest, "        self.check_errors(|| {describe});")?;
+        }
+
+        if ret_type != "()" {
+            writeln!(dest, "        result")?;
+        }
+        writeln!(dest, "    }}")?;
     }
 
     writeln!(dest, "}}")?; // Close impl
@@ -243,6 +363,22 @@ fn make_args(bindings: &[Binding]) -> String {
         .join(", ")
 }
 
+/// Creates an expression that formats a call to the given function with the values of its arguments, like
+/// `glBindBuffer(target: 34962, buffer: 1)`, for error messages.
+fn make_call_description(gl_name: &str, bindings: &[Binding]) -> String {
+    let params = bindings
+        .iter()
+        .map(|binding| format!("{}: {{:?}}", rename_parameter(&binding.ident)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if bindings.is_empty() {
+        format!("String::from(\"{gl_name}()\")")
+    } else {
+        format!("format!(\"{gl_name}({params})\", {args})", args = make_args(bindings))
+    }
+}
+
 fn make_fn_cast(cmd: &Cmd) -> String {
     let ident = rename_function(&cmd.proto.ident);
     let ret_ty = rename_lib_type(&cmd.proto.ty);
@@ -254,3 +390,652 @@ fn make_fn_cast(cmd: &Cmd) -> String {
         .join(", ");
     format!("cast!(self.{ident}({params}) -> {ret_ty})")
 }
+
+
+/// The types of output parameters that the `mock` module knows how to write query results to.
+const QUERY_OUTPUT_TYPES: &[&str] = &[
+    "GLint",
+    "GLint64",
+    "GLuint",
+    "GLuint64",
+    "GLenum",
+    "GLfloat",
+    "GLdouble",
+    "GLboolean",
+];
+
+/// Output a stub `extern "system"` function for every command in the registry, along with a `lookup` function that
+/// finds them by symbol name, for the `mock` module.
+///
+/// Each stub records its call and decoded arguments, then hands off to the helpers in `mock` to fill in object names,
+/// query results, and return values. Those helpers are expected to be in scope wherever this file is included.
+pub fn write_mock_stubs(registry: &Registry, dest: &mut impl Write) -> io::Result<()> {
+    for cmd in &registry.cmds {
+        let gl_name = format!("gl{}", cmd.proto.ident);
+        let ret_type = rename_lib_type(&cmd.proto.ty);
+
+        let params = make_params(&cmd.params);
+        let ret = if ret_type == "()" { String::new() } else { format!(" -> {ret_type}") };
+
+        writeln!(dest, "extern \"system\" fn {gl_name}({params}){ret} {{")?;
+
+        let args = cmd
+            .params
+            .iter()
+            .map(|binding| {
+                let ident = rename_parameter(&binding.ident);
+                format!("(\"{ident}\", {})", make_mock_arg(ident, rename_lib_type(&binding.ty)))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(dest, "    record(\"{gl_name}\", vec![{args}]);")?;
+
+        let find_param = |name: &str| cmd.params.iter().find(|b| rename_parameter(&b.ident) == name);
+        let last = cmd.params.last().map(|b| (rename_parameter(&b.ident), rename_lib_type(&b.ty)));
+
+        // `glGen*` and `glCreate*` functions that write an array of new object names
+        let is_gen = cmd.proto.ident.starts_with("Gen") || cmd.proto.ident.starts_with("Create");
+        if let (true, Some(_), Some((names, "*mut GLuint"))) = (is_gen, find_param("n"), last) {
+            writeln!(dest, "    unsafe {{ fill_names(n, {names}) }};")?;
+        }
+
+        // Queries that write their results to an output pointer. Indexed `glGet*` functions call their `pname`
+        // parameter `target`, for some reason.
+        let is_indexed_get = cmd.proto.ident.starts_with("Get") && cmd.proto.ident.ends_with("i_v");
+        let query_param = find_param("pname").or(if is_indexed_get { find_param("target") } else { None });
+        if let (Some(query), Some((output, ty))) = (query_param, last) {
+            let query = rename_parameter(&query.ident);
+            if QUERY_OUTPUT_TYPES.iter().any(|w| ty.strip_prefix("*mut ") == Some(w)) {
+                // Some queries say how much room their output has, others write a fixed number of values per `pname`
+                let len = match (find_param("buf_size"), find_param("uniform_count")) {
+                    (Some(_), _) => "Some(buf_size.max(0) as usize)",
+                    (_, Some(_)) => "Some(uniform_count.max(0) as usize)",
+                    _ => "None",
+                };
+                writeln!(dest, "    unsafe {{ write_query_result({query}, {output}, {len}) }};")?;
+            }
+        }
+
+        // Program resource queries, which write one value for each property in an array of them
+        if let (Some(_), Some(_), Some((output, "*mut GLint"))) = (find_param("props"), find_param("buf_size"), last) {
+            writeln!(dest, "    unsafe {{ write_property_results(props, prop_count, {output}, buf_size) }};")?;
+        }
+
+        match ret_type {
+            "()" => {},
+            "*const GLubyte" => {
+                let index = if find_param("index").is_some() { "Some(index)" } else { "None" };
+                writeln!(dest, "    get_string(name, {index})")?;
+            },
+            "*mut c_void" => match find_param("length") {
+                Some(_) => writeln!(dest, "    map_buffer(length as usize)")?,
+                None => writeln!(dest, "    ::core::ptr::null_mut()")?,
+            },
+            _ => writeln!(dest, "    respond(\"{gl_name}\")")?,
+        }
+
+        writeln!(dest, "}}\n")?;
+    }
+
+    // Then the function that `GLContext::init` uses to find them
+    writeln!(dest, "/// Finds the stub for the OpenGL function with the given name.")?;
+    writeln!(dest, "pub(super) fn lookup(name: &str) -> *const c_void {{")?;
+    writeln!(dest, "    match name {{")?;
+    for cmd in &registry.cmds {
+        writeln!(dest, "        \"gl{0}\" => gl{0} as *const c_void,", cmd.proto.ident)?;
+    }
+    writeln!(dest, "        _ => ::core::ptr::null(),")?;
+    writeln!(dest, "    }}")?;
+    writeln!(dest, "}}\n")?;
+
+    write_enum_names(registry, dest)
+}
+
+/// Creates an expression that converts a parameter of the given type into a `mock::Arg`.
+fn make_mock_arg(ident: &str, ty: &str) -> String {
+    if let Some(scalar) = make_scalar_value("Arg", ident, ty) {
+        return scalar;
+    }
+
+    match ty {
+        // Names passed to functions like `glGetUniformLocation` are always NUL-terminated, unlike most other strings.
+        "*const GLchar" if ident == "name" => format!("unsafe {{ Arg::c_str({ident}) }}"),
+        "types::GLDebugProc" => format!("Arg::Ptr({ident}.map_or(0, |f| f as usize))"),
+        _ => format!("Arg::Ptr({ident} as usize)"),
+    }
+}
+
+/// Creates an expression that converts a scalar parameter of the given type into a variant of `mock::Arg` or
+/// `trace::Value`, which have the same variants for scalars. Returns `None` if the type is not a scalar.
+fn make_scalar_value(enum_name: &str, ident: &str, ty: &str) -> Option<String> {
+    Some(match ty {
+        "GLenum" => format!("{enum_name}::Enum({ident})"),
+        "GLbitfield" => format!("{enum_name}::Bitfield({ident})"),
+        "GLboolean" => format!("{enum_name}::Bool({ident} != 0)"),
+        "GLdouble" => format!("{enum_name}::Float({ident})"),
+        "GLfloat" => format!("{enum_name}::Float({ident} as f64)"),
+        "GLint64" => format!("{enum_name}::Int({ident})"),
+        "GLbyte" | "GLshort" | "GLint" | "GLsizei" | "GLintptr" | "GLsizeiptr" => {
+            format!("{enum_name}::Int({ident} as i64)")
+        },
+        "GLuint64" => format!("{enum_name}::UInt({ident})"),
+        "GLubyte" | "GLushort" | "GLuint" => format!("{enum_name}::UInt({ident} as u64)"),
+        _ => return None,
+    })
+}
+
+/// Output a function that finds the name of a `GLenum` value, for values that belong to exactly one name. Values with
+/// more than one name (like `0`, which is `GL_NONE`, `GL_ZERO`, `GL_POINTS`, and more) are left out.
+fn write_enum_names(registry: &Registry, dest: &mut impl Write) -> io::Result<()> {
+    let grouped = registry
+        .groups
+        .values()
+        .filter(|group| group.enums_type.is_none())
+        .flat_map(|group| group.enums.iter().map(|e| e.as_str()))
+        .collect::<BTreeSet<_>>();
+
+    let mut names = BTreeMap::<u64, Vec<&str>>::new();
+    for e in &registry.enums {
+        if e.ty != "GLenum" || !grouped.contains(e.ident.as_str()) {
+            continue;
+        }
+
+        if let Some(value) = parse_enum_value(&e.value) {
+            names.entry(value).or_default().push(&e.ident);
+        }
+    }
+
+    writeln!(dest, "/// Finds the name of a `GLenum` value, if it only has one.")?;
+    writeln!(dest, "pub(super) fn enum_name(value: GLenum) -> Option<&'static str> {{")?;
+    writeln!(dest, "    match value {{")?;
+    for (value, idents) in names.iter().filter(|(_, idents)| idents.len() == 1) {
+        writeln!(dest, "        {value:#06X} => Some(\"GL_{}\"),", idents[0])?;
+    }
+    writeln!(dest, "        _ => None,")?;
+    writeln!(dest, "    }}")?;
+    writeln!(dest, "}}")?;
+
+    Ok(())
+}
+
+
+/// Output the statements that record a call to the current trace, for the `trace` feature. They go at the end of each
+/// function in [`write_struct_impl`], after the call has been made.
+fn write_trace_call(cmd: &Cmd, dest: &mut impl Write) -> io::Result<()> {
+    let gl_name = format!("gl{}", cmd.proto.ident);
+    let ret_type = rename_lib_type(&cmd.proto.ty);
+    let ret = match ret_type {
+        "()" => "None".to_string(),
+        "GLsync" => "Some(Value::Ptr(result as usize as u64))".to_string(),
+        "*const GLubyte" => "Some(trace::c_str(result.cast()))".to_string(),
+        ty => match make_scalar_value("Value", "result", ty) {
+            Some(value) => format!("Some({value})"),
+            None => "Some(Value::Ptr(result as usize as u64))".to_string(),
+        },
+    };
+
+    writeln!(dest, "        if let Some(mut tracer) = self.tracer() {{")?;
+    if cmd.params.is_empty() {
+        writeln!(dest, "            tracer.call(\"{gl_name}\", &[], {ret});")?;
+    } else {
+        let args = cmd
+            .params
+            .iter()
+            .map(|binding| make_trace_value(cmd, rename_parameter(&binding.ident), rename_lib_type(&binding.ty)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(dest, "            let args = [{args}];")?;
+        writeln!(dest, "            tracer.call(\"{gl_name}\", &args, {ret});")?;
+    }
+    writeln!(dest, "        }}")?;
+
+    Ok(())
+}
+
+/// Creates an expression that records a parameter of the given type as a `trace::Value`.
+fn make_trace_value(cmd: &Cmd, ident: &str, ty: &str) -> String {
+    // Shader sources are recorded as if they were passed as a single NUL-terminated string.
+    if is_shader_source(cmd) {
+        match ident {
+            "count" => return "Value::Int(1)".to_string(),
+            "length" => return "Value::Ptr(0)".to_string(),
+            _ => {},
+        }
+    }
+
+    if let Some(scalar) = make_scalar_value("Value", ident, ty) {
+        return scalar;
+    }
+
+    let pointee = ty.strip_prefix("*const ").or(ty.strip_prefix("*mut ")).unwrap_or("u8");
+    match classify_pointer(cmd, ident, ty) {
+        TracedPointer::Opaque if ty == "types::GLDebugProc" => {
+            format!("Value::Ptr({ident}.map_or(0, |f| f as usize as u64))")
+        },
+        TracedPointer::Opaque => format!("Value::Ptr({ident} as usize as u64)"),
+        TracedPointer::Offset => format!("Value::Offset({ident} as usize as u64)"),
+        TracedPointer::Str(None) => format!("trace::c_str({ident})"),
+        TracedPointer::Str(Some(len)) => format!("trace::str_with_len({ident}, {len})"),
+        TracedPointer::Sources => {
+            let lengths = if find_param(cmd, "length").is_some() { "length" } else { "::core::ptr::null()" };
+            format!("trace::shader_sources(count, {ident}, {lengths})")
+        },
+        TracedPointer::Array(len) if len == "1" => {
+            format!("trace::blob({ident}.cast(), ::core::mem::size_of::<{pointee}>())")
+        },
+        TracedPointer::Array(len) => {
+            format!("trace::blob({ident}.cast(), {len} * ::core::mem::size_of::<{pointee}>())")
+        },
+        TracedPointer::Bytes(len) => format!("trace::blob({ident}, {len})"),
+        TracedPointer::Pixels(dims) => {
+            let height = if dims >= 2 { "height" } else { "1" };
+            let depth = if dims >= 3 { "depth" } else { "1" };
+            format!("tracer.pixels({ident}, format, ty, [width, {height}, {depth}], {dims})")
+        },
+        TracedPointer::CompressedPixels(len) => format!("tracer.pixel_blob({ident}, {len})"),
+        TracedPointer::NewNames => {
+            let n = new_names_count(cmd).unwrap();
+            format!("trace::blob({ident}.cast_const().cast(), {n}.max(0) as usize * ::core::mem::size_of::<GLuint>())")
+        },
+    }
+}
+
+
+/// Scalar types that OpenGL functions read arrays of.
+const ARRAY_TYPES: &[&str] = &[
+    "GLboolean",
+    "GLbyte",
+    "GLubyte",
+    "GLshort",
+    "GLushort",
+    "GLint",
+    "GLuint",
+    "GLint64",
+    "GLuint64",
+    "GLsizei",
+    "GLintptr",
+    "GLsizeiptr",
+    "GLenum",
+    "GLfloat",
+    "GLdouble",
+];
+
+/// How the data behind a pointer parameter is recorded in a trace, and passed back to OpenGL when the trace is
+/// replayed.
+enum TracedPointer {
+    /// Only the address is recorded. Calls with these can't be replayed, unless they're null.
+    Opaque,
+    /// An offset into a bound buffer, like the `indices` of `glDrawElements`, which is recorded as-is.
+    Offset,
+    /// A string, which is either NUL-terminated or has its length in the parameter with the given name.
+    Str(Option<&'static str>),
+    /// The array of strings passed to `glShaderSource` or `glCreateShaderProgramv`, recorded as a single string.
+    Sources,
+    /// An array of the pointee type, with the number of elements given by an expression.
+    Array(String),
+    /// Some number of bytes, given by an expression.
+    Bytes(String),
+    /// Pixels for a texture upload with the given number of dimensions.
+    Pixels(usize),
+    /// Pixels for a compressed texture upload, with the number of bytes given by an expression.
+    CompressedPixels(String),
+    /// The array of names written by `glGen*` and `glCreate*` functions.
+    NewNames,
+}
+
+/// Figures out how much data a pointer parameter points to, based on its function and the names of the other
+/// parameters.
+fn classify_pointer(cmd: &Cmd, ident: &str, ty: &str) -> TracedPointer {
+    let name = cmd.proto.ident.as_str();
+    let scalar = |param: &str| find_param(cmd, param).is_some_and(|b| !rename_lib_type(&b.ty).contains('*'));
+
+    if ty.starts_with("*mut ") {
+        return if is_new_names(cmd, ident, ty) { TracedPointer::NewNames } else { TracedPointer::Opaque };
+    }
+
+    let Some(pointee) = ty.strip_prefix("*const ") else {
+        return TracedPointer::Opaque;
+    };
+
+    match pointee {
+        "GLchar" if scalar("length") => TracedPointer::Str(Some("length")),
+        "GLchar" => TracedPointer::Str(None),
+        "*const GLchar" if is_shader_source(cmd) => TracedPointer::Sources,
+        "c_void" => match ident {
+            "pointer" | "indices" | "indirect" => TracedPointer::Offset,
+            "pixels" => TracedPointer::Pixels(texture_dimensions(name)),
+            "data" if scalar("image_size") => TracedPointer::CompressedPixels("image_size.max(0) as usize".to_string()),
+            "data" if scalar("format") => TracedPointer::Bytes("trace::pixel_size(format, ty)".to_string()),
+            "data" if scalar("size") => TracedPointer::Bytes("size.max(0) as usize".to_string()),
+            _ => TracedPointer::Opaque,
+        },
+        // `glMultiDrawElements` takes an array of offsets into the element buffer
+        "*const c_void" if ident == "indices" && scalar("draw_count") => {
+            TracedPointer::Array("draw_count.max(0) as usize".to_string())
+        },
+        _ if ARRAY_TYPES.contains(&pointee) => match array_len(cmd, scalar) {
+            Some(len) => TracedPointer::Array(len),
+            None => TracedPointer::Opaque,
+        },
+        _ => TracedPointer::Opaque,
+    }
+}
+
+/// Creates an expression for the number of elements in an array parameter, or `None` if it can't be figured out.
+fn array_len(cmd: &Cmd, scalar: impl Fn(&str) -> bool) -> Option<String> {
+    let name = cmd.proto.ident.as_str();
+
+    // `glUniform4fv`, `glProgramUniformMatrix3x2fv`, and the like
+    let uniform = name.strip_prefix("Program").unwrap_or(name).strip_prefix("Uniform");
+    match uniform.and_then(uniform_components) {
+        Some(1) => return Some("count.max(0) as usize".to_string()),
+        Some(components) => return Some(format!("count.max(0) as usize * {components}")),
+        None => {},
+    }
+
+    // `glVertexAttrib4fv`, `glVertexAttribI4iv`, and the like. Packed attributes are a single value.
+    if let Some(rest) = name.strip_prefix("VertexAttrib") {
+        let rest = rest.trim_start_matches(['I', 'L']);
+        if rest.starts_with('P') {
+            return Some("1".to_string());
+        } else if let Some(components) = rest.chars().next().and_then(|c| c.to_digit(10)) {
+            return Some(components.to_string());
+        }
+    }
+
+    // Color buffers are cleared with four values, depth and stencil buffers with one.
+    if name.starts_with("ClearBuffer") || name.starts_with("ClearNamedFramebuffer") {
+        return Some("trace::clear_value_len(buffer)".to_string());
+    }
+
+    match name {
+        "ViewportArrayv" | "ScissorArrayv" => return Some("count.max(0) as usize * 4".to_string()),
+        "DepthRangeArrayv" => return Some("count.max(0) as usize * 2".to_string()),
+        "ViewportIndexedfv" | "ScissorIndexedv" => return Some("4".to_string()),
+        _ => {},
+    }
+
+    // `glTexParameterfv`, `glPatchParameterfv`, and the like
+    if scalar("pname") {
+        return Some("trace::parameter_len(pname)".to_string());
+    }
+
+    ["n", "count", "draw_count", "num_attachments"]
+        .into_iter()
+        .find(|&param| scalar(param))
+        .map(|param| format!("{param}.max(0) as usize"))
+}
+
+/// Finds the number of components in a uniform from the end of its function's name, like `4fv` or `Matrix3x2fv`.
+fn uniform_components(suffix: &str) -> Option<u32> {
+    let digit = |i: usize| suffix.as_bytes().get(i).and_then(|&b| (b as char).to_digit(10));
+    match suffix.strip_prefix("Matrix") {
+        Some(_) if suffix.as_bytes().get(7) == Some(&b'x') => Some(digit(6)? * digit(8)?),
+        Some(_) => Some(digit(6)? * digit(6)?),
+        None => digit(0),
+    }
+}
+
+/// Finds the number of dimensions of a texture upload function, like `glTexSubImage2D`.
+fn texture_dimensions(name: &str) -> usize {
+    if name.contains("3D") {
+        3
+    } else if name.contains("2D") {
+        2
+    } else {
+        1
+    }
+}
+
+fn find_param<'a>(cmd: &'a Cmd, ident: &str) -> Option<&'a Binding> {
+    cmd.params.iter().find(|b| rename_parameter(&b.ident) == ident)
+}
+
+fn is_shader_source(cmd: &Cmd) -> bool {
+    matches!(cmd.proto.ident.as_str(), "ShaderSource" | "CreateShaderProgramv")
+}
+
+/// Checks whether a parameter is the array of new names written by a `glGen*` or `glCreate*` function.
+fn is_new_names(cmd: &Cmd, ident: &str, ty: &str) -> bool {
+    let is_last = cmd.params.last().is_some_and(|b| rename_parameter(&b.ident) == ident);
+    is_last && ty == "*mut GLuint" && new_names_kind(&cmd.proto.ident).is_some() && new_names_count(cmd).is_some()
+}
+
+/// Finds the parameter of a `glGen*` or `glCreate*` function that says how many names to create.
+fn new_names_count(cmd: &Cmd) -> Option<&'static str> {
+    ["n", "count"].into_iter().find(|&param| find_param(cmd, param).is_some())
+}
+
+
+/// Output the parameter names, enum names, and replay function used by the `trace` module.
+pub fn write_trace_replay(registry: &Registry, dest: &mut impl Write) -> io::Result<()> {
+    writeln!(dest, "/// Finds the names of the parameters of the OpenGL function with the given name.")?;
+    writeln!(dest, "pub(super) fn param_names(function: &str) -> Option<&'static [&'static str]> {{")?;
+    writeln!(dest, "    match function {{")?;
+    for cmd in &registry.cmds {
+        let names = cmd
+            .params
+            .iter()
+            .map(|binding| format!("\"{}\"", rename_parameter(&binding.ident)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(dest, "        \"gl{}\" => Some(&[{names}]),", cmd.proto.ident)?;
+    }
+    writeln!(dest, "        _ => None,")?;
+    writeln!(dest, "    }}")?;
+    writeln!(dest, "}}\n")?;
+
+    write_enum_names(registry, dest)?;
+    writeln!(dest)?;
+
+    writeln!(dest, "/// Re-issues a recorded call.")?;
+    writeln!(dest, "///")?;
+    writeln!(dest, "/// # Safety")?;
+    writeln!(dest, "///")?;
+    writeln!(dest, "/// See [`Replayer::replay`].")?;
+    writeln!(
+        dest,
+        "pub(super) unsafe fn replay(gl: &GLPointers, names: &mut Names, call: &Call) -> Result<bool, ReplayError> {{"
+    )?;
+    writeln!(dest, "    let function = call.name.as_str();")?;
+    writeln!(dest, "    match function {{")?;
+    for cmd in &registry.cmds {
+        write_replay_arm(cmd, dest)?;
+    }
+    writeln!(dest, "        _ => Err(ReplayError::UnknownFunction(call.name.clone())),")?;
+    writeln!(dest, "    }}")?;
+    writeln!(dest, "}}")?;
+
+    Ok(())
+}
+
+/// Output the `match` arm that replays a single function.
+fn write_replay_arm(cmd: &Cmd, dest: &mut impl Write) -> io::Result<()> {
+    let gl_name = format!("gl{}", cmd.proto.ident);
+    if !is_replayable(cmd) {
+        return writeln!(dest, "        \"{gl_name}\" => Ok(false),");
+    }
+
+    writeln!(dest, "        \"{gl_name}\" => {{")?;
+
+    let num_params = cmd.params.len();
+    if num_params == 0 {
+        writeln!(dest, "            args::<0>(call)?;")?;
+    } else {
+        writeln!(dest, "            let args = args::<{num_params}>(call)?;")?;
+    }
+
+    let mut call_args = Vec::with_capacity(num_params);
+    for (i, binding) in cmd.params.iter().enumerate() {
+        let ident = rename_parameter(&binding.ident);
+        let ty = rename_lib_type(&binding.ty);
+        let (prelude, expr) = make_replay_arg(cmd, i, ident, ty);
+        if let Some(prelude) = prelude {
+            writeln!(dest, "            {prelude}")?;
+        }
+        call_args.push(expr);
+    }
+
+    let call = format!("gl.{}({})", rename_function(&cmd.proto.ident), call_args.join(", "));
+    let new_names = cmd.params.last().and_then(|b| {
+        let ident = rename_parameter(&b.ident);
+        is_new_names(cmd, ident, rename_lib_type(&b.ty)).then_some(ident)
+    });
+
+    if let (Some(kind), Some(_)) = (new_names_kind(&cmd.proto.ident), new_names) {
+        writeln!(dest, "            unsafe {{ {call} }};")?;
+        writeln!(dest, "            names.insert_all(NameKind::{kind}, &args[{}], &new_names);", num_params - 1)?;
+    } else if let Some(kind) = created_kind(&cmd.proto.ident) {
+        let cast = if kind == "Sync" { "as usize as u64" } else { "as u64" };
+        writeln!(dest, "            let new = unsafe {{ {call} }};")?;
+        writeln!(dest, "            names.insert(NameKind::{kind}, call.ret.as_ref(), new {cast});")?;
+    } else {
+        writeln!(dest, "            unsafe {{ {call} }};")?;
+    }
+
+    writeln!(dest, "            Ok(true)")?;
+    writeln!(dest, "        }},")?;
+    Ok(())
+}
+
+/// Creates the expression that passes a recorded argument back to OpenGL, along with a statement that needs to come
+/// before the call, if there is one (for values that need to outlive the expression).
+fn make_replay_arg(cmd: &Cmd, i: usize, ident: &str, ty: &str) -> (Option<String>, String) {
+    let arg = format!("&args[{i}]");
+    let index_of = |param: &str| cmd.params.iter().position(|b| rename_parameter(&b.ident) == param);
+
+    if let Some(name) = make_replay_name(cmd, ident, ty, &format!("uint({arg}, function)?"), index_of) {
+        return (None, format!("{name} as {ty}"));
+    }
+
+    let expr = match ty {
+        "GLfloat" | "GLdouble" => format!("float({arg}, function)? as {ty}"),
+        "GLbyte" | "GLshort" | "GLint" | "GLsizei" | "GLintptr" | "GLsizeiptr" | "GLint64" => {
+            format!("int({arg}, function)? as {ty}")
+        },
+        "GLenum" | "GLbitfield" | "GLboolean" | "GLubyte" | "GLushort" | "GLuint" | "GLuint64" => {
+            format!("uint({arg}, function)? as {ty}")
+        },
+        "GLsync" => format!("names.get(NameKind::Sync, uint({arg}, function)?) as usize as GLsync"),
+        _ => match classify_pointer(cmd, ident, ty) {
+            TracedPointer::Str(_) => {
+                let prelude = format!("let a{i} = c_string({arg}, function)?;");
+                return (Some(prelude), format!("opt_ptr(&a{i})"));
+            },
+            TracedPointer::Sources => {
+                let prelude =
+                    format!("let s{i} = c_string({arg}, function)?;\n            let a{i} = [opt_ptr(&s{i})];");
+                return (Some(prelude), format!("a{i}.as_ptr()"));
+            },
+            TracedPointer::Array(_) if ty == "*const GLuint" && object_kind(&cmd.proto.ident, ident).is_some() => {
+                let kind = object_kind(&cmd.proto.ident, ident).unwrap();
+                let prelude = format!("let a{i} = names.get_all(NameKind::{kind}, {arg}, function)?;");
+                return (Some(prelude), format!("opt_ptr(&a{i})"));
+            },
+            TracedPointer::NewNames => {
+                let n = format!("&args[{}]", index_of(new_names_count(cmd).unwrap()).unwrap());
+                let prelude = format!("let mut new_names = vec![0; int({n}, function)?.max(0) as usize];");
+                return (Some(prelude), "new_names.as_mut_ptr()".to_string());
+            },
+            _ => format!("ptr({arg}, function)?.cast()"),
+        },
+    };
+
+    (None, expr)
+}
+
+/// Creates an expression that maps a recorded object name to the one created during the replay, if the parameter with
+/// the given name and type is an object name.
+fn make_replay_name(
+    cmd: &Cmd,
+    ident: &str,
+    ty: &str,
+    value: &str,
+    index_of: impl Fn(&str) -> Option<usize>,
+) -> Option<String> {
+    if ty != "GLuint" {
+        return None;
+    }
+
+    if let Some(kind) = object_kind(&cmd.proto.ident, ident) {
+        return Some(format!("names.get(NameKind::{kind}, {value})"));
+    }
+
+    // The kinds of some names depend on another parameter
+    let (method, param) = match (cmd.proto.ident.as_str(), ident) {
+        ("CopyImageSubData", "src_name") => ("get_image", "src_target"),
+        ("CopyImageSubData", "dst_name") => ("get_image", "dst_target"),
+        ("ObjectLabel", "name") => ("get_object", "identifier"),
+        _ => return None,
+    };
+
+    let index = index_of(param)?;
+    Some(format!("names.{method}(uint(&args[{index}], function)? as GLenum, {value})"))
+}
+
+/// Checks whether a function's calls can be replayed. Functions are skipped if they only query state (which includes
+/// anything that writes to a pointer or returns a value, other than creating objects), or if they deal with memory that
+/// isn't recorded, like mapped buffers and debug callbacks.
+fn is_replayable(cmd: &Cmd) -> bool {
+    let name = cmd.proto.ident.as_str();
+    if rename_lib_type(&cmd.proto.ty) != "()" && created_kind(name).is_none() {
+        return false;
+    }
+
+    if ["Map", "Unmap", "FlushMapped"].iter().any(|prefix| name.starts_with(prefix)) {
+        return false;
+    }
+
+    cmd.params.iter().all(|binding| {
+        let ident = rename_parameter(&binding.ident);
+        let ty = rename_lib_type(&binding.ty);
+        let is_output = ty.starts_with("*mut ") && !is_new_names(cmd, ident, ty);
+        !is_output && !ty.starts_with("types::")
+    })
+}
+
+/// Finds the kind of object named by a parameter, if it is always the same kind.
+fn object_kind(cmd: &str, ident: &str) -> Option<&'static str> {
+    Some(match ident {
+        "buffer" | "buffers" | "read_buffer" | "write_buffer" => "Buffer",
+        "framebuffer" | "framebuffers" | "read_framebuffer" | "draw_framebuffer" => "Framebuffer",
+        "program" => "Program",
+        "pipeline" | "pipelines" => "ProgramPipeline",
+        "renderbuffer" | "renderbuffers" => "Renderbuffer",
+        "sampler" | "samplers" => "Sampler",
+        "shader" | "shaders" => "Shader",
+        "texture" | "textures" | "orig_texture" => "Texture",
+        "vaobj" | "array" | "arrays" => "VertexArray",
+        "xfb" => "TransformFeedback",
+        "id" | "ids" if cmd.contains("Quer") => "Query",
+        "id" | "ids" if cmd.contains("TransformFeedback") => "TransformFeedback",
+        _ => return None,
+    })
+}
+
+/// Finds the kind of object created by a `glGen*` or `glCreate*` function that writes an array of names.
+fn new_names_kind(cmd: &str) -> Option<&'static str> {
+    let objects = cmd.strip_prefix("Gen").or_else(|| cmd.strip_prefix("Create"))?;
+    Some(match objects {
+        "Buffers" => "Buffer",
+        "Framebuffers" => "Framebuffer",
+        "ProgramPipelines" => "ProgramPipeline",
+        "Queries" => "Query",
+        "Renderbuffers" => "Renderbuffer",
+        "Samplers" => "Sampler",
+        "Textures" => "Texture",
+        "TransformFeedbacks" => "TransformFeedback",
+        "VertexArrays" => "VertexArray",
+        _ => return None,
+    })
+}
+
+/// Finds the kind of object created by a function that returns its name.
+fn created_kind(cmd: &str) -> Option<&'static str> {
+    match cmd {
+        "CreateShader" => Some("Shader"),
+        "CreateProgram" | "CreateShaderProgramv" => Some("Program"),
+        "FenceSync" => Some("Sync"),
+        _ => None,
+    }
+}
@@ -16,9 +16,51 @@ use gl_generator::{Api, Fallbacks, Profile, Registry};
 const STRUCT_NAME: &'static str = "GLPointers";
 
 
+/// The OpenGL versions that can be selected with cargo features, from highest to lowest.
+const VERSIONS: &[(&str, (u8, u8))] = &[("GL46", (4, 6)), ("GL45", (4, 5)), ("GL41", (4, 1)), ("GL33", (3, 3))];
+
+/// The extensions that can be opted into with cargo features.
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("ARB_BINDLESS_TEXTURE", "GL_ARB_bindless_texture"),
+    ("EXT_TEXTURE_FILTER_ANISOTROPIC", "GL_EXT_texture_filter_anisotropic"),
+    ("KHR_PARALLEL_SHADER_COMPILE", "GL_KHR_parallel_shader_compile"),
+];
+
+
+/// Checks whether the cargo feature with the given name (in `UPPER_SNAKE_CASE`) is enabled.
+fn feature_enabled(name: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{name}")).is_some()
+}
+
+
+/// Whether the `error-checking` feature is enabled, in which case every generated function checks `glGetError` after
+/// it is called.
+fn error_checking() -> bool {
+    feature_enabled("ERROR_CHECKING")
+}
+
+
+/// Whether the `trace` feature is enabled, in which case every generated function records its call to the current
+/// trace.
+fn tracing() -> bool {
+    feature_enabled("TRACE")
+}
+
+
 pub fn main() -> io::Result<()> {
-    // Parse the registry for OpenGL Core 4.6 bindings
-    let registry = Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, []);
+    // Use the highest version that has been enabled
+    let Some(&(_, version)) = VERSIONS.iter().find(|(feature, _)| feature_enabled(feature)) else {
+        panic!("one of gloog-core's OpenGL version features (`gl33`, `gl41`, `gl45`, or `gl46`) must be enabled");
+    };
+
+    let extensions = EXTENSIONS
+        .iter()
+        .filter(|(feature, _)| feature_enabled(feature))
+        .map(|&(_, ext)| ext)
+        .collect::<Vec<_>>();
+
+    // Parse the registry for OpenGL Core bindings
+    let registry = Registry::new(Api::Gl, version, Profile::Core, Fallbacks::All, extensions);
 
     // Get path to Cargo 'target' directory
     let dest_path = env::var("OUT_DIR").expect("OUT_DIR environment variable should be set");
@@ -28,6 +70,21 @@ pub fn main() -> io::Result<()> {
     let mut dest_file = File::create(&dest_path)?;
     write_bindings(&registry, &mut dest_file)?;
 
+    // Typed enums go in a separate file, since they're included into the `types` module instead of `raw`
+    let groups_path = dest_path.with_file_name("groups.rs");
+    let mut groups_file = File::create(&groups_path)?;
+    gen::write_enum_groups(&registry, &mut groups_file)?;
+
+    // Stub functions for the `mock` module are always generated, but only compiled when it is enabled
+    let mock_path = dest_path.with_file_name("mock.rs");
+    let mut mock_file = File::create(&mock_path)?;
+    gen::write_mock_stubs(&registry, &mut mock_file)?;
+
+    // Same for the `trace` module's replay function
+    let trace_path = dest_path.with_file_name("trace.rs");
+    let mut trace_file = File::create(&trace_path)?;
+    gen::write_trace_replay(&registry, &mut trace_file)?;
+
     // Only rerun if the build directory has changes, not if anything in the library changes
     println!("cargo:rerun-if-changed=build");
 
@@ -367,3 +367,95 @@ pub fn rename_parameter(ident: &str) -> &'static str {
         unsafe { str_to_static(inserted) }
     }
 }
+
+
+/// Groups from the registry which are generated as public enums and bitfields in `gloog_core::types`, along with the
+/// name of the Rust type for each. Groups not on this list only have their raw values generated.
+///
+/// Most groups are still wrapped by hand in `src/types/enums.rs` and `src/types/flags.rs`, since the registry's groups
+/// are missing documentation and often include values that are not valid for the functions that use them. Those
+/// groups are left off of this list so that they don't get generated twice.
+#[rustfmt::skip]
+const GROUP_ALLOW_LIST: &[(Str, Str)] = &[
+    // cspell:disable
+    ("ClipControlDepth", "ClipControlDepth"),
+    ("ClipControlOrigin", "ClipControlOrigin"),
+    ("ContextFlagMask", "ContextFlags"),
+    ("ContextProfileMask", "ContextProfileFlags"),
+    ("ErrorCode", "ErrorCode"),
+    ("GraphicsResetStatus", "GraphicsResetStatus"),
+    ("HintMode", "HintMode"),
+    ("HintTarget", "HintTarget"),
+    ("LogicOp", "LogicOp"),
+    ("PatchParameterName", "PatchParameterName"),
+    ("VertexProvokingMode", "ProvokingVertex"),
+    // cspell:enable
+];
+
+
+/// Returns the name of the Rust type that the given registry group should be generated as, or `None` if it isn't on
+/// the [allow-list][GROUP_ALLOW_LIST].
+pub fn rename_group(ident: &str) -> Option<&'static str> {
+    let (_, name) = GROUP_ALLOW_LIST.iter().find(|&&(group, _)| group == ident)?;
+    Some(name)
+}
+
+
+/// Converts the raw names of every member of a group into names for its Rust enum variants or bitfield constants.
+///
+/// Any words that every member has in common at its start or end are removed (e.g., `LINE_SMOOTH_HINT` and
+/// `POLYGON_SMOOTH_HINT` become `LineSmooth` and `PolygonSmooth`), as is the `_BIT` suffix from bitfield members. Enum
+/// variants are converted to `PascalCase`, except for words containing digits, which stay uppercase (like `RGBA8`);
+/// bitfield constants stay in `UPPER_SNAKE_CASE`.
+pub fn rename_group_members(idents: &[&str], bitmask: bool) -> Vec<String> {
+    let mut words = idents
+        .iter()
+        .map(|ident| {
+            let mut ident = *ident;
+            if bitmask {
+                trim_end_mut(&mut ident, "_BIT");
+            }
+            ident.split('_').collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Strip words that every member shares at its start, then at its end, so long as every member keeps at least one
+    // word and none of them end up starting with a digit (which wouldn't be a valid identifier). Shared endings are
+    // only stripped from larger groups, since in pairs they are usually meaningful (`LOWER_LEFT` and `UPPER_LEFT`).
+    let is_valid = |words: &[&str]| words.first().is_some_and(|w| !w.starts_with(|c: char| c.is_ascii_digit()));
+
+    while words.len() > 1
+        && words.iter().all(|w| w.first() == words[0].first())
+        && words.iter().all(|w| is_valid(&w[1..]))
+    {
+        for w in &mut words {
+            w.remove(0);
+        }
+    }
+
+    while words.len() > 2
+        && words.iter().all(|w| w.last() == words[0].last())
+        && words.iter().all(|w| is_valid(&w[..w.len() - 1]))
+    {
+        for w in &mut words {
+            w.pop();
+        }
+    }
+
+    words
+        .into_iter()
+        .map(|words| {
+            if bitmask {
+                return words.join("_");
+            }
+
+            let capitalize = |word: &str| match word.chars().any(|c| c.is_ascii_digit()) {
+                true => word.to_owned(),
+                false => word[..1].to_owned() + &word[1..].to_lowercase(),
+            };
+
+            words.into_iter().map(capitalize).collect()
+        })
+        .collect()
+}
+
@@ -1,9 +1,20 @@
+#[cfg(feature = "gl45")]
+use bytemuck::Pod;
+
+#[cfg(feature = "gl45")]
+use crate::objects::BufferMapping;
+#[cfg(feature = "gl45")]
+use crate::params::ShaderStorageBufferOffsetAlignment;
+use crate::params::UniformBufferOffsetAlignment;
 use crate::raw::types::*;
+#[cfg(feature = "gl45")]
+use crate::raw::BUFFER_MAPPED;
 use crate::types::*;
 use crate::{convert, GLContext};
 
 
 impl GLContext {
+    #[cfg(feature = "gl45")]
     pub fn create_buffer(&self) -> BufferID {
         let mut name = 0;
         unsafe { self.gl.create_buffers(1, &mut name) };
@@ -11,6 +22,7 @@ impl GLContext {
     }
 
 
+    #[cfg(feature = "gl45")]
     pub fn create_buffers(&self, n: usize) -> Vec<BufferID> {
         if n == 0 {
             return vec![];
@@ -24,38 +36,306 @@ impl GLContext {
     }
 
 
+    /// # Panics
+    ///
+    /// This function panics if the buffer is still mapped by a live [`BufferMapping`].
     pub fn delete_buffer(&self, buffer: BufferID) {
+        #[cfg(feature = "gl45")]
+        self.assert_not_mapped(buffer);
         unsafe { self.gl.delete_buffers(1, &buffer.into_raw()) }
+        #[cfg(feature = "gl45")]
+        self.update_unpack_state(|state| state.buffer = state.buffer.filter(|&bound| bound != buffer));
     }
 
 
+    /// # Panics
+    ///
+    /// This function panics if any of the buffers are still mapped by a live [`BufferMapping`].
     pub fn delete_buffers(&self, buffers: &[BufferID]) {
+        #[cfg(feature = "gl45")]
+        buffers.iter().for_each(|&buffer| self.assert_not_mapped(buffer));
         let len = convert!(buffers.len(), GLsizei, "number of buffers");
         let ptr = buffers.as_ptr().cast(); // cast is safe because `BufferID` is `repr(transparent)`
         unsafe { self.gl.delete_buffers(len, ptr) }
+        #[cfg(feature = "gl45")]
+        self.update_unpack_state(|state| state.buffer = state.buffer.filter(|bound| !buffers.contains(bound)));
     }
 
 
     pub fn bind_buffer(&self, target: BufferTarget, buffer: BufferID) {
         unsafe { self.gl.bind_buffer(target.into_raw(), buffer.into_raw()) }
+        #[cfg(feature = "gl45")]
+        if target == BufferTarget::PixelUnpackBuffer {
+            self.update_unpack_state(|state| state.buffer = Some(buffer));
+        }
     }
 
 
     pub fn unbind_buffer(&self, target: BufferTarget) {
         unsafe { self.gl.bind_buffer(target.into_raw(), 0) }
+        #[cfg(feature = "gl45")]
+        if target == BufferTarget::PixelUnpackBuffer {
+            self.update_unpack_state(|state| state.buffer = None);
+        }
+    }
+
+
+    /// Binds a buffer to one of the indexed binding points of the given target, as well as to the generic binding point
+    /// for that target.
+    pub fn bind_buffer_base(&self, target: IndexedBufferTarget, index: u32, buffer: BufferID) {
+        unsafe { self.gl.bind_buffer_base(target.into_raw(), index, buffer.into_raw()) }
     }
 
 
+    /// Binds `size` bytes of a buffer, starting at `offset` bytes, to one of the indexed binding points of the given
+    /// target.
+    ///
+    /// `offset` must be a multiple of the target's offset alignment; see
+    /// [`get_uniform_buffer_offset_alignment`][Self::get_uniform_buffer_offset_alignment] and
+    /// [`get_shader_storage_buffer_offset_alignment`][Self::get_shader_storage_buffer_offset_alignment].
+    pub fn bind_buffer_range(
+        &self,
+        target: IndexedBufferTarget,
+        index: u32,
+        buffer: BufferID,
+        offset: usize,
+        size: usize,
+    ) {
+        let offset = convert!(offset, GLintptr, "buffer offset");
+        let size = convert!(size, GLsizeiptr, "buffer range size");
+        let (target, buffer) = (target.into_raw(), buffer.into_raw());
+        unsafe { self.gl.bind_buffer_range(target, index, buffer, offset, size) }
+    }
+
+
+    /// Unbinds whatever buffer is bound to one of the indexed binding points of the given target.
+    pub fn unbind_buffer_base(&self, target: IndexedBufferTarget, index: u32) {
+        unsafe { self.gl.bind_buffer_base(target.into_raw(), index, 0) }
+    }
+
+
+    /// # Panics
+    ///
+    /// This function panics if the buffer bound to `target` is mapped while any [`BufferMapping`] is alive.
     pub fn buffer_data(&self, target: BufferTarget, data: &[u8], usage: BufferUsage) {
+        #[cfg(feature = "gl45")]
+        if !self.mapped_buffers.borrow().is_empty() {
+            // There's no single query for the buffer bound to an arbitrary target, so ask whether it's mapped instead;
+            // this is only done while some mapping is alive, so it costs nothing the rest of the time.
+            let mut mapped = 0;
+            unsafe { self.gl.get_buffer_parameter_iv(target.into_raw(), BUFFER_MAPPED, &mut mapped) };
+            assert!(mapped == 0, "buffer bound to {target:?} should not be re-allocated while it is mapped");
+        }
+
         let len = convert!(data.len(), isize, "buffer data size");
         let ptr = data.as_ptr().cast();
         unsafe { self.gl.buffer_data(target.into_raw(), len, ptr, usage.into_raw()) }
     }
 
 
+    /// # Panics
+    ///
+    /// This function panics if the buffer is still mapped by a live [`BufferMapping`].
+    #[cfg(feature = "gl45")]
     pub fn named_buffer_data(&self, buffer: BufferID, data: &[u8], usage: BufferUsage) {
+        self.assert_not_mapped(buffer);
         let len = convert!(data.len(), isize, "buffer data size");
         let ptr = data.as_ptr().cast();
         unsafe { self.gl.named_buffer_data(buffer.into_raw(), len, ptr, usage.into_raw()) }
     }
+
+
+    /// Reads data out of a buffer object's data store, starting at `offset` bytes, to fill `data`.
+    #[cfg(feature = "gl45")]
+    pub fn get_named_buffer_sub_data(&self, buffer: BufferID, offset: usize, data: &mut [u8]) {
+        let offset = convert!(offset, GLintptr, "buffer offset");
+        let len = convert!(data.len(), GLsizeiptr, "buffer data size");
+        let ptr = data.as_mut_ptr().cast();
+        unsafe { self.gl.get_named_buffer_sub_data(buffer.into_raw(), offset, len, ptr) }
+    }
+
+
+    /// Creates an immutable data store for a buffer object, initialized with the given data.
+    ///
+    /// Unlike with [`named_buffer_data`][Self::named_buffer_data], the data store can never be re-allocated; its
+    /// contents may only be changed in the ways allowed by `flags`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the buffer is still mapped by a live [`BufferMapping`].
+    #[cfg(feature = "gl45")]
+    pub fn named_buffer_storage(&self, buffer: BufferID, data: &[u8], flags: BufferStorageFlags) {
+        self.assert_not_mapped(buffer);
+        let len = convert!(data.len(), GLsizeiptr, "buffer storage size");
+        let ptr = data.as_ptr().cast();
+        unsafe { self.gl.named_buffer_storage(buffer.into_raw(), len, ptr, flags.into_raw()) }
+    }
+
+
+    /// Creates an immutable data store of `size` bytes for a buffer object, without initializing its contents.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the buffer is still mapped by a live [`BufferMapping`].
+    #[cfg(feature = "gl45")]
+    pub fn named_buffer_storage_empty(&self, buffer: BufferID, size: usize, flags: BufferStorageFlags) {
+        self.assert_not_mapped(buffer);
+        let size = convert!(size, GLsizeiptr, "buffer storage size");
+        let ptr = std::ptr::null();
+        unsafe { self.gl.named_buffer_storage(buffer.into_raw(), size, ptr, flags.into_raw()) }
+    }
+
+
+    /// Replaces part of a buffer object's data store, starting at `offset` bytes, with the given data.
+    ///
+    /// Immutable buffers must have been created with [`BufferStorageFlags::DYNAMIC_STORAGE`] to be updated this way.
+    #[cfg(feature = "gl45")]
+    pub fn named_buffer_sub_data(&self, buffer: BufferID, offset: usize, data: &[u8]) {
+        let offset = convert!(offset, GLintptr, "buffer offset");
+        let len = convert!(data.len(), GLsizeiptr, "buffer data size");
+        let ptr = data.as_ptr().cast();
+        unsafe { self.gl.named_buffer_sub_data(buffer.into_raw(), offset, len, ptr) }
+    }
+
+
+    /// Copies `size` bytes from one buffer object's data store to another's. The source and destination may be the
+    /// same buffer, as long as the two ranges do not overlap.
+    #[cfg(feature = "gl45")]
+    pub fn copy_named_buffer_sub_data(
+        &self,
+        read_buffer: BufferID,
+        write_buffer: BufferID,
+        read_offset: usize,
+        write_offset: usize,
+        size: usize,
+    ) {
+        let read_offset = convert!(read_offset, GLintptr, "buffer read offset");
+        let write_offset = convert!(write_offset, GLintptr, "buffer write offset");
+        let size = convert!(size, GLsizeiptr, "buffer copy size");
+        let (read, write) = (read_buffer.into_raw(), write_buffer.into_raw());
+        unsafe { self.gl.copy_named_buffer_sub_data(read, write, read_offset, write_offset, size) }
+    }
+
+
+    /// Fills a buffer object's entire data store with copies of a single value.
+    ///
+    /// `value` is a single element, described by `format` and `ty`, which is converted into `internal_format` before
+    /// being repeated throughout the buffer. For example, to fill a buffer with zero bytes, use
+    /// [`InternalFormat::R8`], [`PixelFormat::Red`], [`PixelType::UnsignedByte`], and `&[0]`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `value` is not exactly one element of the given format and type in size.
+    #[cfg(feature = "gl45")]
+    pub fn clear_named_buffer_data(
+        &self,
+        buffer: BufferID,
+        internal_format: InternalFormat,
+        format: PixelFormat,
+        ty: PixelType,
+        value: &[u8],
+    ) {
+        let expected = format.pixel_size(ty);
+        assert_eq!(value.len(), expected, "buffer clear value should be a single {format:?}/{ty:?} element");
+
+        let (buf, internal_format) = (buffer.into_raw(), internal_format.into_raw());
+        let (format, ty) = (format.into_raw(), ty.into_raw());
+        let ptr = value.as_ptr().cast();
+        unsafe { self.gl.clear_named_buffer_data(buf, internal_format, format, ty, ptr) }
+    }
+
+
+    /// Maps `length` bytes of a buffer object's data store, starting at `offset` bytes, into client memory.
+    ///
+    /// The returned guard dereferences to the mapped range as a slice of `T`, and unmaps the buffer when it is dropped.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if mapping fails (for example, if the buffer is already mapped or the range is out of
+    /// bounds), if `length` is not a multiple of the size of `T`, or if the mapped pointer is not suitably aligned for
+    /// `T`.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must not be deleted, re-allocated, or unmapped by any other means while the returned guard is alive.
+    /// The functions on this context check for that and panic instead, so this only needs to be upheld for calls that
+    /// bypass them, such as ones made through another context that shares objects with this one.
+    #[cfg(feature = "gl45")]
+    pub unsafe fn map_named_buffer_range<T: Pod>(
+        &self,
+        buffer: BufferID,
+        offset: usize,
+        length: usize,
+        access: MapAccessFlags,
+    ) -> BufferMapping<'_, T> {
+        let elem_size = std::mem::size_of::<T>();
+        assert!(elem_size > 0, "cannot map a buffer as a slice of zero-sized types");
+        assert!(length.is_multiple_of(elem_size), "mapped buffer length should be a multiple of {elem_size} bytes");
+
+        let gl_offset = convert!(offset, GLintptr, "buffer offset");
+        let gl_length = convert!(length, GLsizeiptr, "buffer map length");
+        let (buf, access) = (buffer.into_raw(), access.into_raw());
+        let ptr = self.gl.map_named_buffer_range(buf, gl_offset, gl_length, access);
+
+        assert!(!ptr.is_null(), "failed to map buffer {buffer:?}");
+        assert!(ptr.cast::<T>().is_aligned(), "mapped buffer pointer is misaligned");
+
+        self.mapped_buffers.borrow_mut().push(buffer);
+        BufferMapping::new(self, buffer, ptr.cast(), length / elem_size)
+    }
+
+
+    /// Releases the mapping of a buffer object's data store.
+    ///
+    /// Returns `false` if the data store's contents were corrupted while mapped (for example, by a screen mode change),
+    /// in which case they are now undefined.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the buffer is mapped by a live [`BufferMapping`], which unmaps it when it is dropped.
+    #[cfg(feature = "gl45")]
+    pub fn unmap_named_buffer(&self, buffer: BufferID) -> bool {
+        self.assert_not_mapped(buffer);
+        unsafe { self.gl.unmap_named_buffer(buffer.into_raw()) != 0 }
+    }
+
+
+    /// Called by [`BufferMapping`] when it is dropped, to unmap the buffer and stop tracking it.
+    #[cfg(feature = "gl45")]
+    pub(crate) fn release_buffer_mapping(&self, buffer: BufferID) -> bool {
+        self.mapped_buffers.borrow_mut().retain(|&mapped| mapped != buffer);
+        self.unmap_named_buffer(buffer)
+    }
+
+
+    #[cfg(feature = "gl45")]
+    fn assert_not_mapped(&self, buffer: BufferID) {
+        let mapped = self.mapped_buffers.borrow().contains(&buffer);
+        assert!(!mapped, "buffer {buffer:?} should not be changed while it is mapped by a live `BufferMapping`");
+    }
+
+
+    /// Indicates that a range of a buffer mapped with [`MapAccessFlags::FLUSH_EXPLICIT`] has been modified. `offset` is
+    /// relative to the start of the mapping.
+    #[cfg(feature = "gl45")]
+    pub fn flush_mapped_named_buffer_range(&self, buffer: BufferID, offset: usize, length: usize) {
+        let offset = convert!(offset, GLintptr, "buffer offset");
+        let length = convert!(length, GLsizeiptr, "buffer flush length");
+        unsafe { self.gl.flush_mapped_named_buffer_range(buffer.into_raw(), offset, length) }
+    }
+
+
+    /// Gets the alignment, in bytes, that offsets into buffers bound to [`BufferTarget::UniformBuffer`] binding points
+    /// must be multiples of.
+    pub fn get_uniform_buffer_offset_alignment(&self) -> usize {
+        self.get(UniformBufferOffsetAlignment) as usize
+    }
+
+
+    /// Gets the alignment, in bytes, that offsets into buffers bound to [`BufferTarget::ShaderStorageBuffer`] binding
+    /// points must be multiples of.
+    #[cfg(feature = "gl45")]
+    pub fn get_shader_storage_buffer_offset_alignment(&self) -> usize {
+        self.get(ShaderStorageBufferOffsetAlignment) as usize
+    }
 }
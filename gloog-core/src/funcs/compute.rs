@@ -0,0 +1,99 @@
+use crate::params::{
+    MaxComputeSharedMemorySize,
+    MaxComputeWorkGroupCount,
+    MaxComputeWorkGroupInvocations,
+    MaxComputeWorkGroupSize,
+};
+use crate::raw::types::*;
+use crate::types::*;
+use crate::{convert, GLContext};
+
+
+impl GLContext {
+    /// Launches the compute shader of the current program with the given number of work groups in each dimension.
+    ///
+    /// Writes made by the compute shader are not visible to later commands until a suitable
+    /// [memory barrier][Self::memory_barrier] has been issued.
+    pub fn dispatch_compute(&self, num_groups_x: u32, num_groups_y: u32, num_groups_z: u32) {
+        unsafe { self.gl.dispatch_compute(num_groups_x, num_groups_y, num_groups_z) }
+    }
+
+
+    /// Launches the compute shader of the current program with the work group counts in the
+    /// [`DispatchIndirectCommand`] found `offset` bytes into the buffer bound to
+    /// [`BufferTarget::DispatchIndirectBuffer`].
+    pub fn dispatch_compute_indirect(&self, offset: usize) {
+        let offset = convert!(offset, GLintptr, "dispatch indirect offset");
+        unsafe { self.gl.dispatch_compute_indirect(offset) }
+    }
+
+
+    /// Binds a single mipmap level of a texture to an image unit, so that shaders can load from, store to, and perform
+    /// atomic operations on it through `image` uniforms.
+    ///
+    /// For array, cube map, and 3D textures, `layer` selects a single layer to bind; `None` binds all of them at once
+    /// (a _layered_ binding). It is ignored for other kinds of textures.
+    pub fn bind_image_texture(
+        &self,
+        unit: u32,
+        texture: TextureID,
+        level: usize,
+        layer: Option<usize>,
+        access: ImageAccess,
+        format: ImageUnitFormat,
+    ) {
+        let level = convert!(level, GLint, "texture level");
+        let layered = convert!(layer.is_none(), GLboolean, "'layered' parameter");
+        let layer = convert!(layer.unwrap_or(0), GLint, "texture layer");
+        let (tex, access, format) = (texture.into_raw(), access.into_raw(), format.into_raw());
+        unsafe { self.gl.bind_image_texture(unit, tex, level, layered, layer, access, format) }
+    }
+
+
+    /// Unbinds whatever texture is bound to an image unit.
+    pub fn unbind_image_texture(&self, unit: u32) {
+        let (access, format) = (ImageAccess::ReadOnly.into_raw(), ImageUnitFormat::R8.into_raw());
+        unsafe { self.gl.bind_image_texture(unit, 0, 0, 0, 0, access, format) }
+    }
+
+
+    /// Orders memory transactions issued by shaders before this call relative to those issued after it, for the kinds
+    /// of access given by `barriers`.
+    pub fn memory_barrier(&self, barriers: MemoryBarrierFlags) {
+        unsafe { self.gl.memory_barrier(barriers.into_raw()) }
+    }
+
+
+    /// Like [`memory_barrier`][Self::memory_barrier], but only orders memory transactions made by fragment shaders
+    /// within the same framebuffer region. This can be cheaper, but only some barrier flags are allowed:
+    /// `ATOMIC_COUNTER`, `FRAMEBUFFER`, `SHADER_IMAGE_ACCESS`, `SHADER_STORAGE`, `TEXTURE_FETCH`, `UNIFORM`, and `ALL`.
+    pub fn memory_barrier_by_region(&self, barriers: MemoryBarrierFlags) {
+        unsafe { self.gl.memory_barrier_by_region(barriers.into_raw()) }
+    }
+
+
+    /// Gets the maximum number of work groups that may be dispatched in each dimension by a single dispatch command.
+    pub fn get_max_compute_work_group_count(&self) -> [u32; 3] {
+        [0, 1, 2].map(|i| self.get_indexed(MaxComputeWorkGroupCount, i) as u32)
+    }
+
+
+    /// Gets the maximum size of a single work group in each dimension, as declared by a compute shader's
+    /// `local_size_x`, `local_size_y`, and `local_size_z` layout qualifiers.
+    pub fn get_max_compute_work_group_size(&self) -> [u32; 3] {
+        [0, 1, 2].map(|i| self.get_indexed(MaxComputeWorkGroupSize, i) as u32)
+    }
+
+
+    /// Gets the maximum total number of invocations in a single work group (the product of its sizes in each
+    /// dimension).
+    pub fn get_max_compute_work_group_invocations(&self) -> u32 {
+        self.get(MaxComputeWorkGroupInvocations) as u32
+    }
+
+
+    /// Gets the maximum total size, in bytes, of all `shared` variables in a compute shader.
+    pub fn get_max_compute_shared_memory_size(&self) -> usize {
+        self.get(MaxComputeSharedMemorySize) as usize
+    }
+}
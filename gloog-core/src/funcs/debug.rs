@@ -1,16 +1,29 @@
+#[cfg(feature = "gl45")]
 use std::ffi::c_void;
+#[cfg(feature = "gl45")]
 use std::mem::align_of;
+#[cfg(feature = "gl45")]
 use std::ptr::{from_mut, from_ref};
-use std::slice;
 
+#[cfg(feature = "gl45")]
 use super::DebugFilter;
+#[cfg(feature = "gl45")]
+use crate::convert;
+#[cfg(feature = "gl45")]
+use crate::objects::DebugGroup;
+#[cfg(feature = "gl45")]
+use crate::params::MaxDebugMessageLength;
+#[cfg(feature = "gl45")]
 use crate::raw::types::*;
-use crate::raw::{DONT_CARE as GL_DONT_CARE, MAX_DEBUG_MESSAGE_LENGTH as GL_MAX_DEBUG_MESSAGE_LENGTH};
-use crate::types::{DebugMessage, DebugSeverity, DebugSource, DebugType};
-use crate::{convert, GLContext};
+#[cfg(feature = "gl45")]
+use crate::raw::{DEBUG_SOURCE_APPLICATION, DONT_CARE as GL_DONT_CARE};
+#[cfg(feature = "gl45")]
+use crate::types::{DebugMessage, DebugSeverity, DebugSource, DebugType, LabeledObject, SyncID};
+use crate::GLContext;
 
 
 /// Copies a message returned by OpenGL into an owned Rust [`String`]. Panics if any invariants are unmet.
+#[cfg(feature = "gl45")]
 unsafe fn msg_to_string(str: *const GLchar, len: GLsizei) -> String {
     // From OpenGL Spec 4.6 section 20.2:
     // > The memory for `message` is owned and managed by the GL, and should only be considered valid for the
@@ -30,7 +43,7 @@ unsafe fn msg_to_string(str: *const GLchar, len: GLsizei) -> String {
     // - OpenGL spec asserts that the message is valid for the duration of the callback (this function), long
     //   enough to read from
     // - We do not mutate the slice, only copy from it.
-    let msg_slice: &[GLchar] = unsafe { slice::from_raw_parts(str, len) };
+    let msg_slice: &[GLchar] = unsafe { std::slice::from_raw_parts(str, len) };
 
     // Hand-rolled "lossy" conversion into UTF-8 string
     msg_slice
@@ -45,8 +58,128 @@ unsafe fn msg_to_string(str: *const GLchar, len: GLsizei) -> String {
 }
 
 
+/// Reads an object label with one of the `glGetObject*Label` functions, which is given the buffer size, a pointer to
+/// write the label's length to, and a pointer to write the label to.
+#[cfg(feature = "gl45")]
+fn read_label(get_label: impl Fn(GLsizei, *mut GLsizei, *mut GLchar)) -> String {
+    // With a null buffer, OpenGL only returns the length of the label, not including its null terminator.
+    let mut len = 0;
+    get_label(0, &mut len, std::ptr::null_mut());
+
+    if len <= 0 {
+        return String::new();
+    }
+
+    let mut buf = vec![0u8; len as usize + 1];
+    let buf_size = convert!(buf.len(), GLsizei, "object label buffer size");
+    get_label(buf_size, &mut len, buf.as_mut_ptr().cast());
+
+    buf.truncate(len.max(0) as usize);
+    String::from_utf8_lossy(&buf).into_owned()
+}
+
+
 impl GLContext {
+    /// Gives an object a human-readable label, which is used in place of its raw name in debug messages and by graphics
+    /// debuggers. The label must be shorter than [`MaxLabelLength`][crate::params::MaxLabelLength].
+    #[cfg(feature = "gl45")]
+    pub fn object_label<O: LabeledObject>(&self, object: O, label: &str) {
+        let len = convert!(label.len(), GLsizei, "object label length");
+        let identifier = O::IDENTIFIER.into_raw();
+        unsafe { self.gl.object_label(identifier, object.raw_name(), len, label.as_ptr().cast()) }
+    }
+
+
+    /// Removes an object's label.
+    #[cfg(feature = "gl45")]
+    pub fn remove_object_label<O: LabeledObject>(&self, object: O) {
+        let identifier = O::IDENTIFIER.into_raw();
+        unsafe { self.gl.object_label(identifier, object.raw_name(), 0, std::ptr::null()) }
+    }
+
+
+    /// Gets an object's label, or an empty string if it doesn't have one.
+    #[cfg(feature = "gl45")]
+    pub fn get_object_label<O: LabeledObject>(&self, object: O) -> String {
+        let (identifier, name) = (O::IDENTIFIER.into_raw(), object.raw_name());
+        read_label(|buf_size, len, label| unsafe { self.gl.get_object_label(identifier, name, buf_size, len, label) })
+    }
+
+
+    /// Gives a sync object a human-readable label. See [`object_label`][Self::object_label].
+    #[cfg(feature = "gl45")]
+    pub fn sync_label(&self, sync: SyncID, label: &str) {
+        let len = convert!(label.len(), GLsizei, "object label length");
+        unsafe { self.gl.object_ptr_label(sync.into_raw().cast(), len, label.as_ptr().cast()) }
+    }
+
+
+    /// Gets a sync object's label, or an empty string if it doesn't have one.
+    #[cfg(feature = "gl45")]
+    pub fn get_sync_label(&self, sync: SyncID) -> String {
+        let sync = sync.into_raw().cast();
+        read_label(|buf_size, len, label| unsafe { self.gl.get_object_ptr_label(sync, buf_size, len, label) })
+    }
+
+
+    /// Inserts a message into the debug output, which is delivered to the debug callback and the message log like any
+    /// other. This is useful for marking points of interest in a trace of the application's OpenGL calls.
+    ///
+    /// The source must be either [`DebugSource::Application`] or [`DebugSource::ThirdParty`], and the message must be
+    /// shorter than [`MaxDebugMessageLength`].
+    #[cfg(feature = "gl45")]
+    pub fn debug_message_insert(
+        &self,
+        source: DebugSource,
+        typ: DebugType,
+        id: u32,
+        severity: DebugSeverity,
+        message: &str,
+    ) {
+        let len = convert!(message.len(), GLsizei, "debug message length");
+        let (src, typ, sev) = (source.into_raw(), typ.into_raw(), severity.into_raw());
+        unsafe { self.gl.debug_message_insert(src, typ, id, sev, len, message.as_ptr().cast()) }
+    }
+
+
+    /// Pushes a named debug group onto the debug group stack. Until it is popped, debug messages are attributed to this
+    /// group, and graphics debuggers show the commands issued inside of it as a nested scope.
+    ///
+    /// Pushing a group also inserts a [`DebugType::PushGroup`] message with the given ID and message into the debug
+    /// output. Most of the time, [`debug_group`][Self::debug_group] is more convenient, since it pops the group
+    /// automatically.
+    #[cfg(feature = "gl45")]
+    pub fn push_debug_group(&self, id: u32, message: &str) {
+        let len = convert!(message.len(), GLsizei, "debug group message length");
+        let message = message.as_ptr().cast();
+        unsafe { self.gl.push_debug_group(DEBUG_SOURCE_APPLICATION, id, len, message) }
+    }
+
+
+    /// Pops the debug group most recently pushed with [`push_debug_group`][Self::push_debug_group].
+    #[cfg(feature = "gl45")]
+    pub fn pop_debug_group(&self) {
+        unsafe { self.gl.pop_debug_group() }
+    }
+
+
+    /// Pushes a named debug group which is popped when the returned guard is dropped. See
+    /// [`push_debug_group`][Self::push_debug_group].
+    ///
+    /// ```ignore
+    /// {
+    ///     let _group = gl.debug_group("shadow pass");
+    ///     // ... draw calls show up under "shadow pass" ...
+    /// }
+    /// ```
+    #[cfg(feature = "gl45")]
+    pub fn debug_group(&self, message: &str) -> DebugGroup<'_> {
+        DebugGroup::new(self, message)
+    }
+
+
     /// Untested.
+    #[cfg(feature = "gl45")]
     pub fn debug_message_control(&self, filter: DebugFilter, enabled: bool) {
         let enabled = convert!(enabled, GLboolean, "'enabled' boolean");
         let (src, typ, sev, count, ids) = match filter {
@@ -69,23 +202,16 @@ impl GLContext {
         unsafe { self.gl.debug_message_control(src, typ, sev, count, ids, enabled) }
     }
 
+
     /// WIP/untested. Contains a lot of unsafe code, so it should be tested.
+    #[cfg(feature = "gl45")]
     pub fn get_debug_message_log(&self, count: usize) -> Vec<DebugMessage> {
         // Borrows pretty heavily from the example at: https://www.khronos.org/opengl/wiki/Debug_Output.
 
-        // TODO: actually implement `glGet*`, with some nice enum stuff (or triaits!!)
-        let max_msg_len = {
-            let mut n: GLint = 0;
-            unsafe {
-                self.gl.get_integer_v(GL_MAX_DEBUG_MESSAGE_LENGTH, from_mut(&mut n));
-            }
-
-            if n <= 0 {
-                // should never happen, but w/e
-                return vec![];
-            } else {
-                n as usize
-            }
+        let max_msg_len = match self.get(MaxDebugMessageLength) {
+            // should never happen, but w/e
+            n if n <= 0 => return vec![],
+            n => n as usize,
         };
 
         let mut text_data: Vec<GLchar> = Vec::with_capacity(count * max_msg_len);
@@ -145,11 +271,13 @@ impl GLContext {
         messages
     }
 
+
     /// This function requires a mutable reference to the context because the callback closure, if it captures anything,
     /// must be kept alive for as long as OpenGL may call the debug callback. If this function is called twice, the
     /// callback from the first call is replaced and its closure is dropped.
     ///
     /// [`glDebugMessageCallback`]: https://registry.khronos.org/OpenGL-Refpages/gl4/html/glDebugMessageCallback.xhtml
+    #[cfg(feature = "gl45")]
     pub fn debug_message_callback<F: FnMut(DebugMessage) + Sync + 'static>(&mut self, callback: F) {
         /// This is the function that actually receives OpenGL's callback.
         extern "system" fn intercept_debug_callback<C: FnMut(DebugMessage) + Sync + 'static>(
@@ -211,9 +339,11 @@ impl GLContext {
         self.debug_callback = Some(callback);
     }
 
+
     /// Unsets the debug message callback which was previously established by [`Self::debug_message_callback`].
     ///
     /// If the previous callback was an owning closure, it will be dropped after this function is called.
+    #[cfg(feature = "gl45")]
     pub fn unset_debug_message_callback(&mut self) {
         unsafe {
             self.gl.debug_message_callback(None, std::ptr::null());
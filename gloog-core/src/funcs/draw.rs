@@ -0,0 +1,313 @@
+use crate::raw::types::*;
+use crate::types::*;
+use crate::{convert, GLContext};
+
+
+/// Size of an indirect command, for when a stride of zero (tightly packed) is given.
+#[cfg(feature = "gl45")]
+fn command_stride<T>(stride: usize) -> GLsizei {
+    let stride = if stride == 0 { std::mem::size_of::<T>() } else { stride };
+    convert!(stride, GLsizei, "indirect command stride")
+}
+
+
+impl GLContext {
+    pub fn draw_arrays(&self, mode: DrawMode, first: usize, count: usize) {
+        let first = convert!(first, GLint, "draw arrays index");
+        let count = convert!(count, GLsizei, "draw arrays count");
+        unsafe { self.gl.draw_arrays(mode.into_raw(), first, count) }
+    }
+
+
+    /// Draws `instance_count` instances of a range of vertices.
+    pub fn draw_arrays_instanced(&self, mode: DrawMode, first: usize, count: usize, instance_count: usize) {
+        let first = convert!(first, GLint, "draw arrays index");
+        let count = convert!(count, GLsizei, "draw arrays count");
+        let instances = convert!(instance_count, GLsizei, "draw instance count");
+        unsafe { self.gl.draw_arrays_instance_d(mode.into_raw(), first, count, instances) }
+    }
+
+
+    /// Draws `instance_count` instances of a range of vertices, with instance numbers (for the purpose of reading
+    /// instanced attributes) starting from `base_instance`.
+    #[cfg(feature = "gl45")]
+    pub fn draw_arrays_instanced_base_instance(
+        &self,
+        mode: DrawMode,
+        first: usize,
+        count: usize,
+        instance_count: usize,
+        base_instance: u32,
+    ) {
+        let first = convert!(first, GLint, "draw arrays index");
+        let count = convert!(count, GLsizei, "draw arrays count");
+        let instances = convert!(instance_count, GLsizei, "draw instance count");
+        unsafe {
+            let gl = &self.gl;
+            gl.draw_arrays_instanced_base_instance(mode.into_raw(), first, count, instances, base_instance);
+        }
+    }
+
+
+    /// Draws several ranges of vertices at once. `firsts` and `counts` hold the start and length of each range.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `firsts` and `counts` are not the same length.
+    pub fn multi_draw_arrays(&self, mode: DrawMode, firsts: &[i32], counts: &[i32]) {
+        assert_eq!(firsts.len(), counts.len(), "multi-draw should have as many first indices as counts");
+        let draw_count = convert!(firsts.len(), GLsizei, "multi-draw count");
+        let (firsts, counts) = (firsts.as_ptr(), counts.as_ptr());
+        unsafe { self.gl.multi_draw_arrays(mode.into_raw(), firsts, counts, draw_count) }
+    }
+
+
+    /// Draws using the [`DrawArraysIndirectCommand`] found `offset` bytes into the buffer bound to
+    /// [`BufferTarget::DrawIndirectBuffer`].
+    #[cfg(feature = "gl41")]
+    pub fn draw_arrays_indirect(&self, mode: DrawMode, offset: usize) {
+        let indirect = offset as *const _;
+        unsafe { self.gl.draw_arrays_indirect(mode.into_raw(), indirect) }
+    }
+
+
+    /// Draws using `draw_count` [`DrawArraysIndirectCommand`]s, starting `offset` bytes into the buffer bound to
+    /// [`BufferTarget::DrawIndirectBuffer`] and `stride` bytes apart. A stride of zero means that the commands are
+    /// tightly packed.
+    #[cfg(feature = "gl45")]
+    pub fn multi_draw_arrays_indirect(&self, mode: DrawMode, offset: usize, draw_count: usize, stride: usize) {
+        let indirect = offset as *const _;
+        let draw_count = convert!(draw_count, GLsizei, "multi-draw count");
+        let stride = command_stride::<DrawArraysIndirectCommand>(stride);
+        let mode = mode.into_raw();
+        unsafe { self.gl.multi_draw_arrays_indirect(mode, indirect, draw_count, stride) }
+    }
+
+
+    pub fn draw_elements(&self, mode: DrawMode, count: usize, ty: DrawElementsType, offset: usize) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let indices = offset as *const _;
+        unsafe { self.gl.draw_elements(mode.into_raw(), count, ty.into_raw(), indices) }
+    }
+
+
+    /// Draws a range of indices from the current element buffer, adding `base_vertex` to each index before reading
+    /// from the vertex buffers. As with [`draw_elements`][Self::draw_elements], `offset` is in bytes.
+    pub fn draw_elements_base_vertex(
+        &self,
+        mode: DrawMode,
+        count: usize,
+        ty: DrawElementsType,
+        offset: usize,
+        base_vertex: i32,
+    ) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let (mode, ty, indices) = (mode.into_raw(), ty.into_raw(), offset as *const _);
+        unsafe { self.gl.draw_elements_base_vertex(mode, count, ty, indices, base_vertex) }
+    }
+
+
+    /// Draws `instance_count` instances of a range of indices from the current element buffer.
+    pub fn draw_elements_instanced(
+        &self,
+        mode: DrawMode,
+        count: usize,
+        ty: DrawElementsType,
+        offset: usize,
+        instance_count: usize,
+    ) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let instances = convert!(instance_count, GLsizei, "draw instance count");
+        let (mode, ty, indices) = (mode.into_raw(), ty.into_raw(), offset as *const _);
+        unsafe { self.gl.draw_elements_instance_d(mode, count, ty, indices, instances) }
+    }
+
+
+    /// Draws `instance_count` instances of a range of indices from the current element buffer, adding `base_vertex`
+    /// to each index.
+    pub fn draw_elements_instanced_base_vertex(
+        &self,
+        mode: DrawMode,
+        count: usize,
+        ty: DrawElementsType,
+        offset: usize,
+        instance_count: usize,
+        base_vertex: i32,
+    ) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let instances = convert!(instance_count, GLsizei, "draw instance count");
+        let (mode, ty, indices) = (mode.into_raw(), ty.into_raw(), offset as *const _);
+        unsafe {
+            let gl = &self.gl;
+            gl.draw_elements_instanced_base_vertex(mode, count, ty, indices, instances, base_vertex);
+        }
+    }
+
+
+    /// Draws `instance_count` instances of a range of indices from the current element buffer, with instance numbers
+    /// starting from `base_instance`.
+    #[cfg(feature = "gl45")]
+    pub fn draw_elements_instanced_base_instance(
+        &self,
+        mode: DrawMode,
+        count: usize,
+        ty: DrawElementsType,
+        offset: usize,
+        instance_count: usize,
+        base_instance: u32,
+    ) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let instances = convert!(instance_count, GLsizei, "draw instance count");
+        let (mode, ty, indices) = (mode.into_raw(), ty.into_raw(), offset as *const _);
+        unsafe {
+            let gl = &self.gl;
+            gl.draw_elements_instanced_base_instance(mode, count, ty, indices, instances, base_instance);
+        }
+    }
+
+
+    /// Draws `instance_count` instances of a range of indices from the current element buffer, adding `base_vertex`
+    /// to each index and with instance numbers starting from `base_instance`.
+    #[cfg(feature = "gl45")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_elements_instanced_base_vertex_base_instance(
+        &self,
+        mode: DrawMode,
+        count: usize,
+        ty: DrawElementsType,
+        offset: usize,
+        instance_count: usize,
+        base_vertex: i32,
+        base_instance: u32,
+    ) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let instances = convert!(instance_count, GLsizei, "draw instance count");
+        let (mode, ty, indices) = (mode.into_raw(), ty.into_raw(), offset as *const _);
+        unsafe {
+            let gl = &self.gl;
+            gl.draw_elements_instanced_base_vertex_base_instance(
+                mode,
+                count,
+                ty,
+                indices,
+                instances,
+                base_vertex,
+                base_instance,
+            );
+        }
+    }
+
+
+    /// Draws a range of indices from the current element buffer, promising that every index in the range is between
+    /// `start` and `end` (inclusive). Some implementations can use this to avoid scanning the indices first.
+    pub fn draw_range_elements(
+        &self,
+        mode: DrawMode,
+        start: u32,
+        end: u32,
+        count: usize,
+        ty: DrawElementsType,
+        offset: usize,
+    ) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let (mode, ty, indices) = (mode.into_raw(), ty.into_raw(), offset as *const _);
+        unsafe { self.gl.draw_range_elements(mode, start, end, count, ty, indices) }
+    }
+
+
+    /// Draws a range of indices from the current element buffer, promising that every index in the range (before
+    /// `base_vertex` is added) is between `start` and `end` (inclusive).
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_range_elements_base_vertex(
+        &self,
+        mode: DrawMode,
+        start: u32,
+        end: u32,
+        count: usize,
+        ty: DrawElementsType,
+        offset: usize,
+        base_vertex: i32,
+    ) {
+        let count = convert!(count, GLsizei, "draw elements count");
+        let (mode, ty, indices) = (mode.into_raw(), ty.into_raw(), offset as *const _);
+        unsafe {
+            let gl = &self.gl;
+            gl.draw_range_elements_base_vertex(mode, start, end, count, ty, indices, base_vertex);
+        }
+    }
+
+
+    /// Draws several ranges of indices from the current element buffer at once. `counts` and `offsets` hold the length
+    /// of each range and its offset into the element buffer, in bytes.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `counts` and `offsets` are not the same length.
+    pub fn multi_draw_elements(&self, mode: DrawMode, counts: &[i32], ty: DrawElementsType, offsets: &[usize]) {
+        assert_eq!(counts.len(), offsets.len(), "multi-draw should have as many counts as offsets");
+        let draw_count = convert!(counts.len(), GLsizei, "multi-draw count");
+        let indices = offsets.iter().map(|&offset| offset as *const _).collect::<Vec<_>>();
+        let (mode, ty) = (mode.into_raw(), ty.into_raw());
+        let (counts, indices) = (counts.as_ptr(), indices.as_ptr());
+        unsafe { self.gl.multi_draw_elements(mode, counts, ty, indices, draw_count) }
+    }
+
+
+    /// Draws several ranges of indices from the current element buffer at once, adding the corresponding entry of
+    /// `base_vertices` to each range's indices.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `counts`, `offsets`, and `base_vertices` are not all the same length.
+    pub fn multi_draw_elements_base_vertex(
+        &self,
+        mode: DrawMode,
+        counts: &[i32],
+        ty: DrawElementsType,
+        offsets: &[usize],
+        base_vertices: &[i32],
+    ) {
+        assert_eq!(counts.len(), offsets.len(), "multi-draw should have as many counts as offsets");
+        assert_eq!(counts.len(), base_vertices.len(), "multi-draw should have as many counts as base vertices");
+        let draw_count = convert!(counts.len(), GLsizei, "multi-draw count");
+        let indices = offsets.iter().map(|&offset| offset as *const _).collect::<Vec<_>>();
+        let (mode, ty) = (mode.into_raw(), ty.into_raw());
+        let (counts, indices, base_vertices) = (counts.as_ptr(), indices.as_ptr(), base_vertices.as_ptr());
+        unsafe {
+            let gl = &self.gl;
+            gl.multi_draw_elements_base_vertex(mode, counts, ty, indices, draw_count, base_vertices);
+        }
+    }
+
+
+    /// Draws using the [`DrawElementsIndirectCommand`] found `offset` bytes into the buffer bound to
+    /// [`BufferTarget::DrawIndirectBuffer`].
+    #[cfg(feature = "gl41")]
+    pub fn draw_elements_indirect(&self, mode: DrawMode, ty: DrawElementsType, offset: usize) {
+        let indirect = offset as *const _;
+        unsafe { self.gl.draw_elements_indirect(mode.into_raw(), ty.into_raw(), indirect) }
+    }
+
+
+    /// Draws using `draw_count` [`DrawElementsIndirectCommand`]s, starting `offset` bytes into the buffer bound to
+    /// [`BufferTarget::DrawIndirectBuffer`] and `stride` bytes apart. A stride of zero means that the commands are
+    /// tightly packed.
+    ///
+    /// Each command is a separate draw, which shaders can tell apart with `gl_DrawID`; so, for example, all of the
+    /// material groups of a model can be drawn in one call by indexing an array of materials with it.
+    #[cfg(feature = "gl45")]
+    pub fn multi_draw_elements_indirect(
+        &self,
+        mode: DrawMode,
+        ty: DrawElementsType,
+        offset: usize,
+        draw_count: usize,
+        stride: usize,
+    ) {
+        let indirect = offset as *const _;
+        let draw_count = convert!(draw_count, GLsizei, "multi-draw count");
+        let stride = command_stride::<DrawElementsIndirectCommand>(stride);
+        let (mode, ty) = (mode.into_raw(), ty.into_raw());
+        unsafe { self.gl.multi_draw_elements_indirect(mode, ty, indirect, draw_count, stride) }
+    }
+}
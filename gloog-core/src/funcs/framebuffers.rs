@@ -0,0 +1,297 @@
+use crate::raw::types::*;
+#[cfg(feature = "gl45")]
+use crate::raw::{COLOR, DEPTH, DEPTH_STENCIL, RENDERBUFFER, STENCIL};
+use crate::types::*;
+use crate::{convert, GLContext};
+
+
+impl GLContext {
+    #[cfg(feature = "gl45")]
+    pub fn create_framebuffer(&self) -> FramebufferID {
+        let mut name = 0;
+        unsafe { self.gl.create_framebuffers(1, &mut name) };
+        FramebufferID::new(name)
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn create_framebuffers(&self, n: usize) -> Vec<FramebufferID> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let mut names = vec![0; n];
+        let n = convert!(n, GLsizei, "number of framebuffers");
+
+        unsafe { self.gl.create_framebuffers(n, names.as_mut_ptr()) };
+        names.into_iter().map(FramebufferID::new).collect()
+    }
+
+
+    pub fn delete_framebuffer(&self, framebuffer: FramebufferID) {
+        unsafe { self.gl.delete_framebuffers(1, &framebuffer.into_raw()) }
+    }
+
+
+    pub fn delete_framebuffers(&self, framebuffers: &[FramebufferID]) {
+        let len = convert!(framebuffers.len(), GLsizei, "number of framebuffers");
+        let ptr = framebuffers.as_ptr().cast(); // cast is safe because `FramebufferID` is `repr(transparent)`
+        unsafe { self.gl.delete_framebuffers(len, ptr) }
+    }
+
+
+    pub fn bind_framebuffer(&self, target: FramebufferTarget, framebuffer: FramebufferID) {
+        unsafe { self.gl.bind_framebuffer(target.into_raw(), framebuffer.into_raw()) }
+    }
+
+
+    /// Binds the [default framebuffer][FramebufferID::DEFAULT] to the given target.
+    pub fn unbind_framebuffer(&self, target: FramebufferTarget) {
+        unsafe { self.gl.bind_framebuffer(target.into_raw(), 0) }
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn create_renderbuffer(&self) -> RenderbufferID {
+        let mut name = 0;
+        unsafe { self.gl.create_renderbuffers(1, &mut name) };
+        RenderbufferID::new(name)
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn create_renderbuffers(&self, n: usize) -> Vec<RenderbufferID> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let mut names = vec![0; n];
+        let n = convert!(n, GLsizei, "number of renderbuffers");
+
+        unsafe { self.gl.create_renderbuffers(n, names.as_mut_ptr()) };
+        names.into_iter().map(RenderbufferID::new).collect()
+    }
+
+
+    pub fn delete_renderbuffer(&self, renderbuffer: RenderbufferID) {
+        unsafe { self.gl.delete_renderbuffers(1, &renderbuffer.into_raw()) }
+    }
+
+
+    pub fn delete_renderbuffers(&self, renderbuffers: &[RenderbufferID]) {
+        let len = convert!(renderbuffers.len(), GLsizei, "number of renderbuffers");
+        let ptr = renderbuffers.as_ptr().cast(); // cast is safe because `RenderbufferID` is `repr(transparent)`
+        unsafe { self.gl.delete_renderbuffers(len, ptr) }
+    }
+
+
+    /// Allocates storage for a renderbuffer.
+    #[cfg(feature = "gl45")]
+    pub fn named_renderbuffer_storage(
+        &self,
+        renderbuffer: RenderbufferID,
+        format: InternalFormat,
+        width: usize,
+        height: usize,
+    ) {
+        let width = convert!(width, GLsizei, "renderbuffer width");
+        let height = convert!(height, GLsizei, "renderbuffer height");
+        let (rb, format) = (renderbuffer.into_raw(), format.into_raw());
+        unsafe { self.gl.named_renderbuffer_storage(rb, format, width, height) }
+    }
+
+
+    /// Allocates multisampled storage for a renderbuffer. A sample count of zero allocates regular, single-sampled
+    /// storage.
+    #[cfg(feature = "gl45")]
+    pub fn named_renderbuffer_storage_multisample(
+        &self,
+        renderbuffer: RenderbufferID,
+        samples: usize,
+        format: InternalFormat,
+        width: usize,
+        height: usize,
+    ) {
+        let samples = convert!(samples, GLsizei, "number of renderbuffer samples");
+        let width = convert!(width, GLsizei, "renderbuffer width");
+        let height = convert!(height, GLsizei, "renderbuffer height");
+        let (rb, format) = (renderbuffer.into_raw(), format.into_raw());
+        let (w, h) = (width, height);
+        unsafe { self.gl.named_renderbuffer_storage_multisample(rb, samples, format, w, h) }
+    }
+
+
+    /// Attaches a level of a texture to a framebuffer.
+    ///
+    /// If the texture is a 3D, array, or cube map texture, all of its layers are attached, making this a _layered_
+    /// attachment that a geometry shader can select layers of with `gl_Layer`.
+    #[cfg(feature = "gl45")]
+    pub fn named_framebuffer_texture(
+        &self,
+        framebuffer: FramebufferID,
+        attachment: FramebufferAttachment,
+        texture: TextureID,
+        level: usize,
+    ) {
+        let level = convert!(level, GLint, "texture level");
+        let (fb, attachment) = (framebuffer.into_raw(), attachment.into_raw());
+        unsafe { self.gl.named_framebuffer_texture(fb, attachment, texture.into_raw(), level) }
+    }
+
+
+    /// Attaches a single layer of a level of a 3D, array, or cube map texture to a framebuffer. For cube maps, see
+    /// [`CubeMapFace::layer`].
+    #[cfg(feature = "gl45")]
+    pub fn named_framebuffer_texture_layer(
+        &self,
+        framebuffer: FramebufferID,
+        attachment: FramebufferAttachment,
+        texture: TextureID,
+        level: usize,
+        layer: usize,
+    ) {
+        let level = convert!(level, GLint, "texture level");
+        let layer = convert!(layer, GLint, "texture layer");
+        let (fb, attachment, tex) = (framebuffer.into_raw(), attachment.into_raw(), texture.into_raw());
+        unsafe { self.gl.named_framebuffer_texture_layer(fb, attachment, tex, level, layer) }
+    }
+
+
+    /// Attaches a renderbuffer to a framebuffer.
+    #[cfg(feature = "gl45")]
+    pub fn named_framebuffer_renderbuffer(
+        &self,
+        framebuffer: FramebufferID,
+        attachment: FramebufferAttachment,
+        renderbuffer: RenderbufferID,
+    ) {
+        let (fb, attachment, rb) = (framebuffer.into_raw(), attachment.into_raw(), renderbuffer.into_raw());
+        unsafe { self.gl.named_framebuffer_renderbuffer(fb, attachment, RENDERBUFFER, rb) }
+    }
+
+
+    /// Removes whatever image is attached to the given attachment point of a framebuffer.
+    #[cfg(feature = "gl45")]
+    pub fn named_framebuffer_detach(&self, framebuffer: FramebufferID, attachment: FramebufferAttachment) {
+        let (fb, attachment) = (framebuffer.into_raw(), attachment.into_raw());
+        unsafe { self.gl.named_framebuffer_texture(fb, attachment, 0, 0) }
+    }
+
+
+    /// Selects the color buffer that fragment shader output zero is written to.
+    #[cfg(feature = "gl45")]
+    pub fn named_framebuffer_draw_buffer(&self, framebuffer: FramebufferID, buffer: ColorBuffer) {
+        unsafe { self.gl.named_framebuffer_draw_buffer(framebuffer.into_raw(), buffer.into_raw()) }
+    }
+
+
+    /// Selects the color buffers that each fragment shader output is written to, in order.
+    #[cfg(feature = "gl45")]
+    pub fn named_framebuffer_draw_buffers(&self, framebuffer: FramebufferID, buffers: &[ColorBuffer]) {
+        let n = convert!(buffers.len(), GLsizei, "number of draw buffers");
+        let ptr = buffers.as_ptr().cast(); // cast is safe because `ColorBuffer` is `repr(u32)`
+        unsafe { self.gl.named_framebuffer_draw_buffers(framebuffer.into_raw(), n, ptr) }
+    }
+
+
+    /// Selects the color buffer that pixel reads and blits read from.
+    #[cfg(feature = "gl45")]
+    pub fn named_framebuffer_read_buffer(&self, framebuffer: FramebufferID, buffer: ColorBuffer) {
+        unsafe { self.gl.named_framebuffer_read_buffer(framebuffer.into_raw(), buffer.into_raw()) }
+    }
+
+
+    /// Checks whether a framebuffer is complete, and can be used with the given target.
+    #[cfg(feature = "gl45")]
+    pub fn check_named_framebuffer_status(
+        &self,
+        framebuffer: FramebufferID,
+        target: FramebufferTarget,
+    ) -> Result<(), FramebufferError> {
+        let (fb, target) = (framebuffer.into_raw(), target.into_raw());
+        let status = unsafe { self.gl.check_named_framebuffer_status(fb, target) };
+        FramebufferError::check(status)
+    }
+
+
+    /// Copies a rectangle of pixels from one framebuffer to another.
+    ///
+    /// Rectangles are given as `[x0, y0, x1, y1]`. If the source and destination rectangles are different sizes, the
+    /// image is stretched using the given filter; swapping the corners of one rectangle flips the image along that
+    /// axis. Color is copied from the read framebuffer's [read buffer] into all of the draw framebuffer's draw buffers.
+    ///
+    /// [read buffer]: Self::named_framebuffer_read_buffer
+    #[cfg(feature = "gl45")]
+    pub fn blit_named_framebuffer(
+        &self,
+        read_framebuffer: FramebufferID,
+        draw_framebuffer: FramebufferID,
+        src: [i32; 4],
+        dst: [i32; 4],
+        mask: ClearMask,
+        filter: BlitFilter,
+    ) {
+        let (read, draw) = (read_framebuffer.into_raw(), draw_framebuffer.into_raw());
+        let [sx0, sy0, sx1, sy1] = src;
+        let [dx0, dy0, dx1, dy1] = dst;
+        let (mask, filter) = (mask.into_raw(), filter.into_raw());
+        unsafe {
+            let gl = &self.gl;
+            gl.blit_named_framebuffer(read, draw, sx0, sy0, sx1, sy1, dx0, dy0, dx1, dy1, mask, filter);
+        }
+    }
+
+
+    /// Clears one of a framebuffer's color buffers to the given floating-point (or normalized integer) color.
+    ///
+    /// `draw_buffer` is an index into the list of buffers set with [`named_framebuffer_draw_buffers`], _not_ a color
+    /// attachment index.
+    ///
+    /// [`named_framebuffer_draw_buffers`]: Self::named_framebuffer_draw_buffers
+    #[cfg(feature = "gl45")]
+    pub fn clear_named_framebuffer_color(&self, framebuffer: FramebufferID, draw_buffer: usize, color: [f32; 4]) {
+        let draw_buffer = convert!(draw_buffer, GLint, "draw buffer index");
+        let (fb, ptr) = (framebuffer.into_raw(), color.as_ptr());
+        unsafe { self.gl.clear_named_framebuffer_fv(fb, COLOR, draw_buffer, ptr) }
+    }
+
+
+    /// Clears one of a framebuffer's signed integer color buffers to the given value.
+    #[cfg(feature = "gl45")]
+    pub fn clear_named_framebuffer_color_i(&self, framebuffer: FramebufferID, draw_buffer: usize, color: [i32; 4]) {
+        let draw_buffer = convert!(draw_buffer, GLint, "draw buffer index");
+        let (fb, ptr) = (framebuffer.into_raw(), color.as_ptr());
+        unsafe { self.gl.clear_named_framebuffer_iv(fb, COLOR, draw_buffer, ptr) }
+    }
+
+
+    /// Clears one of a framebuffer's unsigned integer color buffers to the given value.
+    #[cfg(feature = "gl45")]
+    pub fn clear_named_framebuffer_color_ui(&self, framebuffer: FramebufferID, draw_buffer: usize, color: [u32; 4]) {
+        let draw_buffer = convert!(draw_buffer, GLint, "draw buffer index");
+        let (fb, ptr) = (framebuffer.into_raw(), color.as_ptr());
+        unsafe { self.gl.clear_named_framebuffer_uiv(fb, COLOR, draw_buffer, ptr) }
+    }
+
+
+    /// Clears a framebuffer's depth buffer to the given value.
+    #[cfg(feature = "gl45")]
+    pub fn clear_named_framebuffer_depth(&self, framebuffer: FramebufferID, depth: f32) {
+        unsafe { self.gl.clear_named_framebuffer_fv(framebuffer.into_raw(), DEPTH, 0, &depth) }
+    }
+
+
+    /// Clears a framebuffer's stencil buffer to the given value.
+    #[cfg(feature = "gl45")]
+    pub fn clear_named_framebuffer_stencil(&self, framebuffer: FramebufferID, stencil: i32) {
+        unsafe { self.gl.clear_named_framebuffer_iv(framebuffer.into_raw(), STENCIL, 0, &stencil) }
+    }
+
+
+    /// Clears a framebuffer's depth and stencil buffers at the same time.
+    #[cfg(feature = "gl45")]
+    pub fn clear_named_framebuffer_depth_stencil(&self, framebuffer: FramebufferID, depth: f32, stencil: i32) {
+        let fb = framebuffer.into_raw();
+        unsafe { self.gl.clear_named_framebufferf_i(fb, DEPTH_STENCIL, 0, depth, stencil) }
+    }
+}
@@ -0,0 +1,245 @@
+#[cfg(feature = "gl45")]
+use std::ffi::CString;
+
+#[cfg(feature = "gl45")]
+use crate::convert;
+#[cfg(feature = "gl45")]
+use crate::raw::types::*;
+#[cfg(feature = "gl45")]
+use crate::raw::{ACTIVE_RESOURCES, INVALID_INDEX, MAX_NAME_LENGTH};
+use crate::types::*;
+use crate::GLContext;
+
+
+/// Converts an integer property that is `-1` when it does not apply into an `Option`.
+#[cfg(feature = "gl45")]
+fn non_negative(value: GLint) -> Option<usize> {
+    (value >= 0).then_some(value as usize)
+}
+
+/// Converts a stride property that is `-1` or `0` when it does not apply into an `Option`.
+#[cfg(feature = "gl45")]
+fn positive(value: GLint) -> Option<usize> {
+    (value > 0).then_some(value as usize)
+}
+
+
+impl GLContext {
+    /// Gets the number of active resources in one of a program's interfaces.
+    #[cfg(feature = "gl45")]
+    pub fn get_program_interface_active_resources(&self, program: ProgramID, interface: ProgramInterface) -> usize {
+        let mut count = 0;
+        let (prog, interface) = (program.into_raw(), interface.into_raw());
+        unsafe { self.gl.get_program_interface_iv(prog, interface, ACTIVE_RESOURCES, &mut count) };
+        count as usize
+    }
+
+
+    /// Gets the length of the longest name of any active resource in one of a program's interfaces, including its
+    /// NUL-terminator.
+    #[cfg(feature = "gl45")]
+    pub fn get_program_interface_max_name_length(&self, program: ProgramID, interface: ProgramInterface) -> usize {
+        let mut len = 0;
+        let (prog, interface) = (program.into_raw(), interface.into_raw());
+        unsafe { self.gl.get_program_interface_iv(prog, interface, MAX_NAME_LENGTH, &mut len) };
+        len as usize
+    }
+
+
+    /// Gets the name of an active resource in one of a program's interfaces, by index.
+    #[cfg(feature = "gl45")]
+    pub fn get_program_resource_name(&self, program: ProgramID, interface: ProgramInterface, index: u32) -> String {
+        let [length] = self.get_program_resource(program, interface, index, [ProgramResourceProperty::NameLength]);
+        if length <= 1 {
+            return String::new();
+        }
+
+        let mut buffer = vec![0u8; length as usize];
+        unsafe {
+            let (prog, interface) = (program.into_raw(), interface.into_raw());
+            let (len_ptr, buf_ptr) = (std::ptr::null_mut(), buffer.as_mut_ptr().cast());
+            let gl = &self.gl;
+            gl.get_program_resource_name(prog, interface, index, length, len_ptr, buf_ptr);
+        }
+
+        buffer.pop(); // NUL-terminator
+        String::from_utf8_lossy(&buffer).into()
+    }
+
+
+    /// Gets the values of several properties of an active resource in one of a program's interfaces, by index.
+    ///
+    /// Properties that do not apply to the given interface generate an OpenGL error.
+    #[cfg(feature = "gl45")]
+    pub fn get_program_resource<const N: usize>(
+        &self,
+        program: ProgramID,
+        interface: ProgramInterface,
+        index: u32,
+        properties: [ProgramResourceProperty; N],
+    ) -> [i32; N] {
+        let mut values = [0; N];
+        let n = convert!(N, GLsizei, "number of program resource properties");
+        let (prog, interface) = (program.into_raw(), interface.into_raw());
+        let props = properties.as_ptr().cast(); // cast is safe because `ProgramResourceProperty` is `repr(u32)`
+        let (len_ptr, ptr) = (std::ptr::null_mut(), values.as_mut_ptr());
+        unsafe {
+            let gl = &self.gl;
+            gl.get_program_resource_iv(prog, interface, index, n, props, n, len_ptr, ptr);
+        }
+        values
+    }
+
+
+    /// Looks up the index of an active resource in one of a program's interfaces by name. Returns `None` if there is no
+    /// active resource with the given name.
+    #[cfg(feature = "gl45")]
+    pub fn get_program_resource_index(
+        &self,
+        program: ProgramID,
+        interface: ProgramInterface,
+        name: &str,
+    ) -> Option<u32> {
+        let name = CString::new(name).expect("program resource name should not contain NUL-bytes");
+        let (program, interface) = (program.into_raw(), interface.into_raw());
+        let index = unsafe { self.gl.get_program_resource_index(program, interface, name.as_ptr()) };
+        (index != INVALID_INDEX).then_some(index)
+    }
+
+
+    /// Lists all of a program's active uniforms, including the members of named uniform blocks.
+    ///
+    /// This can be used to check that all of the uniforms that an application expects to set actually exist, instead of
+    /// silently ignoring a `-1` location from [`get_uniform_location`][Self::get_uniform_location]. Keep in mind that
+    /// uniforms which do not contribute to a shader's output may be optimized away.
+    #[cfg(feature = "gl45")]
+    pub fn get_active_uniforms(&self, program: ProgramID) -> Vec<ActiveUniform> {
+        use ProgramResourceProperty::*;
+
+        let interface = ProgramInterface::Uniform;
+        let count = self.get_program_interface_active_resources(program, interface);
+        let properties = [Type, ArraySize, Location, BlockIndex, Offset, ArrayStride, MatrixStride];
+
+        (0..count as u32)
+            .map(|i| {
+                let [ty, size, location, block, offset, array_stride, matrix_stride] =
+                    self.get_program_resource(program, interface, i, properties);
+
+                ActiveUniform {
+                    name: self.get_program_resource_name(program, interface, i),
+                    ty: GlslType::from_raw(ty as u32),
+                    array_size: size as usize,
+                    location: (location >= 0).then_some(UniformLocation::new(location)),
+                    block_index: (block >= 0).then_some(block as u32),
+                    offset: non_negative(offset),
+                    array_stride: positive(array_stride),
+                    matrix_stride: positive(matrix_stride),
+                }
+            })
+            .collect()
+    }
+
+
+    /// Lists all of a program's active vertex attributes (the inputs to its first shader stage).
+    #[cfg(feature = "gl45")]
+    pub fn get_active_attributes(&self, program: ProgramID) -> Vec<ActiveAttribute> {
+        use ProgramResourceProperty::*;
+
+        let interface = ProgramInterface::ProgramInput;
+        let count = self.get_program_interface_active_resources(program, interface);
+        let properties = [Type, ArraySize, Location];
+
+        (0..count as u32)
+            .map(|i| {
+                let [ty, size, location] = self.get_program_resource(program, interface, i, properties);
+
+                ActiveAttribute {
+                    name: self.get_program_resource_name(program, interface, i),
+                    ty: GlslType::from_raw(ty as u32),
+                    array_size: size as usize,
+                    location: (location >= 0).then_some(VertexAttribLocation::new(location as u32)),
+                }
+            })
+            .collect()
+    }
+
+
+    /// Lists all of a program's active uniform blocks.
+    #[cfg(feature = "gl45")]
+    pub fn get_active_uniform_blocks(&self, program: ProgramID) -> Vec<ActiveBlock> {
+        self.get_active_blocks(program, ProgramInterface::UniformBlock)
+    }
+
+
+    /// Lists all of a program's active shader storage blocks.
+    #[cfg(feature = "gl45")]
+    pub fn get_active_shader_storage_blocks(&self, program: ProgramID) -> Vec<ActiveBlock> {
+        self.get_active_blocks(program, ProgramInterface::ShaderStorageBlock)
+    }
+
+
+    #[cfg(feature = "gl45")]
+    fn get_active_blocks(&self, program: ProgramID, interface: ProgramInterface) -> Vec<ActiveBlock> {
+        use ProgramResourceProperty::*;
+
+        let count = self.get_program_interface_active_resources(program, interface);
+        let properties = [BufferBinding, BufferDataSize, NumActiveVariables];
+
+        (0..count as u32)
+            .map(|i| {
+                let [binding, data_size, num_variables] = self.get_program_resource(program, interface, i, properties);
+
+                ActiveBlock {
+                    name: self.get_program_resource_name(program, interface, i),
+                    index: i,
+                    binding: binding as u32,
+                    data_size: data_size as usize,
+                    num_variables: num_variables as usize,
+                }
+            })
+            .collect()
+    }
+
+
+    /// Lists all of the active variables in all of a program's shader storage blocks.
+    #[cfg(feature = "gl45")]
+    pub fn get_active_buffer_variables(&self, program: ProgramID) -> Vec<ActiveBufferVariable> {
+        use ProgramResourceProperty::*;
+
+        let interface = ProgramInterface::BufferVariable;
+        let count = self.get_program_interface_active_resources(program, interface);
+        let properties = [Type, ArraySize, BlockIndex, Offset, ArrayStride, MatrixStride];
+
+        (0..count as u32)
+            .map(|i| {
+                let [ty, size, block, offset, array_stride, matrix_stride] =
+                    self.get_program_resource(program, interface, i, properties);
+
+                ActiveBufferVariable {
+                    name: self.get_program_resource_name(program, interface, i),
+                    ty: GlslType::from_raw(ty as u32),
+                    array_size: size as usize,
+                    block_index: block as u32,
+                    offset: offset as usize,
+                    array_stride: positive(array_stride),
+                    matrix_stride: positive(matrix_stride),
+                }
+            })
+            .collect()
+    }
+
+
+    /// Changes the binding point that one of a program's uniform blocks reads from, overriding any `binding` layout
+    /// qualifier.
+    pub fn uniform_block_binding(&self, program: ProgramID, block_index: u32, binding: u32) {
+        unsafe { self.gl.uniform_block_binding(program.into_raw(), block_index, binding) }
+    }
+
+
+    /// Changes the binding point that one of a program's shader storage blocks reads from, overriding any `binding`
+    /// layout qualifier.
+    #[cfg(feature = "gl45")]
+    pub fn shader_storage_block_binding(&self, program: ProgramID, block_index: u32, binding: u32) {
+        unsafe { self.gl.shader_storage_block_binding(program.into_raw(), block_index, binding) }
+    }
+}
@@ -1,11 +1,24 @@
 pub(crate) mod buffers;
+#[cfg(feature = "gl45")]
+pub(crate) mod compute;
 pub(crate) mod debug;
+pub(crate) mod draw;
+pub(crate) mod framebuffers;
+pub(crate) mod introspection;
+pub(crate) mod pipeline;
+pub(crate) mod pixels;
+pub(crate) mod queries;
+pub(crate) mod samplers;
 pub(crate) mod shaders;
+pub(crate) mod state;
+pub(crate) mod sync;
+pub(crate) mod textures;
 pub(crate) mod uniforms;
 pub(crate) mod vertex;
 
 use std::ffi::CStr;
 
+use crate::params::*;
 use crate::types::*;
 use crate::GLContext;
 
@@ -56,4 +69,74 @@ impl GLContext {
         let str = unsafe { CStr::from_ptr(ptr.cast()) };
         str.to_string_lossy().into_owned()
     }
+
+    /// Queries a piece of OpenGL state. See the [`params`][crate::params] module for the parameters that can be
+    /// queried.
+    ///
+    /// ```ignore
+    /// let max_texture_size = gl.get(params::MaxTextureSize);
+    /// ```
+    pub fn get<P: Parameter>(&self, param: P) -> P::Value {
+        let _ = param;
+        unsafe { P::Value::get(&self.gl, P::PNAME) }
+    }
+
+    /// Queries a piece of indexed OpenGL state, such as the buffer bound to one uniform buffer binding point. See the
+    /// [`params`][crate::params] module for the parameters that can be queried.
+    pub fn get_indexed<P: IndexedParameter>(&self, param: P, index: u32) -> P::Value {
+        let _ = param;
+        unsafe { P::Value::get_indexed(&self.gl, P::PNAME, index) }
+    }
+
+    /// Gathers the version, driver information, supported extensions, and important limits of the current OpenGL
+    /// implementation into a single snapshot.
+    ///
+    /// This makes quite a few queries (one per extension), so it is best called once at startup and kept around.
+    pub fn capabilities(&self) -> Capabilities {
+        let num_extensions = self.get(NumExtensions).max(0) as u32;
+        let extensions = (0..num_extensions)
+            .map(|i| self.get_string_i(IndexedStringName::Extensions, i))
+            .collect();
+
+        let size = |value: i32| value.max(0) as usize;
+        let limits = Limits {
+            max_texture_size: size(self.get(MaxTextureSize)),
+            max_3d_texture_size: size(self.get(Max3DTextureSize)),
+            max_array_texture_layers: size(self.get(MaxArrayTextureLayers)),
+            #[cfg(any(feature = "gl46", feature = "ext-texture-filter-anisotropic"))]
+            max_texture_max_anisotropy: self.get(MaxTextureMaxAnisotropy),
+            max_combined_texture_image_units: size(self.get(MaxCombinedTextureImageUnits)),
+            max_vertex_attribs: size(self.get(MaxVertexAttribs)),
+            max_draw_buffers: size(self.get(MaxDrawBuffers)),
+            max_color_attachments: size(self.get(MaxColorAttachments)),
+            max_samples: size(self.get(MaxSamples)),
+            max_uniform_block_size: size(self.get(MaxUniformBlockSize)),
+            max_uniform_buffer_bindings: size(self.get(MaxUniformBufferBindings)),
+            uniform_buffer_offset_alignment: size(self.get(UniformBufferOffsetAlignment)),
+            #[cfg(feature = "gl45")]
+            max_shader_storage_block_size: self.get(MaxShaderStorageBlockSize).max(0) as usize,
+            #[cfg(feature = "gl45")]
+            max_shader_storage_buffer_bindings: size(self.get(MaxShaderStorageBufferBindings)),
+            #[cfg(feature = "gl45")]
+            shader_storage_buffer_offset_alignment: size(self.get(ShaderStorageBufferOffsetAlignment)),
+            #[cfg(feature = "gl45")]
+            max_compute_work_group_count: self.get_max_compute_work_group_count(),
+            #[cfg(feature = "gl45")]
+            max_compute_work_group_size: self.get_max_compute_work_group_size(),
+            #[cfg(feature = "gl45")]
+            max_debug_message_length: size(self.get(MaxDebugMessageLength)),
+            #[cfg(feature = "gl45")]
+            max_label_length: size(self.get(MaxLabelLength)),
+        };
+
+        Capabilities {
+            version: (self.get(MajorVersion) as u32, self.get(MinorVersion) as u32),
+            version_string: self.get_string(StringName::Version),
+            shading_language_version: self.get_string(StringName::ShadingLanguageVersion),
+            vendor: self.get_string(StringName::Vendor),
+            renderer: self.get_string(StringName::Renderer),
+            extensions,
+            limits,
+        }
+    }
 }
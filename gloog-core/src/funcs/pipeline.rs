@@ -0,0 +1,287 @@
+use crate::types::*;
+use crate::GLContext;
+
+
+impl GLContext {
+    /// Applies a complete [`PipelineState`], binding its program and vertex array and setting all of its blend, depth,
+    /// stencil, and rasterization state.
+    ///
+    /// The context keeps track of the last state applied this way, and only issues the OpenGL calls needed to get from
+    /// that state to the new one. The first time that a state is applied (or the first time after
+    /// [`invalidate_pipeline_state`][Self::invalidate_pipeline_state]), every part of it is set.
+    ///
+    /// While part of the state is disabled (for example, blending when [`BlendState::enabled`] is false), the rest of
+    /// that part is left as it was, since it has no effect. The depth and stencil write masks are the exception: they
+    /// are always set, since they also affect [`clear`][Self::clear].
+    pub fn apply_pipeline_state(&self, state: &PipelineState) {
+        let prev = self.pipeline_state.get();
+        let prev = prev.as_ref();
+
+        if differs(prev, state, |s| s.program()) {
+            match state.program() {
+                Some(program) => self.use_program(program),
+                None => unsafe { self.gl.use_program(0) },
+            }
+        }
+
+        if differs(prev, state, |s| s.vertex_array()) {
+            match state.vertex_array() {
+                Some(vao) => self.bind_vertex_array(vao),
+                None => self.unbind_vertex_array(),
+            }
+        }
+
+        let blend = self.apply_blend_state(prev.map(|s| s.blend()), state.blend());
+        let depth = self.apply_depth_state(prev.map(|s| s.depth()), state.depth());
+        let stencil = self.apply_stencil_state(prev.map(|s| s.stencil()), state.stencil());
+        self.apply_raster_state(prev.map(|s| s.raster()), state.raster());
+
+        let applied = state.with_blend(blend).with_depth(depth).with_stencil(stencil);
+        self.pipeline_state.set(Some(applied));
+    }
+
+
+    /// Gets the state that was most recently applied with [`apply_pipeline_state`][Self::apply_pipeline_state], or
+    /// `None` if no state has been applied since the context was created or last
+    /// [invalidated][Self::invalidate_pipeline_state].
+    ///
+    /// Parts of the state that were disabled when they were applied hold the values that are still set in OpenGL,
+    /// rather than the ones that were given.
+    pub fn pipeline_state(&self) -> Option<PipelineState> {
+        self.pipeline_state.get()
+    }
+
+
+    /// Forgets the most recently applied [`PipelineState`], so that the next call to
+    /// [`apply_pipeline_state`][Self::apply_pipeline_state] sets every part of its state.
+    ///
+    /// State changes made through any other method (such as [`enable`][Self::enable] or
+    /// [`use_program`][Self::use_program]) are not tracked. This method should be called after making any such changes,
+    /// before applying another pipeline state.
+    pub fn invalidate_pipeline_state(&self) {
+        self.pipeline_state.set(None);
+    }
+
+
+    fn apply_blend_state(&self, prev: Option<&BlendState>, new: &BlendState) -> BlendState {
+        if differs(prev, new, |s| s.enabled) {
+            self.set_capability(EnableCap::Blend, new.enabled);
+        }
+
+        if let (Some(prev), false) = (prev, new.enabled) {
+            return BlendState { enabled: false, ..*prev };
+        }
+
+        if differs(prev, new, |s| (s.src_rgb, s.dst_rgb, s.src_alpha, s.dst_alpha)) {
+            self.blend_func_separate(new.src_rgb, new.dst_rgb, new.src_alpha, new.dst_alpha);
+        }
+
+        if differs(prev, new, |s| (s.equation_rgb, s.equation_alpha)) {
+            self.blend_equation_separate(new.equation_rgb, new.equation_alpha);
+        }
+
+        if differs(prev, new, |s| s.color) {
+            let [r, g, b, a] = new.color;
+            self.blend_color(r, g, b, a);
+        }
+
+        *new
+    }
+
+
+    fn apply_depth_state(&self, prev: Option<&DepthState>, new: &DepthState) -> DepthState {
+        if differs(prev, new, |s| s.test) {
+            self.set_capability(EnableCap::DepthTest, new.test);
+        }
+
+        // The depth mask also applies to `glClear`, so it is set even while the test is disabled.
+        if differs(prev, new, |s| s.write) {
+            self.depth_mask(new.write);
+        }
+
+        if let (Some(prev), false) = (prev, new.test) {
+            return DepthState { func: prev.func, ..*new };
+        }
+
+        if differs(prev, new, |s| s.func) {
+            self.depth_func(new.func);
+        }
+
+        *new
+    }
+
+
+    fn apply_stencil_state(&self, prev: Option<&StencilState>, new: &StencilState) -> StencilState {
+        if differs(prev, new, |s| s.enabled) {
+            self.set_capability(EnableCap::StencilTest, new.enabled);
+        }
+
+        // Like the depth mask, the stencil write masks also apply to `glClear`.
+        for (face, prev, new) in [
+            (TriangleFace::Front, prev.map(|s| &s.front), &new.front),
+            (TriangleFace::Back, prev.map(|s| &s.back), &new.back),
+        ] {
+            if differs(prev, new, |s| s.write_mask) {
+                self.stencil_mask_separate(face, new.write_mask);
+            }
+        }
+
+        if let (Some(prev), false) = (prev, new.enabled) {
+            let mut applied = StencilState { enabled: false, ..*prev };
+            applied.front.write_mask = new.front.write_mask;
+            applied.back.write_mask = new.back.write_mask;
+            return applied;
+        }
+
+        self.apply_stencil_face_state(TriangleFace::Front, prev.map(|s| &s.front), &new.front);
+        self.apply_stencil_face_state(TriangleFace::Back, prev.map(|s| &s.back), &new.back);
+
+        *new
+    }
+
+
+    fn apply_stencil_face_state(&self, face: TriangleFace, prev: Option<&StencilFaceState>, new: &StencilFaceState) {
+        if differs(prev, new, |s| (s.func, s.reference, s.read_mask)) {
+            self.stencil_func_separate(face, new.func, new.reference, new.read_mask);
+        }
+
+        if differs(prev, new, |s| (s.stencil_fail, s.depth_fail, s.pass)) {
+            self.stencil_op_separate(face, new.stencil_fail, new.depth_fail, new.pass);
+        }
+    }
+
+
+    fn apply_raster_state(&self, prev: Option<&RasterState>, new: &RasterState) {
+        if differs(prev, new, |s| s.cull_face.is_some()) {
+            self.set_capability(EnableCap::CullFace, new.cull_face.is_some());
+        }
+
+        if let Some(face) = new.cull_face {
+            if differs(prev, new, |s| s.cull_face) {
+                self.cull_face(face);
+            }
+        }
+
+        if differs(prev, new, |s| s.front_face) {
+            self.front_face(new.front_face);
+        }
+
+        if differs(prev, new, |s| s.polygon_mode) {
+            self.polygon_mode(PolygonModeFace::FrontAndBack, new.polygon_mode);
+        }
+
+        if differs(prev, new, |s| s.polygon_offset.is_some()) {
+            self.set_capability(EnableCap::PolygonOffsetFill, new.polygon_offset.is_some());
+        }
+
+        if let Some((factor, units)) = new.polygon_offset {
+            if differs(prev, new, |s| s.polygon_offset) {
+                self.polygon_offset(factor, units);
+            }
+        }
+
+        if differs(prev, new, |s| s.scissor.is_some()) {
+            self.set_capability(EnableCap::ScissorTest, new.scissor.is_some());
+        }
+
+        if let Some([x, y, width, height]) = new.scissor {
+            if differs(prev, new, |s| s.scissor) {
+                self.scissor(x, y, width, height);
+            }
+        }
+
+        if differs(prev, new, |s| s.color_mask) {
+            let [r, g, b, a] = new.color_mask;
+            self.color_mask(r, g, b, a);
+        }
+    }
+
+
+    fn set_capability(&self, cap: EnableCap, enabled: bool) {
+        if enabled {
+            self.enable(cap);
+        } else {
+            self.disable(cap);
+        }
+    }
+}
+
+
+/// Checks whether some part of a new state differs from the previous one. An unknown previous state always differs.
+fn differs<S, T: PartialEq>(prev: Option<&S>, new: &S, part: impl Fn(&S) -> T) -> bool {
+    prev.is_none_or(|prev| part(prev) != part(new))
+}
+
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::mock::{self, Arg, Call};
+    use crate::raw::{BACK, BLEND, DEPTH_TEST, FRONT, ONE, STENCIL_TEST};
+
+    /// Applies a pipeline state, and returns the calls that it made.
+    fn apply(gl: &GLContext, state: &PipelineState) -> Vec<Call> {
+        gl.apply_pipeline_state(state);
+        let calls = mock::take_calls().into_iter();
+        calls.filter(|call| call.name != "glGetError").collect()
+    }
+
+    fn names(calls: &[Call]) -> Vec<&'static str> {
+        calls.iter().map(|call| call.name).collect()
+    }
+
+
+    #[test]
+    fn redundant_state_changes_are_skipped() {
+        let gl = mock::context();
+        let state = PipelineState::new().with_blend(BlendState::ALPHA);
+
+        let calls = apply(&gl, &state);
+        let enable = calls.iter().find(|call| call.name == "glEnable").unwrap();
+        assert_eq!(enable.args, [("cap", Arg::Enum(BLEND))]);
+        assert!(names(&calls).contains(&"glBlendFuncSeparate"));
+
+        // Applying the same state again does nothing
+        assert!(apply(&gl, &state).is_empty());
+
+        // Changing only the blend factors only sets the blend factors
+        let calls = apply(&gl, &state.with_blend(BlendState::ADDITIVE));
+        assert_eq!(names(&calls), ["glBlendFuncSeparate"]);
+        assert_eq!(calls[0].args, [
+            ("s_factor_rgb", Arg::Enum(ONE)),
+            ("d_factor_rgb", Arg::Enum(ONE)),
+            ("s_factor_alpha", Arg::Enum(ONE)),
+            ("d_factor_alpha", Arg::Enum(ONE)),
+        ]);
+    }
+
+    #[test]
+    fn write_masks_are_applied_without_tests() {
+        let gl = mock::context();
+        let mut face = StencilFaceState::DEFAULT;
+        face.write_mask = 0;
+        let state = PipelineState::new().with_depth(DepthState::READ_ONLY);
+        apply(&gl, &state.with_stencil(StencilState::new(face)));
+
+        // Disabling both tests still turns writes back on, since `glClear` uses the same masks
+        let calls = apply(&gl, &PipelineState::new());
+        let mask = Arg::UInt(u32::MAX as u64);
+        assert_eq!(names(&calls)[..3], ["glDisable", "glDepthMask", "glDisable"]);
+        assert_eq!(names(&calls)[3..], ["glStencilMaskSeparate", "glStencilMaskSeparate"]);
+        assert_eq!(calls[0].args, [("cap", Arg::Enum(DEPTH_TEST))]);
+        assert_eq!(calls[1].args, [("flag", Arg::Bool(true))]);
+        assert_eq!(calls[2].args, [("cap", Arg::Enum(STENCIL_TEST))]);
+        assert_eq!(calls[3].args, [("face", Arg::Enum(FRONT)), ("mask", mask.clone())]);
+        assert_eq!(calls[4].args, [("face", Arg::Enum(BACK)), ("mask", mask)]);
+        assert_eq!(gl.pipeline_state(), Some(PipelineState::new()));
+
+        // The depth function is left alone while the test is off, and only set once it is turned back on
+        let mut depth = DepthState::LESS_EQUAL_READ_ONLY;
+        depth.test = false;
+        assert_eq!(names(&apply(&gl, &PipelineState::new().with_depth(depth))), ["glDepthMask"]);
+
+        depth.test = true;
+        let calls = apply(&gl, &PipelineState::new().with_depth(depth));
+        assert_eq!(names(&calls), ["glEnable", "glDepthFunc"]);
+    }
+}
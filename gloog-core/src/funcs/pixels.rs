@@ -0,0 +1,244 @@
+#[cfg(feature = "gl45")]
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "gl45")]
+use image::{ImageBuffer, Rgba, RgbaImage};
+
+use crate::params::{PackAlignment, PixelPackBufferBinding};
+use crate::raw::types::*;
+#[cfg(feature = "gl45")]
+use crate::raw::{TEXTURE_CUBE_MAP, TEXTURE_DEPTH, TEXTURE_HEIGHT, TEXTURE_TARGET, TEXTURE_WIDTH};
+use crate::types::*;
+#[cfg(feature = "gl45")]
+use crate::TexturePixel;
+use crate::{convert, GLContext};
+
+
+impl GLContext {
+    /// Runs the given closure with the [pack alignment][PixelStoreParameter::PackAlignment] set to 1, restoring the
+    /// previous value afterwards.
+    pub(crate) fn with_tight_packing<R>(&self, f: impl FnOnce() -> R) -> R {
+        let alignment = self.get(PackAlignment);
+
+        self.pixel_store(PixelStoreParameter::PackAlignment, 1);
+        let result = f();
+        self.pixel_store(PixelStoreParameter::PackAlignment, alignment);
+        result
+    }
+
+
+    /// Reads a rectangle of pixels from the current read framebuffer's [read buffer] into `data`.
+    ///
+    /// `(x, y)` is the bottom-left corner of the rectangle in window coordinates, and rows are written into `data` from
+    /// the bottom upwards. The [pack alignment][PixelStoreParameter::PackAlignment] is temporarily set to 1 while
+    /// reading, so rows are tightly packed.
+    ///
+    /// This function must not be used while a buffer is bound to [`BufferTarget::PixelPackBuffer`]; see
+    /// [`read_pixels_into_buffer`][Self::read_pixels_into_buffer] for that instead.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `data` is not exactly large enough to hold `width × height` pixels of the given format
+    /// and type.
+    ///
+    /// [read buffer]: Self::named_framebuffer_read_buffer
+    #[cfg(feature = "gl45")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn read_pixels(
+        &self,
+        x: i32,
+        y: i32,
+        width: usize,
+        height: usize,
+        format: PixelFormat,
+        ty: PixelType,
+        data: &mut [u8],
+    ) {
+        let expected = format.pixel_size(ty) * width * height;
+        assert_eq!(data.len(), expected, "pixel buffer should fit a {width}×{height} image of {format:?}/{ty:?}");
+
+        let width = convert!(width, GLsizei, "read pixels width");
+        let height = convert!(height, GLsizei, "read pixels height");
+        let size = convert!(data.len(), GLsizei, "read pixels buffer size");
+        let (format, ty) = (format.into_raw(), ty.into_raw());
+        let ptr = data.as_mut_ptr().cast();
+
+        self.with_tight_packing(|| unsafe { self.gl.readn_pixels(x, y, width, height, format, ty, size, ptr) })
+    }
+
+
+    /// Reads a rectangle of pixels from the current read framebuffer's read buffer into a buffer object, starting
+    /// `offset` bytes into its data store.
+    ///
+    /// Unlike [`read_pixels`][Self::read_pixels], this function does not need to wait for rendering to finish before
+    /// returning. Once a [fence][Self::fence_sync] inserted after it has been signaled, the pixels can be read out of
+    /// the buffer without stalling. The buffer is bound to [`BufferTarget::PixelPackBuffer`] while reading, and
+    /// whatever was bound there before is bound again afterwards.
+    #[allow(clippy::too_many_arguments)]
+    pub fn read_pixels_into_buffer(
+        &self,
+        buffer: BufferID,
+        offset: usize,
+        x: i32,
+        y: i32,
+        width: usize,
+        height: usize,
+        format: PixelFormat,
+        ty: PixelType,
+    ) {
+        let width = convert!(width, GLsizei, "read pixels width");
+        let height = convert!(height, GLsizei, "read pixels height");
+        let (format, ty) = (format.into_raw(), ty.into_raw());
+        let offset = offset as *mut _;
+
+        let prev = self.get(PixelPackBufferBinding);
+        self.bind_buffer(BufferTarget::PixelPackBuffer, buffer);
+        self.with_tight_packing(|| unsafe { self.gl.read_pixels(x, y, width, height, format, ty, offset) });
+        match prev {
+            Some(prev) => self.bind_buffer(BufferTarget::PixelPackBuffer, prev),
+            None => self.unbind_buffer(BufferTarget::PixelPackBuffer),
+        }
+    }
+
+
+    /// Reads a rectangle of pixels from the current read framebuffer into an [`ImageBuffer`].
+    ///
+    /// The rows of the returned image are flipped so that they are in top-down order, matching the [`image`] crate's
+    /// convention (and the way the framebuffer appears on screen).
+    #[cfg(feature = "gl45")]
+    pub fn read_pixels_image<P>(&self, x: i32, y: i32, width: u32, height: u32) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: TexturePixel,
+        P::Subpixel: Pod,
+    {
+        let (w, h) = (width as usize, height as usize);
+        let mut data = vec![P::Subpixel::zeroed(); w * h * P::CHANNEL_COUNT as usize];
+        self.read_pixels(x, y, w, h, P::FORMAT, P::TYPE, bytemuck::cast_slice_mut(&mut data));
+
+        let mut image = ImageBuffer::from_raw(width, height, data).expect("pixel buffer should be sized correctly");
+        image::imageops::flip_vertical_in_place(&mut image);
+        image
+    }
+
+
+    /// Reads a rectangle of pixels from the current read framebuffer into an 8-bit RGBA image, with its rows in
+    /// top-down order.
+    ///
+    /// This is useful for taking screenshots, or for reading back object IDs from a picking buffer. Remember that
+    /// window coordinates in OpenGL start from the bottom-left, so `y` may need to be flipped when it comes from the
+    /// windowing system.
+    #[cfg(feature = "gl45")]
+    pub fn read_pixels_rgba(&self, x: i32, y: i32, width: u32, height: u32) -> RgbaImage {
+        self.read_pixels_image::<Rgba<u8>>(x, y, width, height)
+    }
+
+
+    /// Gets the width, height, and depth of one mipmap level of a texture.
+    ///
+    /// For array textures, the number of layers is returned as the height (for 1D arrays) or depth (for 2D arrays and
+    /// cube map arrays).
+    #[cfg(feature = "gl45")]
+    pub fn get_texture_level_size(&self, texture: TextureID, level: usize) -> (usize, usize, usize) {
+        let level = convert!(level, GLint, "texture level");
+        let (mut width, mut height, mut depth) = (0, 0, 0);
+        unsafe {
+            let tex = texture.into_raw();
+            self.gl.get_texture_level_parameter_iv(tex, level, TEXTURE_WIDTH, &mut width);
+            self.gl.get_texture_level_parameter_iv(tex, level, TEXTURE_HEIGHT, &mut height);
+            self.gl.get_texture_level_parameter_iv(tex, level, TEXTURE_DEPTH, &mut depth);
+        }
+        (width as usize, height as usize, depth as usize)
+    }
+
+
+    /// Reads the entirety of one mipmap level of a texture into `data`, with tightly packed rows.
+    ///
+    /// All six faces of a cube map are read, one after the other, in the order of the [`CubeMapFace`] variants. Cube
+    /// map arrays are read the same way, one layer after the other.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `data` is not exactly large enough to hold the texture level in the given format and
+    /// type.
+    #[cfg(feature = "gl45")]
+    pub fn get_texture_image(
+        &self,
+        texture: TextureID,
+        level: usize,
+        format: PixelFormat,
+        ty: PixelType,
+        data: &mut [u8],
+    ) {
+        let (width, height, depth) = self.get_texture_level_size(texture, level);
+        // Cube map arrays already count their depth in layer-faces, but plain cube maps have a depth of one
+        let (tex, mut target) = (texture.into_raw(), 0);
+        unsafe { self.gl.get_texture_parameter_iv(tex, TEXTURE_TARGET, &mut target) };
+        let faces = if target as GLenum == TEXTURE_CUBE_MAP { 6 } else { 1 };
+
+        let expected = format.pixel_size(ty) * width * height * depth * faces;
+        assert_eq!(data.len(), expected, "pixel buffer should fit texture level {level} of {format:?}/{ty:?}");
+
+        let level = convert!(level, GLint, "texture level");
+        let size = convert!(data.len(), GLsizei, "texture image buffer size");
+        let (tex, format, ty) = (texture.into_raw(), format.into_raw(), ty.into_raw());
+        let ptr = data.as_mut_ptr().cast();
+
+        self.with_tight_packing(|| unsafe { self.gl.get_texture_image(tex, level, format, ty, size, ptr) })
+    }
+
+
+    /// Reads one mipmap level of a two-dimensional texture into an [`ImageBuffer`].
+    ///
+    /// The rows of the image are **not** flipped, matching [`texture_sub_image_2d_from_image`]: the first row of the
+    /// returned image is the one at `t = 0`.
+    ///
+    /// [`texture_sub_image_2d_from_image`]: Self::texture_sub_image_2d_from_image
+    #[cfg(feature = "gl45")]
+    pub fn get_texture_image_as<P>(&self, texture: TextureID, level: usize) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: TexturePixel,
+        P::Subpixel: Pod,
+    {
+        let (width, height, _) = self.get_texture_level_size(texture, level);
+        let mut data = vec![P::Subpixel::zeroed(); width * height * P::CHANNEL_COUNT as usize];
+        self.get_texture_image(texture, level, P::FORMAT, P::TYPE, bytemuck::cast_slice_mut(&mut data));
+
+        let (width, height) = (width as u32, height as u32);
+        ImageBuffer::from_raw(width, height, data).expect("pixel buffer should be sized correctly")
+    }
+}
+
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::mock::{self, Arg};
+    use crate::raw::PIXEL_PACK_BUFFER_BINDING;
+
+    #[test]
+    fn read_pixels_into_buffer_restores_binding() {
+        let gl = mock::context();
+        mock::set_query_result(PIXEL_PACK_BUFFER_BINDING, &[5]);
+        gl.read_pixels_into_buffer(BufferID::new(1), 0, 0, 0, 4, 4, PixelFormat::RGBA, PixelType::UnsignedByte);
+
+        let calls = mock::take_calls().into_iter().filter(|call| call.name == "glBindBuffer");
+        let bound = calls.map(|call| call.arg("buffer").cloned()).collect::<Vec<_>>();
+        assert_eq!(bound, [Some(Arg::UInt(1)), Some(Arg::UInt(5))]);
+    }
+
+    #[cfg(feature = "gl45")]
+    #[test]
+    fn cube_map_images_include_every_face() {
+        let gl = mock::context();
+        mock::set_query_result(TEXTURE_TARGET, &[TEXTURE_CUBE_MAP as i64]);
+        mock::set_query_result(TEXTURE_WIDTH, &[2]);
+        mock::set_query_result(TEXTURE_HEIGHT, &[2]);
+        mock::set_query_result(TEXTURE_DEPTH, &[1]);
+
+        let mut data = [0; 2 * 2 * 4 * 6];
+        gl.get_texture_image(TextureID::new(1), 0, PixelFormat::RGBA, PixelType::UnsignedByte, &mut data);
+
+        let calls = mock::take_calls();
+        let get = calls.iter().find(|call| call.name == "glGetTextureImage").unwrap();
+        assert_eq!(get.arg("buf_size"), Some(&Arg::Int(data.len() as i64)));
+    }
+}
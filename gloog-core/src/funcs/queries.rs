@@ -0,0 +1,103 @@
+use crate::raw::types::*;
+use crate::raw::{QUERY_RESULT, QUERY_RESULT_AVAILABLE, TIMESTAMP};
+use crate::types::*;
+use crate::{convert, GLContext};
+
+
+impl GLContext {
+    #[cfg(feature = "gl45")]
+    pub fn create_query(&self, target: QueryTarget) -> QueryID {
+        let mut name = 0;
+        unsafe { self.gl.create_queries(target.into_raw(), 1, &mut name) };
+        QueryID::new(name)
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn create_queries(&self, target: QueryTarget, n: usize) -> Vec<QueryID> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let mut names = vec![0; n];
+        let n = convert!(n, GLsizei, "number of queries");
+
+        unsafe { self.gl.create_queries(target.into_raw(), n, names.as_mut_ptr()) };
+        names.into_iter().map(QueryID::new).collect()
+    }
+
+
+    pub fn delete_query(&self, query: QueryID) {
+        unsafe { self.gl.delete_queries(1, &query.into_raw()) }
+    }
+
+
+    pub fn delete_queries(&self, queries: &[QueryID]) {
+        let len = convert!(queries.len(), GLsizei, "number of queries");
+        let ptr = queries.as_ptr().cast(); // cast is safe because `QueryID` is `repr(transparent)`
+        unsafe { self.gl.delete_queries(len, ptr) }
+    }
+
+
+    /// Starts measuring with a query object. Only one query may be active for each target at a time.
+    ///
+    /// [`QueryTarget::Timestamp`] queries cannot be begun; use [`query_counter`][Self::query_counter] instead.
+    pub fn begin_query(&self, target: QueryTarget, query: QueryID) {
+        unsafe { self.gl.begin_query(target.into_raw(), query.into_raw()) }
+    }
+
+
+    /// Stops measuring with whichever query is active for the given target.
+    pub fn end_query(&self, target: QueryTarget) {
+        unsafe { self.gl.end_query(target.into_raw()) }
+    }
+
+
+    /// Records the GPU's time into a [timestamp][QueryTarget::Timestamp] query once all previous commands have
+    /// completed.
+    pub fn query_counter(&self, query: QueryID) {
+        unsafe { self.gl.query_counter(query.into_raw(), TIMESTAMP) }
+    }
+
+
+    /// Checks whether the result of a query is available yet, without blocking.
+    pub fn is_query_result_available(&self, query: QueryID) -> bool {
+        let mut available = 0;
+        let query = query.into_raw();
+        unsafe { self.gl.get_query_object_iv(query, QUERY_RESULT_AVAILABLE, &mut available) };
+        available != 0
+    }
+
+
+    /// Gets the result of a query, blocking until it is available.
+    ///
+    /// Results of [`TimeElapsed`][QueryTarget::TimeElapsed] and [`Timestamp`][QueryTarget::Timestamp] queries are in
+    /// nanoseconds.
+    pub fn get_query_result(&self, query: QueryID) -> u64 {
+        let mut result = 0;
+        unsafe { self.gl.get_query_object_ui64v(query.into_raw(), QUERY_RESULT, &mut result) };
+        result
+    }
+
+
+    /// Gets the result of a query if it is available, or `None` if the GPU has not finished with it yet.
+    pub fn try_get_query_result(&self, query: QueryID) -> Option<u64> {
+        self.is_query_result_available(query).then(|| self.get_query_result(query))
+    }
+
+
+    /// Begins conditional rendering: until [`end_conditional_render`][Self::end_conditional_render] is called, drawing
+    /// commands are discarded if the given query found that no samples passed.
+    ///
+    /// The query must be a [`SamplesPassed`][QueryTarget::SamplesPassed],
+    /// [`AnySamplesPassed`][QueryTarget::AnySamplesPassed], or
+    /// [`AnySamplesPassedConservative`][QueryTarget::AnySamplesPassedConservative] query.
+    pub fn begin_conditional_render(&self, query: QueryID, mode: ConditionalRenderMode) {
+        unsafe { self.gl.begin_conditional_render(query.into_raw(), mode.into_raw()) }
+    }
+
+
+    pub fn end_conditional_render(&self) {
+        unsafe { self.gl.end_conditional_render() }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::raw::types::*;
+use crate::raw::{
+    TEXTURE_BORDER_COLOR,
+    TEXTURE_COMPARE_FUNC,
+    TEXTURE_COMPARE_MODE,
+    TEXTURE_LOD_BIAS,
+    TEXTURE_MAG_FILTER,
+    TEXTURE_MAX_LOD,
+    TEXTURE_MIN_FILTER,
+    TEXTURE_MIN_LOD,
+};
+#[cfg(any(feature = "gl46", feature = "ext-texture-filter-anisotropic"))]
+use crate::raw::TEXTURE_MAX_ANISOTROPY;
+use crate::types::*;
+use crate::{convert, GLContext};
+
+
+impl GLContext {
+    #[cfg(feature = "gl45")]
+    pub fn create_sampler(&self) -> SamplerID {
+        let mut name = 0;
+        unsafe { self.gl.create_samplers(1, &mut name) };
+        SamplerID::new(name)
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn create_samplers(&self, n: usize) -> Vec<SamplerID> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let mut names = vec![0; n];
+        let n = convert!(n, GLsizei, "number of samplers");
+
+        unsafe { self.gl.create_samplers(n, names.as_mut_ptr()) };
+        names.into_iter().map(SamplerID::new).collect()
+    }
+
+
+    /// Creates a new sampler object and sets all of its parameters from a description.
+    #[cfg(feature = "gl45")]
+    pub fn create_sampler_from_desc(&self, desc: &SamplerDesc) -> SamplerID {
+        let sampler = self.create_sampler();
+        self.sampler_desc(sampler, desc);
+        sampler
+    }
+
+
+    pub fn delete_sampler(&self, sampler: SamplerID) {
+        unsafe { self.gl.delete_samplers(1, &sampler.into_raw()) }
+    }
+
+
+    pub fn delete_samplers(&self, samplers: &[SamplerID]) {
+        let len = convert!(samplers.len(), GLsizei, "number of samplers");
+        let ptr = samplers.as_ptr().cast(); // cast is safe because `SamplerID` is `repr(transparent)`
+        unsafe { self.gl.delete_samplers(len, ptr) }
+    }
+
+
+    /// Binds a sampler to a texture unit. While it is bound, its parameters are used instead of those of the texture
+    /// bound to the same unit.
+    pub fn bind_sampler(&self, unit: u32, sampler: SamplerID) {
+        unsafe { self.gl.bind_sampler(unit, sampler.into_raw()) }
+    }
+
+
+    /// Unbinds whatever sampler is bound to a texture unit, so that the texture bound to it is sampled with its own
+    /// parameters again.
+    pub fn unbind_sampler(&self, unit: u32) {
+        unsafe { self.gl.bind_sampler(unit, 0) }
+    }
+
+
+    /// Binds samplers to a consecutive range of texture units, starting at `first`. Units given `None` are unbound.
+    #[cfg(feature = "gl45")]
+    pub fn bind_samplers(&self, first: u32, samplers: &[Option<SamplerID>]) {
+        let names = samplers.iter().map(|s| s.map_or(0, SamplerID::into_raw)).collect::<Vec<_>>();
+        let count = convert!(names.len(), GLsizei, "number of samplers");
+        unsafe { self.gl.bind_samplers(first, count, names.as_ptr()) }
+    }
+
+
+    /// Sets every parameter of a sampler from a description.
+    pub fn sampler_desc(&self, sampler: SamplerID, desc: &SamplerDesc) {
+        let [wrap_s, wrap_t, wrap_r] = desc.wrap;
+        let [min_lod, max_lod] = desc.lod_range;
+
+        self.sampler_min_filter(sampler, desc.min_filter);
+        self.sampler_mag_filter(sampler, desc.mag_filter);
+        self.sampler_wrap(sampler, TextureWrapAxis::S, wrap_s);
+        self.sampler_wrap(sampler, TextureWrapAxis::T, wrap_t);
+        self.sampler_wrap(sampler, TextureWrapAxis::R, wrap_r);
+        self.sampler_lod_bias(sampler, desc.lod_bias);
+        self.sampler_lod_range(sampler, min_lod, max_lod);
+        #[cfg(any(feature = "gl46", feature = "ext-texture-filter-anisotropic"))]
+        self.sampler_max_anisotropy(sampler, desc.max_anisotropy);
+        self.sampler_border_color(sampler, desc.border_color);
+
+        match desc.compare {
+            Some(func) => {
+                self.sampler_compare_mode(sampler, TextureCompareMode::CompareRefToTexture);
+                self.sampler_compare_func(sampler, func);
+            },
+            None => self.sampler_compare_mode(sampler, TextureCompareMode::None),
+        }
+    }
+
+
+    pub fn sampler_wrap(&self, sampler: SamplerID, axis: TextureWrapAxis, mode: TextureWrap) {
+        let mode = mode.into_raw() as GLint;
+        unsafe { self.gl.sampler_parameter_i(sampler.into_raw(), axis.into_raw(), mode) }
+    }
+
+
+    pub fn sampler_min_filter(&self, sampler: SamplerID, filter: TextureMinFilter) {
+        let filter = filter.into_raw() as GLint;
+        unsafe { self.gl.sampler_parameter_i(sampler.into_raw(), TEXTURE_MIN_FILTER, filter) }
+    }
+
+
+    pub fn sampler_mag_filter(&self, sampler: SamplerID, filter: TextureMagFilter) {
+        let filter = filter.into_raw() as GLint;
+        unsafe { self.gl.sampler_parameter_i(sampler.into_raw(), TEXTURE_MAG_FILTER, filter) }
+    }
+
+
+    /// Sets whether sampling a depth texture returns its depth values directly or compares them against a reference
+    /// value.
+    pub fn sampler_compare_mode(&self, sampler: SamplerID, mode: TextureCompareMode) {
+        let mode = mode.into_raw() as GLint;
+        unsafe { self.gl.sampler_parameter_i(sampler.into_raw(), TEXTURE_COMPARE_MODE, mode) }
+    }
+
+
+    /// Sets the comparison used when [comparing depth textures][TextureCompareMode::CompareRefToTexture]. The
+    /// reference value is the incoming value and the texel is the stored one.
+    pub fn sampler_compare_func(&self, sampler: SamplerID, func: CompareFunction) {
+        let func = func.into_raw() as GLint;
+        unsafe { self.gl.sampler_parameter_i(sampler.into_raw(), TEXTURE_COMPARE_FUNC, func) }
+    }
+
+
+    /// Sets the bias added to the level of detail computed for each sample.
+    pub fn sampler_lod_bias(&self, sampler: SamplerID, bias: f32) {
+        unsafe { self.gl.sampler_parameter_f(sampler.into_raw(), TEXTURE_LOD_BIAS, bias) }
+    }
+
+
+    /// Sets the range that the level of detail of each sample is clamped to.
+    pub fn sampler_lod_range(&self, sampler: SamplerID, min: f32, max: f32) {
+        unsafe {
+            let gl = &self.gl;
+            gl.sampler_parameter_f(sampler.into_raw(), TEXTURE_MIN_LOD, min);
+            gl.sampler_parameter_f(sampler.into_raw(), TEXTURE_MAX_LOD, max);
+        }
+    }
+
+
+    /// Sets the maximum degree of anisotropy used when filtering. A value of `1.0` disables anisotropic filtering.
+    #[cfg(any(feature = "gl46", feature = "ext-texture-filter-anisotropic"))]
+    pub fn sampler_max_anisotropy(&self, sampler: SamplerID, max: f32) {
+        unsafe { self.gl.sampler_parameter_f(sampler.into_raw(), TEXTURE_MAX_ANISOTROPY, max) }
+    }
+
+
+    /// Sets the color returned when sampling outside of a texture with [`TextureWrap::ClampToBorder`].
+    pub fn sampler_border_color(&self, sampler: SamplerID, color: BorderColor) {
+        let (gl, sampler) = (&self.gl, sampler.into_raw());
+        let float = |c: [f32; 4]| unsafe { gl.sampler_parameter_fv(sampler, TEXTURE_BORDER_COLOR, c.as_ptr()) };
+
+        match color {
+            BorderColor::TransparentBlack => float([0.0, 0.0, 0.0, 0.0]),
+            BorderColor::OpaqueBlack => float([0.0, 0.0, 0.0, 1.0]),
+            BorderColor::OpaqueWhite => float([1.0, 1.0, 1.0, 1.0]),
+            BorderColor::Float(c) => float(c),
+            BorderColor::Int(c) => unsafe { gl.sampler_parameter_i_iv(sampler, TEXTURE_BORDER_COLOR, c.as_ptr()) },
+            BorderColor::UnsignedInt(c) => unsafe {
+                gl.sampler_parameter_i_uiv(sampler, TEXTURE_BORDER_COLOR, c.as_ptr())
+            },
+        }
+    }
+}
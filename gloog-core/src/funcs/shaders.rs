@@ -1,6 +1,9 @@
+#[cfg(feature = "gl41")]
 use std::ffi::CString;
 
 use crate::raw::types::*;
+#[cfg(feature = "khr-parallel-shader-compile")]
+use crate::raw::COMPLETION_STATUS_KHR;
 use crate::raw::{COMPILE_STATUS, INFO_LOG_LENGTH, LINK_STATUS};
 use crate::types::*;
 use crate::{convert, GLContext};
@@ -151,6 +154,7 @@ impl GLContext {
     }
 
 
+    #[cfg(feature = "gl41")]
     pub fn create_shader_program<I, S>(&self, shader_type: ShaderType, strings: I) -> Result<ProgramID, String>
     where
         I: IntoIterator<Item = S>,
@@ -198,4 +202,31 @@ impl GLContext {
             Err(info_log)
         }
     }
+
+
+    /// Sets the number of background threads that the driver may use to compile shaders and link programs. A count of
+    /// `u32::MAX` lets the driver pick, and `0` disables parallel compilation.
+    #[cfg(feature = "khr-parallel-shader-compile")]
+    pub fn max_shader_compiler_threads(&self, count: u32) {
+        unsafe { self.gl.max_shader_compiler_threads_khr(count) }
+    }
+
+
+    /// Checks whether a shader has finished compiling, without blocking until it does. Once this returns `true`,
+    /// checking the compile status or info log will not stall.
+    #[cfg(feature = "khr-parallel-shader-compile")]
+    pub fn is_shader_compile_complete(&self, shader: ShaderID) -> bool {
+        let mut status = 0;
+        unsafe { self.gl.get_shader_iv(shader.into_raw(), COMPLETION_STATUS_KHR, &mut status) };
+        status != 0
+    }
+
+
+    /// Checks whether a program has finished linking, without blocking until it does.
+    #[cfg(feature = "khr-parallel-shader-compile")]
+    pub fn is_program_link_complete(&self, program: ProgramID) -> bool {
+        let mut status = 0;
+        unsafe { self.gl.get_program_iv(program.into_raw(), COMPLETION_STATUS_KHR, &mut status) };
+        status != 0
+    }
 }
@@ -0,0 +1,295 @@
+use crate::raw::types::*;
+use crate::types::*;
+use crate::{convert, GLContext};
+
+
+impl GLContext {
+    /// Enables a capability for a single index, such as blending for one draw buffer.
+    pub fn enable_i(&self, cap: EnableCap, index: u32) {
+        unsafe { self.gl.enable_i(cap.into_raw(), index) }
+    }
+
+
+    /// Disables a capability for a single index, such as blending for one draw buffer.
+    pub fn disable_i(&self, cap: EnableCap, index: u32) {
+        unsafe { self.gl.disable_i(cap.into_raw(), index) }
+    }
+
+
+    // Blending
+    // -----------------------------------------------------------------------------------------------------------------
+
+    /// Sets the factors that the source and destination colors are multiplied by when [blending][EnableCap::Blend], for
+    /// all draw buffers.
+    ///
+    /// The classic blend function for transparency is [`SrcAlpha`] and [`OneMinusSrcAlpha`].
+    ///
+    /// [`SrcAlpha`]: BlendFactor::SrcAlpha
+    /// [`OneMinusSrcAlpha`]: BlendFactor::OneMinusSrcAlpha
+    pub fn blend_func(&self, src: BlendFactor, dst: BlendFactor) {
+        unsafe { self.gl.blend_func(src.into_raw(), dst.into_raw()) }
+    }
+
+
+    /// Sets the source and destination blend factors for all draw buffers, separately for the color and alpha
+    /// components.
+    pub fn blend_func_separate(
+        &self,
+        src_rgb: BlendFactor,
+        dst_rgb: BlendFactor,
+        src_a: BlendFactor,
+        dst_a: BlendFactor,
+    ) {
+        let (src_rgb, dst_rgb) = (src_rgb.into_raw(), dst_rgb.into_raw());
+        let (src_a, dst_a) = (src_a.into_raw(), dst_a.into_raw());
+        unsafe { self.gl.blend_func_separate(src_rgb, dst_rgb, src_a, dst_a) }
+    }
+
+
+    /// Sets the source and destination blend factors for a single draw buffer.
+    #[cfg(feature = "gl41")]
+    pub fn blend_func_i(&self, draw_buffer: u32, src: BlendFactor, dst: BlendFactor) {
+        unsafe { self.gl.blend_func_i(draw_buffer, src.into_raw(), dst.into_raw()) }
+    }
+
+
+    /// Sets the source and destination blend factors for a single draw buffer, separately for the color and alpha
+    /// components.
+    #[cfg(feature = "gl41")]
+    pub fn blend_func_separate_i(
+        &self,
+        draw_buffer: u32,
+        src_rgb: BlendFactor,
+        dst_rgb: BlendFactor,
+        src_a: BlendFactor,
+        dst_a: BlendFactor,
+    ) {
+        let (src_rgb, dst_rgb) = (src_rgb.into_raw(), dst_rgb.into_raw());
+        let (src_a, dst_a) = (src_a.into_raw(), dst_a.into_raw());
+        unsafe { self.gl.blend_func_separate_i(draw_buffer, src_rgb, dst_rgb, src_a, dst_a) }
+    }
+
+
+    /// Sets how the weighted source and destination colors are combined when blending, for all draw buffers.
+    pub fn blend_equation(&self, mode: BlendEquation) {
+        unsafe { self.gl.blend_equation(mode.into_raw()) }
+    }
+
+
+    /// Sets how the weighted source and destination colors are combined when blending, for all draw buffers,
+    /// separately for the color and alpha components.
+    pub fn blend_equation_separate(&self, mode_rgb: BlendEquation, mode_alpha: BlendEquation) {
+        unsafe { self.gl.blend_equation_separate(mode_rgb.into_raw(), mode_alpha.into_raw()) }
+    }
+
+
+    /// Sets how the weighted source and destination colors are combined when blending, for a single draw buffer.
+    #[cfg(feature = "gl41")]
+    pub fn blend_equation_i(&self, draw_buffer: u32, mode: BlendEquation) {
+        unsafe { self.gl.blend_equation_i(draw_buffer, mode.into_raw()) }
+    }
+
+
+    /// Sets how the weighted source and destination colors are combined when blending, for a single draw buffer,
+    /// separately for the color and alpha components.
+    #[cfg(feature = "gl41")]
+    pub fn blend_equation_separate_i(&self, draw_buffer: u32, mode_rgb: BlendEquation, mode_alpha: BlendEquation) {
+        let (rgb, alpha) = (mode_rgb.into_raw(), mode_alpha.into_raw());
+        unsafe { self.gl.blend_equation_separate_i(draw_buffer, rgb, alpha) }
+    }
+
+
+    /// Sets the constant color used by the [`ConstantColor`][BlendFactor::ConstantColor] and
+    /// [`ConstantAlpha`][BlendFactor::ConstantAlpha] blend factors.
+    pub fn blend_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
+        unsafe { self.gl.blend_color(red, green, blue, alpha) }
+    }
+
+
+    // Depth
+    // -----------------------------------------------------------------------------------------------------------------
+
+    /// Sets the comparison used by the [depth test][EnableCap::DepthTest]. The default is
+    /// [`Less`][CompareFunction::Less].
+    pub fn depth_func(&self, func: CompareFunction) {
+        unsafe { self.gl.depth_func(func.into_raw()) }
+    }
+
+
+    /// Enables or disables writing into the depth buffer. Depth testing still happens while writing is disabled.
+    pub fn depth_mask(&self, write: bool) {
+        let write = convert!(write, GLboolean, "depth mask flag");
+        unsafe { self.gl.depth_mask(write) }
+    }
+
+
+    /// Sets the mapping of depth values from normalized device coordinates to window coordinates. The default is
+    /// `0.0` to `1.0`.
+    pub fn depth_range(&self, near: f64, far: f64) {
+        unsafe { self.gl.depth_range(near, far) }
+    }
+
+
+    // Stencil
+    // -----------------------------------------------------------------------------------------------------------------
+
+    /// Sets the comparison used by the [stencil test][EnableCap::StencilTest] for both front- and back-facing
+    /// polygons. Both `reference` and the stored stencil value are ANDed with `mask` before being compared.
+    pub fn stencil_func(&self, func: CompareFunction, reference: i32, mask: u32) {
+        unsafe { self.gl.stencil_func(func.into_raw(), reference, mask) }
+    }
+
+
+    /// Sets the comparison used by the stencil test for polygons facing the given direction.
+    pub fn stencil_func_separate(&self, face: TriangleFace, func: CompareFunction, reference: i32, mask: u32) {
+        let (face, func) = (face.into_raw(), func.into_raw());
+        unsafe { self.gl.stencil_func_separate(face, func, reference, mask) }
+    }
+
+
+    /// Sets what happens to the stored stencil value when the stencil test fails (`stencil_fail`), when it passes but
+    /// the depth test fails (`depth_fail`), and when both pass (`pass`), for both front- and back-facing polygons.
+    pub fn stencil_op(&self, stencil_fail: StencilOp, depth_fail: StencilOp, pass: StencilOp) {
+        let (s_fail, dp_fail, dp_pass) = (stencil_fail.into_raw(), depth_fail.into_raw(), pass.into_raw());
+        unsafe { self.gl.stencil_op(s_fail, dp_fail, dp_pass) }
+    }
+
+
+    /// Sets what happens to the stored stencil value after the stencil and depth tests, for polygons facing the given
+    /// direction.
+    pub fn stencil_op_separate(
+        &self,
+        face: TriangleFace,
+        stencil_fail: StencilOp,
+        depth_fail: StencilOp,
+        pass: StencilOp,
+    ) {
+        let face = face.into_raw();
+        let (s_fail, dp_fail, dp_pass) = (stencil_fail.into_raw(), depth_fail.into_raw(), pass.into_raw());
+        unsafe { self.gl.stencil_op_separate(face, s_fail, dp_fail, dp_pass) }
+    }
+
+
+    /// Sets which bits of the stencil buffer may be written to, for both front- and back-facing polygons.
+    pub fn stencil_mask(&self, mask: u32) {
+        unsafe { self.gl.stencil_mask(mask) }
+    }
+
+
+    /// Sets which bits of the stencil buffer may be written to, for polygons facing the given direction.
+    pub fn stencil_mask_separate(&self, face: TriangleFace, mask: u32) {
+        unsafe { self.gl.stencil_mask_separate(face.into_raw(), mask) }
+    }
+
+
+    // Color, scissor, and rasterization
+    // -----------------------------------------------------------------------------------------------------------------
+
+    /// Sets which color components may be written to in all draw buffers.
+    pub fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool) {
+        let [r, g, b, a] = [red, green, blue, alpha].map(|c| convert!(c, GLboolean, "color mask flag"));
+        unsafe { self.gl.color_mask(r, g, b, a) }
+    }
+
+
+    /// Sets which color components may be written to in a single draw buffer.
+    pub fn color_mask_i(&self, draw_buffer: u32, red: bool, green: bool, blue: bool, alpha: bool) {
+        let [r, g, b, a] = [red, green, blue, alpha].map(|c| convert!(c, GLboolean, "color mask flag"));
+        unsafe { self.gl.color_mask_i(draw_buffer, r, g, b, a) }
+    }
+
+
+    /// Sets the rectangle, in window coordinates, outside of which fragments are discarded when the
+    /// [scissor test][EnableCap::ScissorTest] is enabled.
+    pub fn scissor(&self, x: i32, y: i32, width: i32, height: i32) {
+        unsafe { self.gl.scissor(x, y, width, height) }
+    }
+
+
+    /// Sets the offset added to polygons' depth values when [polygon offset][EnableCap::PolygonOffsetFill] is enabled.
+    /// The offset is `factor × slope + units × r`, where `slope` is the polygon's depth slope and `r` is the smallest
+    /// resolvable difference in depth.
+    pub fn polygon_offset(&self, factor: f32, units: f32) {
+        unsafe { self.gl.polygon_offset(factor, units) }
+    }
+
+
+    /// Like [`polygon_offset`][Self::polygon_offset], but the magnitude of the offset is limited to `clamp` (when
+    /// `clamp` is non-zero).
+    #[cfg(feature = "gl46")]
+    pub fn polygon_offset_clamp(&self, factor: f32, units: f32, clamp: f32) {
+        unsafe { self.gl.polygon_offset_clamp(factor, units, clamp) }
+    }
+
+
+    /// Sets the width of rasterized lines. Core profile contexts are only required to support a width of 1.0.
+    pub fn line_width(&self, width: f32) {
+        unsafe { self.gl.line_width(width) }
+    }
+
+
+    /// Sets the diameter of rasterized points, when [`EnableCap::ProgramPointSize`] is disabled.
+    pub fn point_size(&self, size: f32) {
+        unsafe { self.gl.point_size(size) }
+    }
+
+
+    /// Sets the coverage value used when [`EnableCap::SampleCoverage`] is enabled, optionally inverting it.
+    pub fn sample_coverage(&self, value: f32, invert: bool) {
+        let invert = convert!(invert, GLboolean, "'invert' parameter");
+        unsafe { self.gl.sample_coverage(value, invert) }
+    }
+
+
+    /// Sets one word of the sample mask used when [`EnableCap::SampleMask`] is enabled. Bit `n` of word `index`
+    /// controls sample `32 × index + n`.
+    pub fn sample_mask(&self, index: u32, mask: u32) {
+        unsafe { self.gl.sample_mask_i(index, mask) }
+    }
+
+
+    // Miscellaneous
+    // -----------------------------------------------------------------------------------------------------------------
+
+    /// Hints at how the implementation should trade quality for speed for some behavior.
+    pub fn hint(&self, target: HintTarget, mode: HintMode) {
+        unsafe { self.gl.hint(target.into_raw(), mode.into_raw()) }
+    }
+
+
+    /// Sets the logical operation applied between incoming and stored colors, when [`EnableCap::ColorLogicOp`] is
+    /// enabled.
+    pub fn logic_op(&self, op: LogicOp) {
+        unsafe { self.gl.logic_op(op.into_raw()) }
+    }
+
+
+    /// Sets how clip-space coordinates are mapped to window coordinates: which corner the origin is in, and whether
+    /// depth values range from -1 to 1 or from 0 to 1.
+    #[cfg(feature = "gl45")]
+    pub fn clip_control(&self, origin: ClipControlOrigin, depth: ClipControlDepth) {
+        unsafe { self.gl.clip_control(origin.into_raw(), depth.into_raw()) }
+    }
+
+
+    /// Sets which vertex of each primitive provides the values of `flat`-qualified shader outputs.
+    pub fn provoking_vertex(&self, convention: ProvokingVertex) {
+        unsafe { self.gl.provoking_vertex(convention.into_raw()) }
+    }
+
+
+    /// Sets the number of vertices in each patch, for use with tessellation shaders.
+    #[cfg(feature = "gl41")]
+    pub fn patch_vertices(&self, count: u32) {
+        let count = convert!(count, GLint, "number of patch vertices");
+        unsafe { self.gl.patch_parameter_i(PatchParameterName::Vertices.into_raw(), count) }
+    }
+
+
+    /// Checks whether the context has been lost because of a GPU reset, and if so, whether this context caused it.
+    #[cfg(feature = "gl45")]
+    pub fn get_graphics_reset_status(&self) -> GraphicsResetStatus {
+        let status = unsafe { self.gl.get_graphics_reset_status() };
+        GraphicsResetStatus::from_raw(status).expect("glGetGraphicsResetStatus should return a valid status")
+    }
+}
@@ -0,0 +1,44 @@
+use crate::raw::{SIGNALED, SYNC_FLUSH_COMMANDS_BIT, SYNC_GPU_COMMANDS_COMPLETE, SYNC_STATUS, TIMEOUT_IGNORED};
+use crate::types::*;
+use crate::GLContext;
+
+
+impl GLContext {
+    /// Inserts a fence into the command stream. It becomes signaled once all of the commands before it have completed.
+    pub fn fence_sync(&self) -> SyncID {
+        SyncID::new(unsafe { self.gl.fence_sync(SYNC_GPU_COMMANDS_COMPLETE, 0) })
+    }
+
+
+    pub fn delete_sync(&self, sync: SyncID) {
+        unsafe { self.gl.delete_sync(sync.into_raw()) }
+    }
+
+
+    /// Blocks until a sync object is signaled, or until `timeout_ns` nanoseconds have passed. A timeout of zero can be
+    /// used to check whether the sync object is signaled without blocking.
+    ///
+    /// If `flush` is set, the command stream is flushed before waiting. This should be set the first time a fence is
+    /// waited on, otherwise it may never be submitted to the GPU and the wait will never end.
+    pub fn client_wait_sync(&self, sync: SyncID, flush: bool, timeout_ns: u64) -> SyncStatus {
+        let flags = if flush { SYNC_FLUSH_COMMANDS_BIT } else { 0 };
+        let status = unsafe { self.gl.client_wait_sync(sync.into_raw(), flags, timeout_ns) };
+        SyncStatus::from_raw(status).expect("glClientWaitSync should return a valid status")
+    }
+
+
+    /// Makes the server wait for a sync object to be signaled before executing any further commands. Unlike
+    /// [`client_wait_sync`][Self::client_wait_sync], this returns immediately without blocking the CPU.
+    pub fn wait_sync(&self, sync: SyncID) {
+        unsafe { self.gl.wait_sync(sync.into_raw(), 0, TIMEOUT_IGNORED) }
+    }
+
+
+    /// Checks whether a sync object has been signaled, without blocking or flushing the command stream.
+    pub fn is_sync_signaled(&self, sync: SyncID) -> bool {
+        let mut status = 0;
+        let sync = sync.into_raw();
+        unsafe { self.gl.get_sync_iv(sync, SYNC_STATUS, 1, std::ptr::null_mut(), &mut status) };
+        status as u32 == SIGNALED
+    }
+}
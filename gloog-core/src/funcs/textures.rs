@@ -0,0 +1,700 @@
+#[cfg(feature = "gl45")]
+use std::ops::Deref;
+
+#[cfg(feature = "gl45")]
+use image::{EncodableLayout, ImageBuffer};
+use image::{Luma, LumaA, Pixel, Rgb, Rgba};
+
+#[cfg(feature = "gl45")]
+use crate::params::UnpackAlignment;
+#[cfg(feature = "gl45")]
+use crate::params::{
+    PixelUnpackBufferBinding,
+    UnpackImageHeight,
+    UnpackRowLength,
+    UnpackSkipImages,
+    UnpackSkipPixels,
+    UnpackSkipRows,
+};
+use crate::raw::types::*;
+#[cfg(all(feature = "gl45", any(feature = "gl46", feature = "ext-texture-filter-anisotropic")))]
+use crate::raw::TEXTURE_MAX_ANISOTROPY;
+#[cfg(any(feature = "gl45", feature = "trace"))]
+use crate::raw::{
+    PACK_ALIGNMENT,
+    UNPACK_ALIGNMENT,
+    UNPACK_IMAGE_HEIGHT,
+    UNPACK_ROW_LENGTH,
+    UNPACK_SKIP_IMAGES,
+    UNPACK_SKIP_PIXELS,
+    UNPACK_SKIP_ROWS,
+};
+#[cfg(feature = "gl45")]
+use crate::raw::{TEXTURE_BORDER_COLOR, TEXTURE_MAG_FILTER, TEXTURE_MIN_FILTER, TEXTURE_SWIZZLE_RGBA};
+use crate::types::*;
+use crate::{convert, GLContext};
+
+
+/// A type of [pixel][image::Pixel] from the [`image`] crate that can be uploaded directly into a texture.
+///
+/// Grayscale images are uploaded into the red channel (and the green channel, for their alpha). Use
+/// [`texture_swizzle`][GLContext::texture_swizzle] to have them sample as gray.
+pub trait TexturePixel: Pixel {
+    /// The format that this pixel's components are laid out in.
+    const FORMAT: PixelFormat;
+    /// The type of each of this pixel's components.
+    const TYPE: PixelType;
+}
+
+macro_rules! impl_texture_pixel {
+    ($($pixel:ident => $format:ident),*$(,)?) => {
+        $(
+            impl_texture_pixel!(@ $pixel<u8> => $format, UnsignedByte);
+            impl_texture_pixel!(@ $pixel<u16> => $format, UnsignedShort);
+            impl_texture_pixel!(@ $pixel<f32> => $format, Float);
+        )*
+    };
+    (@ $pixel:ident<$sub:ty> => $format:ident, $ty:ident) => {
+        impl TexturePixel for $pixel<$sub> {
+            const FORMAT: PixelFormat = PixelFormat::$format;
+            const TYPE: PixelType = PixelType::$ty;
+        }
+    };
+}
+
+impl_texture_pixel! {
+    Luma => Red,
+    LumaA => RG,
+    Rgb => RGB,
+    Rgba => RGBA,
+}
+
+
+/// The unpack pixel storage parameters and [`BufferTarget::PixelUnpackBuffer`] binding, which decide where uploads read
+/// pixel data from. [`GLContext`] keeps a copy of these so that uploads can be checked without querying OpenGL.
+#[cfg(feature = "gl45")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct UnpackState {
+    pub(crate) store: PixelStore,
+    pub(crate) buffer: Option<BufferID>,
+}
+
+
+/// The pixel storage parameters that decide how pixel data sent to OpenGL is laid out in memory.
+#[cfg(any(feature = "gl45", feature = "trace"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PixelStore {
+    alignment: usize,
+    row_length: usize,
+    image_height: usize,
+    skip_pixels: usize,
+    skip_rows: usize,
+    skip_images: usize,
+}
+
+#[cfg(any(feature = "gl45", feature = "trace"))]
+impl Default for PixelStore {
+    fn default() -> Self {
+        Self {
+            alignment: 4,
+            row_length: 0,
+            image_height: 0,
+            skip_pixels: 0,
+            skip_rows: 0,
+            skip_images: 0,
+        }
+    }
+}
+
+#[cfg(any(feature = "gl45", feature = "trace"))]
+impl PixelStore {
+    /// Checks whether OpenGL accepts a value for a pixel storage parameter, rather than failing with
+    /// `GL_INVALID_VALUE`. Lengths can't be negative, and alignments must be 1, 2, 4, or 8.
+    pub(crate) fn is_valid(pname: GLenum, value: i64) -> bool {
+        match pname {
+            PACK_ALIGNMENT | UNPACK_ALIGNMENT => matches!(value, 1 | 2 | 4 | 8),
+            _ => value >= 0,
+        }
+    }
+
+    /// Updates one of the unpack parameters. Invalid values leave the parameter unchanged, just like they do in OpenGL.
+    pub(crate) fn set(&mut self, pname: GLenum, value: i64) {
+        if !Self::is_valid(pname, value) {
+            return;
+        }
+
+        let value = value as usize;
+        match pname {
+            UNPACK_ALIGNMENT => self.alignment = value,
+            UNPACK_ROW_LENGTH => self.row_length = value,
+            UNPACK_IMAGE_HEIGHT => self.image_height = value,
+            UNPACK_SKIP_PIXELS => self.skip_pixels = value,
+            UNPACK_SKIP_ROWS => self.skip_rows = value,
+            UNPACK_SKIP_IMAGES => self.skip_images = value,
+            _ => {},
+        }
+    }
+
+    /// Computes how many bytes an upload of the given size reads, from the start of its pointer to the end of its last
+    /// pixel. `dims` is the number of dimensions of the upload, and packed types have a single component the size of
+    /// the whole pixel. Returns `None` for sizes too big to fit in memory.
+    pub(crate) fn image_size(
+        &self,
+        pixel_size: usize,
+        component_size: usize,
+        [width, height, depth]: [usize; 3],
+        dims: usize,
+    ) -> Option<usize> {
+        if width == 0 || height == 0 || depth == 0 {
+            return Some(0);
+        }
+
+        let row_length = if self.row_length > 0 { self.row_length } else { width };
+        let row_bytes = row_length.checked_mul(pixel_size)?;
+
+        // Rows are padded to the unpack alignment, unless their components are already at least that big.
+        let row_stride = if component_size >= self.alignment {
+            row_bytes
+        } else {
+            row_bytes.div_ceil(self.alignment).checked_mul(self.alignment)?
+        };
+
+        let last_pixel = self.skip_pixels.checked_add(width)?.checked_mul(pixel_size)?;
+        if dims == 1 {
+            return Some(last_pixel);
+        }
+
+        let last_row = self.skip_rows.checked_add(height - 1)?.checked_mul(row_stride)?;
+        let last_image = if dims == 2 {
+            0
+        } else {
+            let image_height = if self.image_height > 0 { self.image_height } else { height };
+            let image_stride = image_height.checked_mul(row_stride)?;
+            self.skip_images.checked_add(depth - 1)?.checked_mul(image_stride)?
+        };
+        last_image.checked_add(last_row)?.checked_add(last_pixel)
+    }
+}
+
+
+impl GLContext {
+    #[cfg(feature = "gl45")]
+    pub fn create_texture(&self, target: TextureTarget) -> TextureID {
+        let mut name = 0;
+        unsafe { self.gl.create_textures(target.into_raw(), 1, &mut name) };
+        TextureID::new(name)
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn create_textures(&self, target: TextureTarget, n: usize) -> Vec<TextureID> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let mut names = vec![0; n];
+        let n = convert!(n, GLsizei, "number of textures");
+
+        unsafe { self.gl.create_textures(target.into_raw(), n, names.as_mut_ptr()) };
+        names.into_iter().map(TextureID::new).collect()
+    }
+
+
+    pub fn delete_texture(&self, texture: TextureID) {
+        unsafe { self.gl.delete_textures(1, &texture.into_raw()) }
+    }
+
+
+    pub fn delete_textures(&self, textures: &[TextureID]) {
+        let len = convert!(textures.len(), GLsizei, "number of textures");
+        let ptr = textures.as_ptr().cast(); // cast is safe because `TextureID` is `repr(transparent)`
+        unsafe { self.gl.delete_textures(len, ptr) }
+    }
+
+
+    pub fn bind_texture(&self, target: TextureTarget, texture: TextureID) {
+        unsafe { self.gl.bind_texture(target.into_raw(), texture.into_raw()) }
+    }
+
+
+    pub fn unbind_texture(&self, target: TextureTarget) {
+        unsafe { self.gl.bind_texture(target.into_raw(), 0) }
+    }
+
+
+    /// Binds a texture to the given texture unit, so that it can be accessed by samplers bound to that unit.
+    #[cfg(feature = "gl45")]
+    pub fn bind_texture_unit(&self, unit: u32, texture: TextureID) {
+        unsafe { self.gl.bind_texture_unit(unit, texture.into_raw()) }
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn unbind_texture_unit(&self, unit: u32) {
+        unsafe { self.gl.bind_texture_unit(unit, 0) }
+    }
+
+
+    pub fn pixel_store(&self, param: PixelStoreParameter, value: i32) {
+        unsafe { self.gl.pixel_store_i(param.into_raw(), value) }
+        #[cfg(feature = "gl45")]
+        self.update_unpack_state(|state| state.store.set(param.into_raw(), value.into()));
+    }
+
+
+    /// Allocates immutable storage for all `levels` mipmap levels of a one-dimensional texture.
+    #[cfg(feature = "gl45")]
+    pub fn texture_storage_1d(&self, texture: TextureID, levels: usize, format: InternalFormat, width: usize) {
+        let levels = convert!(levels, GLsizei, "number of texture levels");
+        let width = convert!(width, GLsizei, "texture width");
+        let (tex, format) = (texture.into_raw(), format.into_raw());
+        unsafe { self.gl.texture_storage_1_d(tex, levels, format, width) }
+    }
+
+
+    /// Allocates immutable storage for all `levels` mipmap levels of a two-dimensional texture, a one-dimensional array
+    /// texture (where `height` is the number of layers), or a cube map.
+    #[cfg(feature = "gl45")]
+    pub fn texture_storage_2d(
+        &self,
+        texture: TextureID,
+        levels: usize,
+        format: InternalFormat,
+        width: usize,
+        height: usize,
+    ) {
+        let levels = convert!(levels, GLsizei, "number of texture levels");
+        let width = convert!(width, GLsizei, "texture width");
+        let height = convert!(height, GLsizei, "texture height");
+        let (tex, format) = (texture.into_raw(), format.into_raw());
+        unsafe { self.gl.texture_storage_2_d(tex, levels, format, width, height) }
+    }
+
+
+    /// Allocates immutable storage for all `levels` mipmap levels of a three-dimensional texture, or a two-dimensional
+    /// array texture (where `depth` is the number of layers).
+    #[cfg(feature = "gl45")]
+    pub fn texture_storage_3d(
+        &self,
+        texture: TextureID,
+        levels: usize,
+        format: InternalFormat,
+        width: usize,
+        height: usize,
+        depth: usize,
+    ) {
+        let levels = convert!(levels, GLsizei, "number of texture levels");
+        let width = convert!(width, GLsizei, "texture width");
+        let height = convert!(height, GLsizei, "texture height");
+        let depth = convert!(depth, GLsizei, "texture depth");
+        let (tex, format) = (texture.into_raw(), format.into_raw());
+        unsafe { self.gl.texture_storage_3_d(tex, levels, format, width, height, depth) }
+    }
+
+
+    /// Uploads pixel data into a region of a one-dimensional texture.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `data` is too short to contain `width` pixels of the given format and type, laid out as
+    /// the current [pixel storage parameters][Self::pixel_store] say, or if a buffer is bound to
+    /// [`BufferTarget::PixelUnpackBuffer`].
+    #[cfg(feature = "gl45")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn texture_sub_image_1d(
+        &self,
+        texture: TextureID,
+        level: usize,
+        x_offset: usize,
+        width: usize,
+        format: PixelFormat,
+        ty: PixelType,
+        data: &[u8],
+    ) {
+        self.check_pixel_data(data, format, ty, [width, 1, 1], 1);
+
+        let level = convert!(level, GLint, "texture level");
+        let x_offset = convert!(x_offset, GLint, "texture x-offset");
+        let width = convert!(width, GLsizei, "texture width");
+        let ptr = data.as_ptr().cast();
+
+        unsafe {
+            let (tex, format, ty) = (texture.into_raw(), format.into_raw(), ty.into_raw());
+            self.gl.texture_sub_image_1_d(tex, level, x_offset, width, format, ty, ptr);
+        }
+    }
+
+
+    /// Uploads pixel data into a region of a two-dimensional texture, or a range of layers of a one-dimensional array
+    /// texture.
+    ///
+    /// Rows of `data` are read from the bottom of the texture upwards, and the start of each row must be aligned to the
+    /// current [unpack alignment][PixelStoreParameter::UnpackAlignment] (4 bytes, by default).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `data` is too short to contain `width × height` pixels of the given format and type,
+    /// laid out as the current [pixel storage parameters][Self::pixel_store] say, or if a buffer is bound to
+    /// [`BufferTarget::PixelUnpackBuffer`].
+    #[cfg(feature = "gl45")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn texture_sub_image_2d(
+        &self,
+        texture: TextureID,
+        level: usize,
+        (x_offset, y_offset): (usize, usize),
+        (width, height): (usize, usize),
+        format: PixelFormat,
+        ty: PixelType,
+        data: &[u8],
+    ) {
+        self.check_pixel_data(data, format, ty, [width, height, 1], 2);
+
+        let level = convert!(level, GLint, "texture level");
+        let x_offset = convert!(x_offset, GLint, "texture x-offset");
+        let y_offset = convert!(y_offset, GLint, "texture y-offset");
+        let width = convert!(width, GLsizei, "texture width");
+        let height = convert!(height, GLsizei, "texture height");
+        let ptr = data.as_ptr().cast();
+
+        unsafe {
+            let (tex, format, ty) = (texture.into_raw(), format.into_raw(), ty.into_raw());
+            let (x, y) = (x_offset, y_offset);
+            self.gl.texture_sub_image_2_d(tex, level, x, y, width, height, format, ty, ptr);
+        }
+    }
+
+
+    /// Uploads pixel data into a region of a three-dimensional texture, or a range of layers of a two-dimensional array
+    /// texture or cube map (see [`CubeMapFace::layer`]).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `data` is too short to contain `width × height × depth` pixels of the given format and
+    /// type, laid out as the current [pixel storage parameters][Self::pixel_store] say, or if a buffer is bound to
+    /// [`BufferTarget::PixelUnpackBuffer`].
+    #[cfg(feature = "gl45")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn texture_sub_image_3d(
+        &self,
+        texture: TextureID,
+        level: usize,
+        (x_offset, y_offset, z_offset): (usize, usize, usize),
+        (width, height, depth): (usize, usize, usize),
+        format: PixelFormat,
+        ty: PixelType,
+        data: &[u8],
+    ) {
+        self.check_pixel_data(data, format, ty, [width, height, depth], 3);
+
+        let level = convert!(level, GLint, "texture level");
+        let x_offset = convert!(x_offset, GLint, "texture x-offset");
+        let y_offset = convert!(y_offset, GLint, "texture y-offset");
+        let z_offset = convert!(z_offset, GLint, "texture z-offset");
+        let width = convert!(width, GLsizei, "texture width");
+        let height = convert!(height, GLsizei, "texture height");
+        let depth = convert!(depth, GLsizei, "texture depth");
+        let ptr = data.as_ptr().cast();
+
+        unsafe {
+            let (tex, format, ty) = (texture.into_raw(), format.into_raw(), ty.into_raw());
+            let (x, y, z) = (x_offset, y_offset, z_offset);
+            let (w, h, d) = (width, height, depth);
+            self.gl.texture_sub_image_3_d(tex, level, x, y, z, w, h, d, format, ty, ptr);
+        }
+    }
+
+
+    /// Uploads an image from the [`image`] crate into a region of a two-dimensional texture.
+    ///
+    /// Images are stored top row first, whereas OpenGL expects textures to start from the bottom row. This function
+    /// does not flip the image, so the top of the image ends up at `t = 0`; either flip it beforehand with
+    /// [`image::imageops::flip_vertical`], or flip the `t` texture coordinate when sampling.
+    ///
+    /// Because [`ImageBuffer`]s are always tightly packed, the [unpack alignment][PixelStoreParameter::UnpackAlignment]
+    /// is temporarily set to 1 while uploading.
+    #[cfg(feature = "gl45")]
+    pub fn texture_sub_image_2d_from_image<P, C>(
+        &self,
+        texture: TextureID,
+        level: usize,
+        offset: (usize, usize),
+        image: &ImageBuffer<P, C>,
+    ) where
+        P: TexturePixel,
+        [P::Subpixel]: EncodableLayout,
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        let (width, height) = image.dimensions();
+        let size = (width as usize, height as usize);
+        let data = image.as_raw().as_bytes();
+
+        self.with_tight_unpacking(|| {
+            self.texture_sub_image_2d(texture, level, offset, size, P::FORMAT, P::TYPE, data);
+        });
+    }
+
+
+    /// Panics if `data` is too short for an upload of the given size, laid out as the current unpack pixel storage
+    /// parameters say, or if a buffer is bound to [`BufferTarget::PixelUnpackBuffer`] (since OpenGL would then treat
+    /// `data`'s address as an offset into that buffer).
+    #[cfg(feature = "gl45")]
+    fn check_pixel_data(&self, data: &[u8], format: PixelFormat, ty: PixelType, size: [usize; 3], dims: usize) {
+        let UnpackState { store, buffer } = self.unpack_state();
+        assert!(
+            buffer.is_none(),
+            "pixel data can't be uploaded from a slice while a buffer is bound to `PixelUnpackBuffer`",
+        );
+
+        let [width, height, depth] = size;
+        // Sizes too big to fit in memory are too big for any slice
+        let expected = store
+            .image_size(format.pixel_size(ty), ty.size(), size, dims)
+            .unwrap_or(usize::MAX);
+        assert!(
+            data.len() >= expected,
+            "pixel data should be at least {expected} bytes for a {width}×{height}×{depth} image of {format:?}/{ty:?} \
+             with the current pixel storage parameters (got {})",
+            data.len(),
+        );
+    }
+
+
+    /// Runs the given closure with the [unpack alignment][PixelStoreParameter::UnpackAlignment] set to 1, restoring
+    /// the previous value afterwards.
+    #[cfg(feature = "gl45")]
+    pub(crate) fn with_tight_unpacking<R>(&self, f: impl FnOnce() -> R) -> R {
+        let alignment = self.unpack_state().store.alignment as i32;
+
+        self.pixel_store(PixelStoreParameter::UnpackAlignment, 1);
+        let result = f();
+        self.pixel_store(PixelStoreParameter::UnpackAlignment, alignment);
+        result
+    }
+
+
+    /// Gets the current [`UnpackState`], querying it from OpenGL the first time.
+    #[cfg(feature = "gl45")]
+    fn unpack_state(&self) -> UnpackState {
+        if let Some(state) = self.unpack_state.get() {
+            return state;
+        }
+
+        let mut store = PixelStore::default();
+        store.set(UNPACK_ALIGNMENT, self.get(UnpackAlignment).into());
+        store.set(UNPACK_ROW_LENGTH, self.get(UnpackRowLength).into());
+        store.set(UNPACK_IMAGE_HEIGHT, self.get(UnpackImageHeight).into());
+        store.set(UNPACK_SKIP_PIXELS, self.get(UnpackSkipPixels).into());
+        store.set(UNPACK_SKIP_ROWS, self.get(UnpackSkipRows).into());
+        store.set(UNPACK_SKIP_IMAGES, self.get(UnpackSkipImages).into());
+
+        let buffer = self.get(PixelUnpackBufferBinding);
+        let state = UnpackState { store, buffer };
+        self.unpack_state.set(Some(state));
+        state
+    }
+
+
+    /// Updates the cached [`UnpackState`] after a change to it. Does nothing if it hasn't been queried yet.
+    #[cfg(feature = "gl45")]
+    pub(crate) fn update_unpack_state(&self, f: impl FnOnce(&mut UnpackState)) {
+        if let Some(mut state) = self.unpack_state.get() {
+            f(&mut state);
+            self.unpack_state.set(Some(state));
+        }
+    }
+
+
+    /// Generates all mipmap levels of a texture from its base level.
+    #[cfg(feature = "gl45")]
+    pub fn generate_texture_mipmap(&self, texture: TextureID) {
+        unsafe { self.gl.generate_texture_mipmap(texture.into_raw()) }
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn texture_wrap(&self, texture: TextureID, axis: TextureWrapAxis, mode: TextureWrap) {
+        let mode = mode.into_raw() as GLint;
+        unsafe { self.gl.texture_parameter_i(texture.into_raw(), axis.into_raw(), mode) }
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn texture_min_filter(&self, texture: TextureID, filter: TextureMinFilter) {
+        let filter = filter.into_raw() as GLint;
+        unsafe { self.gl.texture_parameter_i(texture.into_raw(), TEXTURE_MIN_FILTER, filter) }
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn texture_mag_filter(&self, texture: TextureID, filter: TextureMagFilter) {
+        let filter = filter.into_raw() as GLint;
+        unsafe { self.gl.texture_parameter_i(texture.into_raw(), TEXTURE_MAG_FILTER, filter) }
+    }
+
+
+    /// Sets the maximum degree of anisotropy used when sampling a texture. A value of `1.0` disables anisotropic
+    /// filtering.
+    #[cfg(all(feature = "gl45", any(feature = "gl46", feature = "ext-texture-filter-anisotropic")))]
+    pub fn texture_max_anisotropy(&self, texture: TextureID, max: f32) {
+        unsafe { self.gl.texture_parameter_f(texture.into_raw(), TEXTURE_MAX_ANISOTROPY, max) }
+    }
+
+
+    /// Sets which components are returned, in `[r, g, b, a]` order, when sampling a texture.
+    #[cfg(feature = "gl45")]
+    pub fn texture_swizzle(&self, texture: TextureID, swizzle: [TextureSwizzle; 4]) {
+        let swizzle = swizzle.map(|s| s.into_raw() as GLint);
+        let ptr = swizzle.as_ptr();
+        unsafe { self.gl.texture_parameter_iv(texture.into_raw(), TEXTURE_SWIZZLE_RGBA, ptr) }
+    }
+
+
+    /// Sets the color returned when sampling outside of a texture with [`TextureWrap::ClampToBorder`].
+    #[cfg(feature = "gl45")]
+    pub fn texture_border_color(&self, texture: TextureID, color: [f32; 4]) {
+        let ptr = color.as_ptr();
+        unsafe { self.gl.texture_parameter_fv(texture.into_raw(), TEXTURE_BORDER_COLOR, ptr) }
+    }
+
+
+    /// Gets a bindless handle to a texture, which samples it using the texture's own sampling parameters. The texture
+    /// becomes immutable once a handle has been made for it.
+    #[cfg(feature = "arb-bindless-texture")]
+    pub fn get_texture_handle(&self, texture: TextureID) -> TextureHandle {
+        TextureHandle::new(unsafe { self.gl.get_texture_handle_arb(texture.into_raw()) })
+    }
+
+
+    /// Gets a bindless handle to a texture that samples it using the parameters of the given sampler object instead of
+    /// the texture's own.
+    #[cfg(feature = "arb-bindless-texture")]
+    pub fn get_texture_sampler_handle(&self, texture: TextureID, sampler: SamplerID) -> TextureHandle {
+        let handle = unsafe { self.gl.get_texture_sampler_handle_arb(texture.into_raw(), sampler.into_raw()) };
+        TextureHandle::new(handle)
+    }
+
+
+    /// Makes a bindless texture handle accessible to shaders. Handles must be resident while any draw call that uses
+    /// them is executing.
+    #[cfg(feature = "arb-bindless-texture")]
+    pub fn make_texture_handle_resident(&self, handle: TextureHandle) {
+        unsafe { self.gl.make_texture_handle_resident_arb(handle.into_raw()) }
+    }
+
+
+    #[cfg(feature = "arb-bindless-texture")]
+    pub fn make_texture_handle_non_resident(&self, handle: TextureHandle) {
+        unsafe { self.gl.make_texture_handle_non_resident_arb(handle.into_raw()) }
+    }
+
+
+    #[cfg(feature = "arb-bindless-texture")]
+    pub fn is_texture_handle_resident(&self, handle: TextureHandle) -> bool {
+        unsafe { self.gl.is_texture_handle_resident_arb(handle.into_raw()) != 0 }
+    }
+
+
+    /// Sets a `sampler*` uniform of the current program to a bindless texture handle.
+    #[cfg(feature = "arb-bindless-texture")]
+    pub fn uniform_texture_handle(&self, location: UniformLocation, handle: TextureHandle) {
+        unsafe { self.gl.uniform_handle_ui64_arb(location.into_raw(), handle.into_raw()) }
+    }
+
+
+    /// Sets a `sampler*` uniform of the given program to a bindless texture handle.
+    #[cfg(feature = "arb-bindless-texture")]
+    pub fn program_uniform_texture_handle(&self, program: ProgramID, location: UniformLocation, handle: TextureHandle) {
+        let (program, location) = (program.into_raw(), location.into_raw());
+        unsafe { self.gl.program_uniform_handle_ui64_arb(program, location, handle.into_raw()) }
+    }
+}
+
+
+#[cfg(all(test, feature = "mock", feature = "gl45"))]
+mod tests {
+    use super::*;
+    use crate::mock::{self, Arg, Call};
+    use crate::raw::PIXEL_UNPACK_BUFFER_BINDING;
+
+    /// Uploads a 3×3 RGB image to texture 1, and returns the calls that it made.
+    fn upload(gl: &GLContext, data: &[u8]) -> Vec<Call> {
+        let (texture, format, ty) = (TextureID::new(1), PixelFormat::RGB, PixelType::UnsignedByte);
+        gl.texture_sub_image_2d(texture, 0, (0, 0), (3, 3), format, ty, data);
+        let calls = mock::take_calls().into_iter();
+        calls.filter(|call| call.name != "glGetError").collect()
+    }
+
+
+    #[test]
+    fn texture_uploads_respect_unpack_alignment() {
+        let gl = mock::context();
+
+        // Three RGB pixels make nine-byte rows, which the default alignment pads to twelve bytes (except the last)
+        let calls = upload(&gl, &[0; 33]);
+        let queries = calls.iter().filter(|call| call.name == "glGetIntegerv");
+        let queried = queries.map(|call| call.arg("pname").cloned()).collect::<Vec<_>>();
+        let expected = [
+            UNPACK_ALIGNMENT,
+            UNPACK_ROW_LENGTH,
+            UNPACK_IMAGE_HEIGHT,
+            UNPACK_SKIP_PIXELS,
+            UNPACK_SKIP_ROWS,
+            UNPACK_SKIP_IMAGES,
+            PIXEL_UNPACK_BUFFER_BINDING,
+        ];
+        assert_eq!(queried, expected.map(|pname| Some(Arg::Enum(pname))));
+
+        let upload_call = calls.last().unwrap();
+        assert_eq!(upload_call.name, "glTextureSubImage2D");
+        assert_eq!(upload_call.arg("width"), Some(&Arg::Int(3)));
+        assert_eq!(upload_call.arg("format"), Some(&Arg::Enum(crate::raw::RGB)));
+
+        // The unpack state is only queried once, and is kept up to date as it changes
+        gl.pixel_store(PixelStoreParameter::UnpackAlignment, 1);
+        let calls = upload(&gl, &[0; 27]);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].args, [("pname", Arg::Enum(UNPACK_ALIGNMENT)), ("param", Arg::Int(1))]);
+        assert_eq!(calls[1].name, "glTextureSubImage2D");
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 33 bytes")]
+    fn texture_uploads_check_row_padding() {
+        let gl = mock::context();
+        upload(&gl, &[0; 27]);
+    }
+
+    #[test]
+    #[should_panic(expected = "bound to `PixelUnpackBuffer`")]
+    fn texture_uploads_check_unpack_buffer() {
+        let gl = mock::context();
+        mock::set_query_result(PIXEL_UNPACK_BUFFER_BINDING, &[1]);
+        upload(&gl, &[0; 33]);
+    }
+
+    #[test]
+    #[should_panic(expected = "bound to `PixelUnpackBuffer`")]
+    fn texture_uploads_track_unpack_buffer_binding() {
+        let gl = mock::context();
+        upload(&gl, &[0; 33]);
+
+        // The query above reported no buffer, so the panic can only come from noticing the binding
+        gl.bind_buffer(BufferTarget::PixelUnpackBuffer, BufferID::new(1));
+        upload(&gl, &[0; 33]);
+    }
+
+    #[test]
+    fn texture_uploads_track_unpack_buffer_deletion() {
+        let gl = mock::context();
+        upload(&gl, &[0; 33]);
+
+        gl.bind_buffer(BufferTarget::PixelUnpackBuffer, BufferID::new(1));
+        gl.delete_buffer(BufferID::new(1));
+        mock::take_calls();
+
+        // Deleting the bound buffer unbinds it, without needing to query the binding again
+        let calls = upload(&gl, &[0; 33]);
+        assert_eq!(calls.iter().map(|call| call.name).collect::<Vec<_>>(), ["glTextureSubImage2D"]);
+    }
+}
@@ -69,6 +69,76 @@ impl GLContext {
         // SAFETY: the implementors of `Uniform` are expected to uphold the safety contract.
         unsafe { T::set_uniform(&self.gl, location.into_raw(), value.count(), value.get_ptr()) }
     }
+
+    /// Looks up the location of a uniform and checks that its GLSL type can be set from a `T`, using
+    /// [program introspection][Self::get_active_uniforms].
+    ///
+    /// Unlike [`get_uniform_location`][Self::get_uniform_location], a missing uniform is reported as an error rather
+    /// than `None`, so that typos can be caught with `?` or `expect` when a program is first loaded.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let u_light_pos = gl.get_typed_uniform_location::<Vec3>(program, "uLightPos")?;
+    /// gl.typed_uniform(u_light_pos, &Vec3::new(1.0, 2.0, 3.0));
+    /// // gl.typed_uniform(u_light_pos, &Mat4::IDENTITY); // <-- does not compile
+    /// ```
+    #[cfg(feature = "gl45")]
+    pub fn get_typed_uniform_location<T: Uniform>(
+        &self,
+        program: ProgramID,
+        name: &str,
+    ) -> Result<TypedUniformLocation<T>, UniformLocationError> {
+        let not_found = || UniformLocationError::NotFound(name.to_string());
+        let location = self.get_uniform_location(program, name).ok_or_else(not_found)?;
+
+        // Individual array elements (`foo[3]`) have locations, but are not active resources of their own; so the type
+        // has to be checked on the array itself.
+        let (base_name, element) = match name.strip_suffix(']').and_then(|s| s.rsplit_once('[')) {
+            Some((base, index)) => match index.parse::<usize>() {
+                Ok(index) => (base, index),
+                Err(_) => (name, 0),
+            },
+            None => (name, 0),
+        };
+
+        let interface = ProgramInterface::Uniform;
+        let index = self.get_program_resource_index(program, interface, base_name);
+        let index = index.ok_or_else(not_found)?;
+
+        let properties = [ProgramResourceProperty::Type, ProgramResourceProperty::ArraySize];
+        let [ty, array_size] = self.get_program_resource(program, interface, index, properties);
+        let glsl_type = GlslType::from_raw(ty as u32);
+
+        // The location refers to `element`, so only the elements after it can be set through it.
+        let remaining = (array_size as usize).saturating_sub(element);
+
+        match glsl_type {
+            Some(ty) if T::accepts_glsl_type(ty) => Ok(TypedUniformLocation::new(location, remaining)),
+            _ => Err(UniformLocationError::TypeMismatch {
+                name: name.to_string(),
+                glsl_type,
+                rust_type: std::any::type_name::<T>(),
+            }),
+        }
+    }
+
+    /// Sets a uniform through a [`TypedUniformLocation`], which guarantees that `value` has the right type for it.
+    pub fn typed_uniform<T: Uniform>(&self, location: TypedUniformLocation<T>, value: &T) {
+        self.uniform(location.untyped(), value)
+    }
+
+    /// Sets consecutive elements of an array uniform through a [`TypedUniformLocation`], starting from the element that
+    /// the location refers to.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more values are given than there are elements in the array.
+    pub fn typed_uniform_array<T: Uniform>(&self, location: TypedUniformLocation<T>, values: &[T]) {
+        let size = location.array_size();
+        assert!(values.len() <= size, "{} values given for a uniform array of size {size}", values.len());
+        self.uniform(location.untyped(), &values)
+    }
 }
 
 
@@ -174,6 +244,16 @@ pub unsafe trait Uniform {
     /// [`get_ptr()`]: Uniform::get_ptr
     /// [`count()`]: Uniform::count
     unsafe fn set_uniform(gl: &GLPointers, location: GLint, count: GLsizei, value: *const Self::PtrType);
+
+    /// Checks whether uniforms of the given GLSL type can be set from values of this type. This is used by
+    /// [`GLContext::get_typed_uniform_location`] to check uniforms' types ahead of time.
+    ///
+    /// The default implementation accepts no types at all, so a custom implementation of this trait cannot be used with
+    /// typed locations unless it also implements this method.
+    fn accepts_glsl_type(ty: GlslType) -> bool {
+        let _ = ty;
+        false
+    }
 }
 
 // ---------------------------------------------------------------------------------------------------------------------
@@ -197,8 +277,39 @@ macro_rules! uniform_type {
     (Vec4) => (GLfloat);
 }
 
+#[rustfmt::skip]
+macro_rules! accepts_glsl_type {
+    // Opaque types (samplers and images) are set to the index of the unit that they should read from.
+    (i32, $ty:ident) => (matches!($ty, GlslType::Int) || $ty.is_sampler() || $ty.is_image());
+    ([i32; 1], $ty:ident) => (matches!($ty, GlslType::Int) || $ty.is_sampler() || $ty.is_image());
+
+    (f32, $ty:ident) => (matches!($ty, GlslType::Float));
+    (u32, $ty:ident) => (matches!($ty, GlslType::UnsignedInt));
+    (bool, $ty:ident) => (matches!($ty, GlslType::Bool));
+
+    ([f32; 1], $ty:ident) => (matches!($ty, GlslType::Float));
+    ([f32; 2], $ty:ident) => (matches!($ty, GlslType::FloatVec2));
+    ([f32; 3], $ty:ident) => (matches!($ty, GlslType::FloatVec3));
+    ([f32; 4], $ty:ident) => (matches!($ty, GlslType::FloatVec4));
+    ([i32; 2], $ty:ident) => (matches!($ty, GlslType::IntVec2));
+    ([i32; 3], $ty:ident) => (matches!($ty, GlslType::IntVec3));
+    ([i32; 4], $ty:ident) => (matches!($ty, GlslType::IntVec4));
+    ([u32; 1], $ty:ident) => (matches!($ty, GlslType::UnsignedInt));
+    ([u32; 2], $ty:ident) => (matches!($ty, GlslType::UnsignedIntVec2));
+    ([u32; 3], $ty:ident) => (matches!($ty, GlslType::UnsignedIntVec3));
+    ([u32; 4], $ty:ident) => (matches!($ty, GlslType::UnsignedIntVec4));
+    ([bool; 1], $ty:ident) => (matches!($ty, GlslType::Bool));
+    ([bool; 2], $ty:ident) => (matches!($ty, GlslType::BoolVec2));
+    ([bool; 3], $ty:ident) => (matches!($ty, GlslType::BoolVec3));
+    ([bool; 4], $ty:ident) => (matches!($ty, GlslType::BoolVec4));
+
+    (Vec2, $ty:ident) => (matches!($ty, GlslType::FloatVec2));
+    (Vec3, $ty:ident) => (matches!($ty, GlslType::FloatVec3));
+    (Vec4, $ty:ident) => (matches!($ty, GlslType::FloatVec4));
+}
+
 macro_rules! impl_uniform {
-    (matrix, $rs_type:ty, $func:ident) => {
+    (matrix, $rs_type:ty, $func:ident, $glsl_type:ident) => {
         unsafe impl Uniform for $rs_type {
             type PtrType = GLfloat; // Only `fv` matrices
 
@@ -213,6 +324,10 @@ macro_rules! impl_uniform {
             unsafe fn set_uniform(gl: &GLPointers, location: GLint, count: GLsizei, value: *const Self::PtrType) {
                 unsafe { gl.$func(location, count, false as GLboolean, value) } // false for transpose
             }
+
+            fn accepts_glsl_type(ty: GlslType) -> bool {
+                ty == GlslType::$glsl_type
+            }
         }
     };
 
@@ -231,6 +346,10 @@ macro_rules! impl_uniform {
             unsafe fn set_uniform(gl: &GLPointers, location: GLint, count: GLsizei, value: *const Self::PtrType) {
                 unsafe { gl.$func(location, count, value) }
             }
+
+            fn accepts_glsl_type(ty: GlslType) -> bool {
+                accepts_glsl_type!($rs_type, ty)
+            }
         }
     };
 
@@ -272,13 +391,13 @@ impl_uniform!(as_ptr, Vec2, uniform_2fv);
 impl_uniform!(as_ptr, Vec3, uniform_3fv);
 impl_uniform!(as_ptr, Vec4, uniform_4fv);
 
-impl_uniform!(matrix, Mat2, uniform_matrix_2fv);
-impl_uniform!(matrix, Mat3, uniform_matrix_3fv);
-impl_uniform!(matrix, Mat4, uniform_matrix_4fv);
+impl_uniform!(matrix, Mat2, uniform_matrix_2fv, FloatMat2);
+impl_uniform!(matrix, Mat3, uniform_matrix_3fv, FloatMat3);
+impl_uniform!(matrix, Mat4, uniform_matrix_4fv, FloatMat4);
 
-impl_uniform!(matrix, [[f32; 2]; 2], uniform_matrix_2fv);
-impl_uniform!(matrix, [[f32; 3]; 3], uniform_matrix_3fv);
-impl_uniform!(matrix, [[f32; 4]; 4], uniform_matrix_4fv);
+impl_uniform!(matrix, [[f32; 2]; 2], uniform_matrix_2fv, FloatMat2);
+impl_uniform!(matrix, [[f32; 3]; 3], uniform_matrix_3fv, FloatMat3);
+impl_uniform!(matrix, [[f32; 4]; 4], uniform_matrix_4fv, FloatMat4);
 
 
 /// Because all uniforms make use of the `Uniform*v` functions, it can be safely implemented it for all slices by simply
@@ -302,4 +421,55 @@ where
         // overrides of `count()` and `as_ptr()` will be used for the `count` and `ptr` parameters.
         T::set_uniform(gl, location, count, ptr)
     }
+
+    fn accepts_glsl_type(ty: GlslType) -> bool {
+        T::accepts_glsl_type(ty)
+    }
+}
+
+
+#[cfg(all(test, feature = "mock", feature = "gl45"))]
+mod tests {
+    use gloog_math::Vec3;
+
+    use super::*;
+    use crate::mock::{self, Arg};
+    use crate::raw::{ARRAY_SIZE, FLOAT_VEC3, TYPE, UNIFORM};
+
+    #[test]
+    fn typed_uniform_locations() {
+        let gl = mock::context();
+        let program = ProgramID::new(1);
+        mock::set_return_value("glGetUniformLocation", 2);
+        mock::set_query_result(TYPE, &[FLOAT_VEC3 as i64]);
+        mock::set_query_result(ARRAY_SIZE, &[1]);
+
+        let location = gl.get_typed_uniform_location::<Vec3>(program, "uLightPos").unwrap();
+        assert_eq!(location.untyped(), UniformLocation::new(2));
+        assert_eq!(location.array_size(), 1);
+
+        let calls = mock::take_calls().into_iter().filter(|call| call.name != "glGetError");
+        let calls = calls.collect::<Vec<_>>();
+        assert_eq!(calls[0].name, "glGetUniformLocation");
+        assert_eq!(calls[1].name, "glGetProgramResourceIndex");
+        assert_eq!(calls[2].name, "glGetProgramResourceiv");
+        assert_eq!(calls[0].arg("name"), Some(&Arg::Str("uLightPos".to_string())));
+        assert_eq!(calls[1].args, [
+            ("program", Arg::UInt(1)),
+            ("program_interface", Arg::Enum(UNIFORM)),
+            ("name", Arg::Str("uLightPos".to_string())),
+        ]);
+        assert_eq!(calls[2].arg("prop_count"), Some(&Arg::Int(2)));
+
+        let error = gl.get_typed_uniform_location::<f32>(program, "uLightPos").unwrap_err();
+        assert_eq!(error, UniformLocationError::TypeMismatch {
+            name: "uLightPos".to_string(),
+            glsl_type: Some(GlslType::FloatVec3),
+            rust_type: "f32",
+        });
+
+        mock::set_return_value("glGetUniformLocation", -1);
+        let error = gl.get_typed_uniform_location::<Vec3>(program, "uLightPos").unwrap_err();
+        assert_eq!(error, UniformLocationError::NotFound("uLightPos".to_string()));
+    }
 }
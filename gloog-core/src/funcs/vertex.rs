@@ -2,9 +2,33 @@ use std::ffi::CString;
 
 use crate::raw::types::*;
 use crate::types::*;
+#[cfg(feature = "gl45")]
+use crate::vertex::{AttribKind, VertexAttrib, VertexLayout};
 use crate::{convert, GLContext};
 
+
+/// Checks that a vertex attribute has a valid number of components.
+fn attrib_size(size: usize) -> GLint {
+    match size {
+        n @ 1..=4 => n as GLint,
+        n if n == (crate::raw::BGRA as usize) => n as GLint,
+        _ => panic!("vertex attribute size should be 1, 2, 3, 4, or GL_BGRA"),
+    }
+}
+
+/// Checks that an integer or double vertex attribute has a valid number of components. Unlike with floating-point
+/// attributes, `GL_BGRA` is not allowed.
+#[cfg(feature = "gl45")]
+fn integer_attrib_size(size: usize) -> GLint {
+    match size {
+        n @ 1..=4 => n as GLint,
+        _ => panic!("integer and double vertex attribute sizes should be 1, 2, 3, or 4"),
+    }
+}
+
+
 impl GLContext {
+    #[cfg(feature = "gl45")]
     pub fn create_vertex_array(&self) -> VertexArrayID {
         let mut name = 0;
         unsafe { self.gl.create_vertex_arrays(1, &mut name) };
@@ -12,6 +36,7 @@ impl GLContext {
     }
 
 
+    #[cfg(feature = "gl45")]
     pub fn create_vertex_arrays(&self, n: usize) -> Vec<VertexArrayID> {
         if n == 0 {
             return vec![];
@@ -25,6 +50,18 @@ impl GLContext {
     }
 
 
+    pub fn delete_vertex_array(&self, vao: VertexArrayID) {
+        unsafe { self.gl.delete_vertex_arrays(1, &vao.into_raw()) }
+    }
+
+
+    pub fn delete_vertex_arrays(&self, vaos: &[VertexArrayID]) {
+        let len = convert!(vaos.len(), GLsizei, "number of vertex arrays");
+        let ptr = vaos.as_ptr().cast(); // cast is safe because `VertexArrayID` is `repr(transparent)`
+        unsafe { self.gl.delete_vertex_arrays(len, ptr) }
+    }
+
+
     pub fn bind_vertex_array(&self, vao: VertexArrayID) {
         unsafe { self.gl.bind_vertex_array(vao.into_raw()) }
     }
@@ -62,12 +99,7 @@ impl GLContext {
         let offset = offset as *const _;
         let attrib = attrib_type.into_raw();
 
-        let size = match size {
-            n @ 1..=4 => n as GLsizei,
-            n if n == (crate::raw::BGRA as usize) => n as GLsizei,
-            _ => panic!("vertex attribute size should be 1, 2, 3, 4, or GL_BGRA"),
-        };
-
+        let size = attrib_size(size);
         unsafe { self.gl.vertex_attrib_pointer(index, size, attrib, normalized, stride, offset) }
     }
 
@@ -78,15 +110,193 @@ impl GLContext {
     }
 
 
-    pub fn draw_arrays(&self, mode: DrawMode, first: usize, count: usize) {
-        let first = convert!(first, GLint, "draw arrays index");
-        let count = convert!(count, GLsizei, "draw arrays count");
-        unsafe { self.gl.draw_arrays(mode.into_raw(), first, count) }
+    pub fn disable_vertex_attrib_array(&self, index: impl Into<VertexAttribLocation>) {
+        let index = index.into().0;
+        unsafe { self.gl.disable_vertex_attrib_array(index) }
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn enable_vertex_array_attrib(&self, vao: VertexArrayID, index: impl Into<VertexAttribLocation>) {
+        let index = index.into().0;
+        unsafe { self.gl.enable_vertex_array_attrib(vao.into_raw(), index) }
+    }
+
+
+    #[cfg(feature = "gl45")]
+    pub fn disable_vertex_array_attrib(&self, vao: VertexArrayID, index: impl Into<VertexAttribLocation>) {
+        let index = index.into().0;
+        unsafe { self.gl.disable_vertex_array_attrib(vao.into_raw(), index) }
+    }
+
+
+    /// Sets the buffer that a vertex array sources its indices from for indexed draw calls.
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_element_buffer(&self, vao: VertexArrayID, buffer: BufferID) {
+        unsafe { self.gl.vertex_array_element_buffer(vao.into_raw(), buffer.into_raw()) }
+    }
+
+
+    /// Binds a buffer to one of a vertex array's vertex buffer binding points. Vertices are read from the buffer
+    /// starting at `offset` bytes, `stride` bytes apart.
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_vertex_buffer(
+        &self,
+        vao: VertexArrayID,
+        binding_index: u32,
+        buffer: BufferID,
+        offset: usize,
+        stride: usize,
+    ) {
+        let offset = convert!(offset, GLintptr, "vertex buffer offset");
+        let stride = convert!(stride, GLsizei, "vertex buffer stride");
+        let (vao, buffer) = (vao.into_raw(), buffer.into_raw());
+        unsafe { self.gl.vertex_array_vertex_buffer(vao, binding_index, buffer, offset, stride) }
+    }
+
+
+    /// Unbinds whatever buffer is bound to one of a vertex array's vertex buffer binding points.
+    #[cfg(feature = "gl45")]
+    pub fn unbind_vertex_array_vertex_buffer(&self, vao: VertexArrayID, binding_index: u32) {
+        unsafe { self.gl.vertex_array_vertex_buffer(vao.into_raw(), binding_index, 0, 0, 0) }
+    }
+
+
+    /// Specifies the format of one of a vertex array's attributes, for attributes that are read as floating-point
+    /// values. `relative_offset` is the offset of the attribute from the start of each vertex, in bytes.
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_attrib_format(
+        &self,
+        vao: VertexArrayID,
+        index: impl Into<VertexAttribLocation>,
+        size: usize,
+        attrib_type: VertexAttribType,
+        normalized: bool,
+        relative_offset: usize,
+    ) {
+        let index = index.into().0;
+        let size = attrib_size(size);
+        let normalized = convert!(normalized, GLboolean, "'normalized' parameter");
+        let offset = convert!(relative_offset, GLuint, "vertex attribute relative offset");
+        let (vao, attrib) = (vao.into_raw(), attrib_type.into_raw());
+        unsafe { self.gl.vertex_array_attrib_format(vao, index, size, attrib, normalized, offset) }
+    }
+
+
+    /// Specifies the format of one of a vertex array's attributes, for attributes that are read as integers. `size`
+    /// must be between 1 and 4, since `GL_BGRA` can only be used with [`vertex_array_attrib_format`].
+    ///
+    /// [`vertex_array_attrib_format`]: Self::vertex_array_attrib_format
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_attrib_i_format(
+        &self,
+        vao: VertexArrayID,
+        index: impl Into<VertexAttribLocation>,
+        size: usize,
+        attrib_type: IntegerVertexAttribType,
+        relative_offset: usize,
+    ) {
+        let index = index.into().0;
+        let size = integer_attrib_size(size);
+        let offset = convert!(relative_offset, GLuint, "vertex attribute relative offset");
+        let (vao, attrib) = (vao.into_raw(), attrib_type.into_raw());
+        unsafe { self.gl.vertex_array_attrib_i_format(vao, index, size, attrib, offset) }
+    }
+
+
+    /// Specifies the format of one of a vertex array's attributes, for attributes that are read as 64-bit doubles.
+    /// `size` must be between 1 and 4.
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_attrib_l_format(
+        &self,
+        vao: VertexArrayID,
+        index: impl Into<VertexAttribLocation>,
+        size: usize,
+        relative_offset: usize,
+    ) {
+        let index = index.into().0;
+        let size = integer_attrib_size(size);
+        let offset = convert!(relative_offset, GLuint, "vertex attribute relative offset");
+        let ty = VertexAttribType::Double.into_raw();
+        unsafe { self.gl.vertex_array_attrib_l_format(vao.into_raw(), index, size, ty, offset) }
+    }
+
+
+    /// Sets which of a vertex array's vertex buffer binding points one of its attributes reads from.
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_attrib_binding(
+        &self,
+        vao: VertexArrayID,
+        index: impl Into<VertexAttribLocation>,
+        binding_index: u32,
+    ) {
+        let index = index.into().0;
+        unsafe { self.gl.vertex_array_attrib_binding(vao.into_raw(), index, binding_index) }
+    }
+
+
+    /// Sets how many instances are drawn before the attributes read from one of a vertex array's vertex buffer binding
+    /// points advance to the next vertex. A divisor of zero advances once per vertex instead.
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_binding_divisor(&self, vao: VertexArrayID, binding_index: u32, divisor: u32) {
+        unsafe { self.gl.vertex_array_binding_divisor(vao.into_raw(), binding_index, divisor) }
+    }
+
+
+    /// Configures and enables all of the attributes of a [`VertexLayout`] in a vertex array, reading them from the
+    /// given vertex buffer binding point.
+    ///
+    /// This does not bind a buffer to the binding point; use
+    /// [`vertex_array_vertex_buffer`][Self::vertex_array_vertex_buffer] with a stride of [`V::STRIDE`] for that.
+    ///
+    /// [`V::STRIDE`]: VertexLayout::STRIDE
+    #[cfg(feature = "gl45")]
+    pub fn vertex_array_layout<V: VertexLayout>(&self, vao: VertexArrayID, binding_index: u32) {
+        for attrib in V::ATTRIBUTES {
+            let VertexAttrib { location, ty, size, kind, offset } = *attrib;
+            match kind {
+                AttribKind::Float => self.vertex_array_attrib_format(vao, location, size, ty, false, offset),
+                AttribKind::Normalized => self.vertex_array_attrib_format(vao, location, size, ty, true, offset),
+                AttribKind::Integer => {
+                    let ty = IntegerVertexAttribType::from_raw(ty.into_raw())
+                        .unwrap_or_else(|| panic!("vertex attribute of type {ty:?} cannot be read as an integer"));
+                    self.vertex_array_attrib_i_format(vao, location, size, ty, offset);
+                },
+                AttribKind::Double => {
+                    assert_eq!(ty, VertexAttribType::Double, "only double attributes can be read as doubles");
+                    self.vertex_array_attrib_l_format(vao, location, size, offset);
+                },
+            }
+
+            self.vertex_array_attrib_binding(vao, location, binding_index);
+            self.enable_vertex_array_attrib(vao, location);
+        }
+    }
+}
+
+
+#[cfg(all(test, feature = "mock", feature = "gl45"))]
+mod tests {
+    use super::*;
+    use crate::mock::{self, Arg};
+    use crate::raw::BGRA;
+
+    #[test]
+    fn float_attributes_accept_bgra() {
+        let gl = mock::context();
+        let ty = VertexAttribType::UnsignedByte;
+        gl.vertex_array_attrib_format(VertexArrayID::new(1), 0, BGRA as usize, ty, true, 0);
+
+        let calls = mock::take_calls();
+        assert_eq!(calls[0].name, "glVertexArrayAttribFormat");
+        assert_eq!(calls[0].arg("size"), Some(&Arg::Int(BGRA as i64)));
     }
 
-    pub fn draw_elements(&self, mode: DrawMode, count: usize, ty: DrawElementsType, offset: usize) {
-        let count = convert!(count, GLsizei, "draw elements count");
-        let indices = offset as *const _;
-        unsafe { self.gl.draw_elements(mode.into_raw(), count, ty.into_raw(), indices) }
+    #[test]
+    #[should_panic(expected = "should be 1, 2, 3, or 4")]
+    fn integer_attributes_reject_bgra() {
+        let gl = mock::context();
+        let ty = IntegerVertexAttribType::UnsignedByte;
+        gl.vertex_array_attrib_i_format(VertexArrayID::new(1), 0, BGRA as usize, ty, 0);
     }
 }
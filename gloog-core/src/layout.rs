@@ -0,0 +1,445 @@
+//! Memory layouts for data stored in uniform and shader storage blocks.
+//!
+//! GLSL interface blocks declared with `layout(std140)` or `layout(std430)` have a well-defined memory layout, but it
+//! is not the same as Rust's: a `vec3` is aligned to 16 bytes, array elements and matrix columns in `std140` blocks are
+//! padded out to 16 bytes, and so on. Instead of requiring hand-inserted padding fields in every `#[repr(C)]` struct,
+//! types implement [`BlockData`] to describe their alignment and size under each [`MemoryLayout`], and are written into
+//! a [`BlockWriter`], which inserts the padding automatically.
+//!
+//! [`BlockData`] is implemented for `f32`, `i32`, `u32`, `bool`, all of the [`gloog_math`] vector and matrix types, and
+//! arrays of any of them. Structs can implement it with the [`block_struct!`][crate::block_struct] macro:
+//!
+//! ```ignore
+//! gloog_core::block_struct! {
+//!     #[derive(Debug, Clone, Copy, Default)]
+//!     pub struct Light {
+//!         pub diffuse: Vec4,
+//!         pub position: Vec3,
+//!     }
+//! }
+//!
+//! let lights: [Light; 16] = /* ... */;
+//! let bytes = Std140::to_bytes(&lights);
+//! gl.named_buffer_sub_data(light_buffer, 0, &bytes);
+//! gl.bind_buffer_base(IndexedBufferTarget::UniformBuffer, 0, light_buffer);
+//! ```
+//!
+//! The layout rules are described in section 7.6.2.2 of the [OpenGL 4.6 core specification][spec].
+//!
+//! [spec]: https://registry.khronos.org/OpenGL/specs/gl/glspec46.core.pdf
+
+use std::marker::PhantomData;
+
+use gloog_math::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+
+/// One of the standard memory layouts for GLSL interface blocks.
+pub trait MemoryLayout: Sized {
+    /// The name of this layout, as it appears in a GLSL `layout` qualifier.
+    const NAME: &'static str;
+
+    /// Rounds the alignment of an array's elements (or a matrix's columns) up to the minimum alignment that this
+    /// layout requires for them.
+    fn array_alignment(element_alignment: usize) -> usize;
+
+    /// Rounds the alignment of a struct (the largest alignment of any of its members) up to the minimum alignment that
+    /// this layout requires for it.
+    fn struct_alignment(member_alignment: usize) -> usize;
+
+    /// Returns the size of a value of type `T` in this layout, including any trailing padding.
+    fn size_of<T: BlockData>() -> usize {
+        T::size::<Self>()
+    }
+
+    /// Returns the alignment of a value of type `T` in this layout.
+    fn align_of<T: BlockData>() -> usize {
+        T::alignment::<Self>()
+    }
+
+    /// Writes a single value into a new byte buffer using this layout.
+    fn to_bytes<T: BlockData>(value: &T) -> Vec<u8> {
+        let mut writer = BlockWriter::<Self>::with_capacity(Self::size_of::<T>());
+        writer.write(value);
+        writer.into_bytes()
+    }
+}
+
+
+/// The `std140` layout, which can be used for both uniform blocks and shader storage blocks.
+///
+/// Arrays and structs are always aligned to at least 16 bytes, and array strides are rounded up to match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Std140 {}
+
+impl MemoryLayout for Std140 {
+    const NAME: &'static str = "std140";
+
+    fn array_alignment(element_alignment: usize) -> usize {
+        element_alignment.next_multiple_of(16)
+    }
+
+    fn struct_alignment(member_alignment: usize) -> usize {
+        member_alignment.next_multiple_of(16)
+    }
+}
+
+
+/// The `std430` layout, which can only be used for shader storage blocks.
+///
+/// This is the same as [`Std140`], except that arrays and structs are not rounded up to 16 bytes, so arrays of scalars
+/// and two-component vectors are tightly packed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Std430 {}
+
+impl MemoryLayout for Std430 {
+    const NAME: &'static str = "std430";
+
+    fn array_alignment(element_alignment: usize) -> usize {
+        element_alignment
+    }
+
+    fn struct_alignment(member_alignment: usize) -> usize {
+        member_alignment
+    }
+}
+
+
+/// A type that can be stored in a uniform or shader storage block.
+///
+/// Structs should generally implement this trait with the [`block_struct!`][crate::block_struct] macro rather than by
+/// hand.
+pub trait BlockData {
+    /// Returns the base alignment of this type in the given layout.
+    fn alignment<L: MemoryLayout>() -> usize;
+
+    /// Returns the size of this type in the given layout, including any trailing padding.
+    fn size<L: MemoryLayout>() -> usize;
+
+    /// Writes this value into a block.
+    ///
+    /// When this method is called, the writer has already been aligned to [`alignment`][Self::alignment]. It must
+    /// write exactly [`size`][Self::size] bytes, otherwise [`BlockWriter::write`] will panic.
+    fn write<L: MemoryLayout>(&self, writer: &mut BlockWriter<L>);
+}
+
+
+/// Writes values into a byte buffer using a given [`MemoryLayout`], inserting padding between them as required.
+///
+/// Offsets are relative to the start of the writer, which should correspond to the start of the interface block (or
+/// some other offset that is aligned to at least 16 bytes).
+#[derive(Debug, Clone)]
+pub struct BlockWriter<L: MemoryLayout> {
+    bytes: Vec<u8>,
+    layout: PhantomData<L>,
+}
+
+impl<L: MemoryLayout> BlockWriter<L> {
+    /// Creates a new, empty writer.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new, empty writer with space for at least `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let bytes = Vec::with_capacity(capacity);
+        Self { bytes, layout: PhantomData }
+    }
+
+    /// Returns the current offset of the writer; that is, the number of bytes written so far.
+    pub fn offset(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the bytes written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes the writer, returning the bytes that were written.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Pads the writer with zeroes until its offset is `offset`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than `offset` bytes have already been written.
+    pub fn pad_to(&mut self, offset: usize) {
+        assert!(offset >= self.offset(), "block writer is already past offset {offset}");
+        self.bytes.resize(offset, 0);
+    }
+
+    /// Pads the writer with zeroes until its offset is a multiple of `alignment`.
+    pub fn align_to(&mut self, alignment: usize) {
+        self.pad_to(self.offset().next_multiple_of(alignment.max(1)));
+    }
+
+    /// Appends raw bytes to the writer, without any alignment. This is mostly useful for implementing [`BlockData`] for
+    /// primitive types.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Aligns the writer for a value and writes it.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value's [`BlockData::write`] implementation does not write exactly as many bytes as
+    /// its [`BlockData::size`] says it should.
+    pub fn write<T: BlockData>(&mut self, value: &T) {
+        self.align_to(T::alignment::<L>());
+
+        let start = self.offset();
+        value.write(self);
+
+        let (written, expected) = (self.offset() - start, T::size::<L>());
+        assert_eq!(written, expected, "`BlockData` implementation wrote the wrong number of bytes");
+    }
+
+    /// Aligns the writer for an array and writes each of the given values as an element of it, padding each one out to
+    /// the array's stride.
+    ///
+    /// This can also be used to write the contents of a runtime-sized array at the end of a shader storage block.
+    pub fn write_array<T: BlockData>(&mut self, values: &[T]) {
+        let (alignment, stride) = array_element_layout::<L, T>();
+        self.align_to(alignment);
+
+        for value in values {
+            let start = self.offset();
+            self.write(value);
+            self.pad_to(start + stride);
+        }
+    }
+}
+
+impl<L: MemoryLayout> Default for BlockWriter<L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// Returns the alignment of an array of `T`s and the stride between its elements.
+fn array_element_layout<L: MemoryLayout, T: BlockData>() -> (usize, usize) {
+    let alignment = L::array_alignment(T::alignment::<L>());
+    (alignment, T::size::<L>().next_multiple_of(alignment))
+}
+
+
+// ---------------------------------------------------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_block_data {
+    (scalar, $rs_type:ty) => {
+        impl BlockData for $rs_type {
+            fn alignment<L: MemoryLayout>() -> usize {
+                4
+            }
+
+            fn size<L: MemoryLayout>() -> usize {
+                4
+            }
+
+            fn write<L: MemoryLayout>(&self, writer: &mut BlockWriter<L>) {
+                writer.write_bytes(bytemuck::bytes_of(self));
+            }
+        }
+    };
+
+    // The base alignment of a three-component vector is the same as that of a four-component one.
+    (vector, $rs_type:ty, $align:literal) => {
+        impl BlockData for $rs_type {
+            fn alignment<L: MemoryLayout>() -> usize {
+                $align
+            }
+
+            fn size<L: MemoryLayout>() -> usize {
+                std::mem::size_of::<$rs_type>()
+            }
+
+            fn write<L: MemoryLayout>(&self, writer: &mut BlockWriter<L>) {
+                writer.write_bytes(self.as_bytes());
+            }
+        }
+    };
+
+    // Column-major matrices are laid out identically to an array of their column vectors.
+    (matrix, $rs_type:ty, $col_type:ty, $cols:literal) => {
+        impl BlockData for $rs_type {
+            fn alignment<L: MemoryLayout>() -> usize {
+                <[$col_type; $cols]>::alignment::<L>()
+            }
+
+            fn size<L: MemoryLayout>() -> usize {
+                <[$col_type; $cols]>::size::<L>()
+            }
+
+            fn write<L: MemoryLayout>(&self, writer: &mut BlockWriter<L>) {
+                writer.write_array(self.as_columns());
+            }
+        }
+    };
+}
+
+
+impl_block_data!(scalar, f32);
+impl_block_data!(scalar, i32);
+impl_block_data!(scalar, u32);
+
+impl_block_data!(vector, Vec2, 8);
+impl_block_data!(vector, Vec3, 16);
+impl_block_data!(vector, Vec4, 16);
+
+impl_block_data!(matrix, Mat2, Vec2, 2);
+impl_block_data!(matrix, Mat3, Vec3, 3);
+impl_block_data!(matrix, Mat4, Vec4, 4);
+
+
+/// GLSL booleans are stored as 32-bit integers.
+impl BlockData for bool {
+    fn alignment<L: MemoryLayout>() -> usize {
+        4
+    }
+
+    fn size<L: MemoryLayout>() -> usize {
+        4
+    }
+
+    fn write<L: MemoryLayout>(&self, writer: &mut BlockWriter<L>) {
+        writer.write_bytes(&u32::from(*self).to_ne_bytes());
+    }
+}
+
+
+impl<T: BlockData, const N: usize> BlockData for [T; N] {
+    fn alignment<L: MemoryLayout>() -> usize {
+        array_element_layout::<L, T>().0
+    }
+
+    fn size<L: MemoryLayout>() -> usize {
+        array_element_layout::<L, T>().1 * N
+    }
+
+    fn write<L: MemoryLayout>(&self, writer: &mut BlockWriter<L>) {
+        writer.write_array(self);
+    }
+}
+
+
+/// Declares a struct that implements [`BlockData`][crate::layout::BlockData], so that it can be written into a uniform
+/// or shader storage block with any [`MemoryLayout`][crate::layout::MemoryLayout].
+///
+/// Every field's type must also implement `BlockData`. Fields are laid out in declaration order, with padding inserted
+/// between them according to the layout's rules; so the struct should be declared with the same fields in the same
+/// order as the GLSL struct that it mirrors. The struct itself is declared exactly as written, and does not need to be
+/// `#[repr(C)]`.
+///
+/// # Syntax
+///
+/// ```ignore
+/// gloog_core::block_struct! {
+///     #[derive(Debug, Clone, Copy)]
+///     pub struct Material {
+///         pub diffuse: Vec4,
+///         pub specular: Vec4,
+///         pub shininess: f32,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! block_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident: $field_type:ty
+            ),*
+            $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $field_type,
+            )*
+        }
+
+        impl $crate::layout::BlockData for $name {
+            fn alignment<L: $crate::layout::MemoryLayout>() -> usize {
+                let alignment = 1_usize;
+                $( let alignment = alignment.max(<$field_type as $crate::layout::BlockData>::alignment::<L>()); )*
+                L::struct_alignment(alignment)
+            }
+
+            fn size<L: $crate::layout::MemoryLayout>() -> usize {
+                let offset = 0_usize;
+                $(
+                    let offset = offset.next_multiple_of(<$field_type as $crate::layout::BlockData>::alignment::<L>())
+                        + <$field_type as $crate::layout::BlockData>::size::<L>();
+                )*
+                offset.next_multiple_of(<Self as $crate::layout::BlockData>::alignment::<L>())
+            }
+
+            fn write<L: $crate::layout::MemoryLayout>(&self, writer: &mut $crate::layout::BlockWriter<L>) {
+                let start = writer.offset();
+                $( writer.write(&self.$field); )*
+                writer.pad_to(start + <Self as $crate::layout::BlockData>::size::<L>());
+            }
+        }
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::block_struct! {
+        struct Packed {
+            position: Vec3,
+            intensity: f32,
+        }
+    }
+
+
+    #[test]
+    fn matrix_columns_are_padded() {
+        assert_eq!(Std140::size_of::<Mat3>(), 48);
+        assert_eq!(Std430::size_of::<Mat3>(), 48);
+        assert_eq!(Std140::size_of::<Mat2>(), 32);
+        assert_eq!(Std430::size_of::<Mat2>(), 16);
+    }
+
+
+    #[test]
+    fn scalar_packs_after_vec3() {
+        assert_eq!(Std140::size_of::<Packed>(), 16);
+        assert_eq!(Std430::size_of::<Packed>(), 16);
+
+        let bytes = Std140::to_bytes(&Packed {
+            position: Vec3::new(1.0, 2.0, 3.0),
+            intensity: 4.0,
+        });
+        assert_eq!(bytes, bytemuck::cast_slice::<f32, u8>(&[1.0, 2.0, 3.0, 4.0]));
+    }
+
+
+    #[test]
+    fn array_strides() {
+        assert_eq!(Std140::size_of::<[f32; 3]>(), 48);
+        assert_eq!(Std430::size_of::<[f32; 3]>(), 12);
+
+        let mut writer = BlockWriter::<Std140>::new();
+        writer.write_array(&[1.0f32, 2.0]);
+        assert_eq!(writer.offset(), 32);
+        assert_eq!(&writer.as_bytes()[16..20], 2.0f32.to_ne_bytes());
+
+        let mut writer = BlockWriter::<Std430>::new();
+        writer.write_array(&[1.0f32, 2.0]);
+        assert_eq!(writer.as_bytes(), bytemuck::cast_slice::<f32, u8>(&[1.0, 2.0]));
+    }
+}
@@ -1,13 +1,32 @@
+
 mod funcs;
+pub mod layout;
 mod macros;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod objects;
+pub mod params;
 pub mod raw;
+#[cfg(feature = "trace")] pub mod trace;
 pub mod types;
+pub mod vertex;
+
+use std::cell::Cell;
+#[cfg(feature = "gl45")]
+use std::cell::RefCell;
 
 pub(crate) use crate::macros::*;
 use crate::raw::GLPointers;
+#[cfg(feature = "error-checking")]
+pub use crate::raw::ErrorCheckMode;
 pub use crate::raw::InitFailureMode;
+pub use crate::funcs::textures::TexturePixel;
+#[cfg(feature = "gl45")]
+use crate::funcs::textures::UnpackState;
 pub use crate::funcs::uniforms::Uniform;
-use crate::types::DebugMessage;
+#[cfg(feature = "gl45")]
+use crate::types::{BufferID, DebugMessage};
+use crate::types::PipelineState;
 
 
 /// A wrapper for an underlying collection of OpenGL functions.
@@ -20,9 +39,26 @@ pub struct GLContext {
 
     /// The current OpenGL debug callback. Closures stored here need to be [`Sync`] because OpenGL may execute them from
     /// another thread when doing logging. Methods in this crate are **guaranteed** not to call this function.
+    #[cfg(feature = "gl45")]
     debug_callback: Option<Box<dyn FnMut(DebugMessage) + Sync + 'static>>,
+
+    /// The [`PipelineState`] that was most recently applied, or `None` if it is unknown. Used to skip redundant state
+    /// changes in [`apply_pipeline_state`][Self::apply_pipeline_state].
+    pipeline_state: Cell<Option<PipelineState>>,
+
+    /// The buffers that are currently mapped by a live [`BufferMapping`][crate::objects::BufferMapping]. Used to stop
+    /// buffers from being deleted, re-allocated, or unmapped while a mapping still points into them.
+    #[cfg(feature = "gl45")]
+    mapped_buffers: RefCell<Vec<BufferID>>,
+
+    /// The unpack pixel storage parameters and pixel unpack buffer binding, or `None` if they haven't been needed yet.
+    /// Kept up to date by [`pixel_store`][Self::pixel_store] and [`bind_buffer`][Self::bind_buffer], so that uploads
+    /// don't have to query them every time.
+    #[cfg(feature = "gl45")]
+    unpack_state: Cell<Option<UnpackState>>,
 }
 
+#[cfg(feature = "gl45")]
 impl Drop for GLContext {
     fn drop(&mut self) {
         // Ensure that OpenGL doesn't try to call our debugging callback when this struct goes away.
@@ -52,10 +88,29 @@ impl GLContext {
     ) -> Result<Self, &'static str> {
         let raw_ptrs = unsafe { GLPointers::load(loader_fn, failure_mode) };
         match raw_ptrs {
-            Ok(gl) => Ok(Self { gl, debug_callback: None }),
+            Ok(gl) => Ok(Self {
+                gl,
+                #[cfg(feature = "gl45")]
+                debug_callback: None,
+                pipeline_state: Cell::new(None),
+                #[cfg(feature = "gl45")]
+                mapped_buffers: RefCell::new(Vec::new()),
+                #[cfg(feature = "gl45")]
+                unpack_state: Cell::new(None),
+            }),
             Err(e) => Err(e),
         }
     }
+
+    /// Sets what happens when `glGetError` reports an error after an OpenGL call. Errors are logged by default.
+    ///
+    /// This is useful on drivers that don't support [debug output][crate::types::EnableCap::DebugOutput], where errors
+    /// would otherwise go unnoticed. Since every call is followed by a call to `glGetError`, which may force the driver
+    /// to synchronize, error checking is slow and is only available with the `error-checking` feature.
+    #[cfg(feature = "error-checking")]
+    pub fn set_error_check_mode(&self, mode: ErrorCheckMode) {
+        self.gl.set_error_check_mode(mode)
+    }
 }
 
 
@@ -86,6 +86,9 @@ macro_rules! gl_newtype {
 /// Simply declare a regular enum, but with additional "match arms" for the OpenGL names. Exclude the `GL_` prefix on
 /// the OpenGL names.
 ///
+/// Attributes on variants are also applied to their arms in the conversion functions, so variants whose values only
+/// exist in some OpenGL versions can be gated with `#[cfg(feature = "...")]`.
+///
 /// ```
 /// gl_enum! {
 ///     /// Acceptable values for [buffer][Buffer] binding targets.
@@ -146,19 +149,20 @@ macro_rules! gl_enum {
                 unsafe { *(self as *const Self as *const u32) }
             }
 
-            #[allow(unused)]
+            #[allow(unused, unused_doc_comments)]
             pub(crate) const fn from_raw(value: u32) -> Option<Self> {
                 match value{
-                    $( crate::raw::$gl_name => Some(Self::$field_name), )*
+                    $( $(#[$field_attrs])* crate::raw::$gl_name => Some(Self::$field_name), )*
                     _ => None,
                 }
             }
         }
 
         impl std::fmt::Debug for $enum_name {
+            #[allow(unused_doc_comments)]
             fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
                 f.write_str(match self {
-                    $ ($enum_name::$field_name => stringify!($field_name), )*
+                    $( $(#[$field_attrs])* $enum_name::$field_name => stringify!($field_name), )*
                 })
             }
         }
@@ -179,6 +183,9 @@ macro_rules! gl_enum {
 /// Declare a struct, but instead of any fields, declare public constants for the OpenGL flags. Exclude the `GL_`
 /// prefixes.
 ///
+/// Like with [`gl_enum`], constants can be gated with `#[cfg(feature = "...")]` when their values only exist in some
+/// OpenGL versions.
+///
 /// ```
 /// gl_bitfield! {
 ///     pub struct BufferMask {
@@ -215,8 +222,16 @@ macro_rules! gl_bitfield {
             )*
 
             /// Returns a set of all defined flags.
+            #[allow(unused_doc_comments)]
             pub const fn all() -> Self {
-                Self( $(Self::$const_name.0|)* 0 )
+                let flags = [ $( $(#[$const_attrs])* Self::$const_name.0, )* ];
+                let mut all = 0;
+                let mut i = 0;
+                while i < flags.len() {
+                    all |= flags[i];
+                    i += 1;
+                }
+                Self(all)
             }
 
             /// Returns an empty set of flags.
@@ -254,7 +269,8 @@ macro_rules! gl_bitfield {
             fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
                 // Take a list of all the possible flags as strings, check if self has each of them, then join together
                 // separated by `|`.
-                let fields = [ $( (Self::$const_name, stringify!($const_name)), )* ]
+                #[allow(unused_doc_comments)]
+                let fields = [ $( $(#[$const_attrs])* (Self::$const_name, stringify!($const_name)), )* ]
                     .into_iter()
                     .filter_map(|(flag, s)| if self.contains(flag) { Some(s) } else { None })
                     .collect::<Vec<&str>>()
@@ -0,0 +1,381 @@
+//! A fake OpenGL implementation, for testing code that uses a [`GLContext`] on machines without a GPU.
+//!
+//! Every OpenGL function is replaced with a stub that records the call and its arguments in a log, instead of doing
+//! anything. The stubs simulate just enough of OpenGL for the wrappers in this crate to work:
+//!
+//! - Functions that create objects (`glGen*`, `glCreate*`, `glFenceSync`) hand out new, unique names;
+//! - Queries (`glGet*` functions with a `pname`) write whatever result was set with [`set_query_result`], or nothing
+//!   if there isn't one; program resource queries write the first result set for each of the properties they ask for;
+//! - Functions that return a value return whatever was set with [`set_return_value`], or a sensible default
+//!   (framebuffers are always complete, syncs are always signaled, and everything else returns zero);
+//! - `glGetString` and `glGetStringi` return strings set with [`set_string`] and [`set_indexed_string`]; and
+//! - Mapping a buffer returns a zeroed block of memory that stays allocated until the mock is [reset][reset].
+//!
+//! Since OpenGL contexts belong to a single thread, so does the mock's state: every thread has its own log and
+//! results, which keeps tests that run in parallel from seeing each other's calls.
+//!
+//! ```ignore
+//! let gl = mock::context();
+//! mock::set_query_result(COMPILE_STATUS, &[0]);
+//!
+//! let shader = gl.create_shader(ShaderType::Vertex);
+//! assert!(gl.compile_shader(shader).is_err());
+//! assert_eq!(mock::call_names(), ["glCreateShader", "glCompileShader", "glGetShaderiv", "glGetShaderiv"]);
+//! ```
+
+#[cfg(test)] mod tests;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr, CString};
+use std::fmt;
+
+use crate::raw::types::*;
+#[cfg(feature = "gl45")]
+use crate::raw::COMPUTE_WORK_GROUP_SIZE;
+use crate::raw::{
+    ALIASED_LINE_WIDTH_RANGE,
+    ALREADY_SIGNALED,
+    BLEND_COLOR,
+    COLOR_CLEAR_VALUE,
+    COLOR_WRITEMASK,
+    COMPRESSED_TEXTURE_FORMATS,
+    DEPTH_RANGE,
+    FRAMEBUFFER_COMPLETE,
+    MAX_VIEWPORT_DIMS,
+    NUM_COMPRESSED_TEXTURE_FORMATS,
+    POINT_SIZE_RANGE,
+    SCISSOR_BOX,
+    SMOOTH_LINE_WIDTH_RANGE,
+    TEXTURE_BORDER_COLOR,
+    TEXTURE_SWIZZLE_RGBA,
+    TRUE,
+    VIEWPORT,
+};
+#[cfg(feature = "gl41")]
+use crate::raw::{
+    NUM_PROGRAM_BINARY_FORMATS,
+    NUM_SHADER_BINARY_FORMATS,
+    PROGRAM_BINARY_FORMATS,
+    SHADER_BINARY_FORMATS,
+    VIEWPORT_BOUNDS_RANGE,
+};
+use crate::{GLContext, InitFailureMode};
+
+
+/// A decoded argument to an OpenGL function.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arg {
+    /// A `GLenum`.
+    Enum(GLenum),
+    /// A `GLbitfield`.
+    Bitfield(GLbitfield),
+    /// A `GLboolean`.
+    Bool(bool),
+    /// Any signed integer type, including sizes and offsets.
+    Int(i64),
+    /// Any unsigned integer type, including object names.
+    UInt(u64),
+    /// A `GLfloat` or `GLdouble`.
+    Float(f64),
+    /// A NUL-terminated string, such as the name passed to `glGetUniformLocation`.
+    Str(String),
+    /// Any other pointer, including function pointers and syncs.
+    Ptr(usize),
+}
+
+impl Arg {
+    /// Reads a NUL-terminated string argument.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must either be null or point to a NUL-terminated string.
+    unsafe fn c_str(ptr: *const GLchar) -> Self {
+        if ptr.is_null() {
+            Arg::Ptr(0)
+        } else {
+            Arg::Str(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+        }
+    }
+}
+
+impl fmt::Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arg::Enum(value) => match stubs::enum_name(*value) {
+                Some(name) => write!(f, "{name}"),
+                None => write!(f, "{value:#06X}"),
+            },
+            Arg::Bitfield(bits) => write!(f, "{bits:#X}"),
+            Arg::Bool(true) => write!(f, "GL_TRUE"),
+            Arg::Bool(false) => write!(f, "GL_FALSE"),
+            Arg::Int(value) => write!(f, "{value}"),
+            Arg::UInt(value) => write!(f, "{value}"),
+            Arg::Float(value) => write!(f, "{value}"),
+            Arg::Str(str) => write!(f, "{str:?}"),
+            // Actual addresses change from run to run, so they aren't useful to print
+            Arg::Ptr(0) => write!(f, "NULL"),
+            Arg::Ptr(_) => write!(f, "<pointer>"),
+        }
+    }
+}
+
+
+/// A single recorded call to an OpenGL function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    /// The name of the function that was called, such as `glBindBuffer`.
+    pub name: &'static str,
+    /// The names and values of each of its arguments, in order.
+    pub args: Vec<(&'static str, Arg)>,
+}
+
+impl Call {
+    /// Finds the value of one of this call's arguments by name.
+    pub fn arg(&self, name: &str) -> Option<&Arg> {
+        self.args.iter().find(|(n, _)| *n == name).map(|(_, arg)| arg)
+    }
+}
+
+impl fmt::Display for Call {
+    /// Formats this call like `glBindBuffer(target: GL_ARRAY_BUFFER, buffer: 1)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (i, (name, arg)) in self.args.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            write!(f, "{sep}{name}: {arg}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+
+#[derive(Default)]
+struct MockState {
+    calls: Vec<Call>,
+    last_name: GLuint,
+    query_results: HashMap<GLenum, Vec<i64>>,
+    return_values: HashMap<&'static str, i64>,
+    strings: HashMap<(GLenum, Option<GLuint>), CString>,
+    mapped_buffers: Vec<Box<[u8]>>,
+}
+
+thread_local! {
+    static STATE: RefCell<MockState> = RefCell::new(MockState::default());
+}
+
+fn with_state<R>(f: impl FnOnce(&mut MockState) -> R) -> R {
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+
+/// Resets the mock and creates a new [`GLContext`] that uses it.
+pub fn context() -> GLContext {
+    reset();
+    GLContext::init(load, InitFailureMode::Abort).expect("every OpenGL function should have a stub")
+}
+
+/// Finds the stub for an OpenGL function. This can be passed to [`GLContext::init`] directly.
+pub fn load(name: &str) -> *const c_void {
+    stubs::lookup(name)
+}
+
+/// Clears this thread's call log and all configured results, and frees any mapped buffers.
+pub fn reset() {
+    with_state(|state| *state = MockState::default());
+}
+
+/// Returns every call recorded on this thread since the last [`reset`] or [`take_calls`].
+pub fn calls() -> Vec<Call> {
+    with_state(|state| state.calls.clone())
+}
+
+/// Returns every call recorded on this thread since the last [`reset`] or [`take_calls`], and clears the log.
+pub fn take_calls() -> Vec<Call> {
+    with_state(|state| std::mem::take(&mut state.calls))
+}
+
+/// Returns the names of every function called on this thread since the last [`reset`] or [`take_calls`].
+pub fn call_names() -> Vec<&'static str> {
+    with_state(|state| state.calls.iter().map(|call| call.name).collect())
+}
+
+/// Sets the values written by any query for `pname`, like `glGetShaderiv(shader, GL_COMPILE_STATUS, ...)` or
+/// `glGetIntegerv(GL_MAX_TEXTURE_SIZE, ...)`. Values are converted to whatever type the function writes.
+///
+/// Queries only write as many values as OpenGL would: one for most parameters, a fixed number for ones like
+/// `GL_VIEWPORT`, and as many as the call has room for in functions that take a buffer size or a count. Extra values
+/// are ignored, and missing ones are left unwritten.
+pub fn set_query_result(pname: GLenum, values: &[i64]) {
+    with_state(|state| state.query_results.insert(pname, values.to_vec()));
+}
+
+/// Sets the value returned by the function with the given name, such as `glCheckNamedFramebufferStatus`.
+pub fn set_return_value(function: &'static str, value: i64) {
+    with_state(|state| state.return_values.insert(function, value));
+}
+
+/// Sets the string returned by `glGetString(name)`.
+pub fn set_string(name: GLenum, value: &str) {
+    let value = CString::new(value).expect("mock strings should not contain NUL-bytes");
+    with_state(|state| state.strings.insert((name, None), value));
+}
+
+/// Sets the string returned by `glGetStringi(name, index)`.
+pub fn set_indexed_string(name: GLenum, index: GLuint, value: &str) {
+    let value = CString::new(value).expect("mock strings should not contain NUL-bytes");
+    with_state(|state| state.strings.insert((name, Some(index)), value));
+}
+
+
+// ---------------------------------------------------------------------------------------------------------------------
+// Helpers for the generated stubs
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// A type that the mock can return from a function.
+trait MockReturn {
+    fn from_mock(value: i64) -> Self;
+}
+
+/// A type that the mock can write as the result of a query.
+trait QueryValue {
+    fn from_mock(value: i64) -> Self;
+}
+
+macro_rules! mock_values {
+    ($trait:ident for $($ty:ty),*) => {
+        $(
+            impl $trait for $ty {
+                fn from_mock(value: i64) -> Self {
+                    value as $ty
+                }
+            }
+        )*
+    };
+}
+
+mock_values!(MockReturn for GLboolean, GLint, GLuint, GLuint64);
+mock_values!(QueryValue for GLboolean, GLint, GLint64, GLuint, GLuint64, GLfloat, GLdouble);
+
+impl MockReturn for GLsync {
+    fn from_mock(value: i64) -> Self {
+        value as usize as GLsync
+    }
+}
+
+
+fn record(name: &'static str, args: Vec<(&'static str, Arg)>) {
+    with_state(|state| state.calls.push(Call { name, args }));
+}
+
+fn next_name() -> GLuint {
+    with_state(|state| {
+        state.last_name += 1;
+        state.last_name
+    })
+}
+
+/// # Safety
+///
+/// `names` must have room for `n` names.
+unsafe fn fill_names(n: GLsizei, names: *mut GLuint) {
+    for i in 0..n.max(0) as usize {
+        unsafe { names.add(i).write(next_name()) };
+    }
+}
+
+/// Writes the values set for `pname` to a query's output. `len` is the number of values that the output has room for,
+/// if the function says (like `glGetInternalformativ`'s `bufSize`); otherwise, it's figured out from `pname`.
+///
+/// # Safety
+///
+/// `output` must have room for `len` values, or as many values as OpenGL would write for `pname` if `len` is `None`.
+unsafe fn write_query_result<T: QueryValue>(pname: GLenum, output: *mut T, len: Option<usize>) {
+    let values = with_state(|state| state.query_results.get(&pname).cloned()).unwrap_or_default();
+    let len = len.unwrap_or_else(|| query_len(pname));
+    for (i, &value) in values.iter().take(len).enumerate() {
+        unsafe { output.add(i).write(T::from_mock(value)) };
+    }
+}
+
+/// Writes the first value set for each of `props` to a query's output, like `glGetProgramResourceiv` does with the
+/// properties of a program resource. No more than `len` values are written.
+///
+/// # Safety
+///
+/// `props` must point to `count` enums, and `output` must have room for `len` values.
+#[cfg(feature = "gl45")]
+unsafe fn write_property_results<T: QueryValue>(props: *const GLenum, count: GLsizei, output: *mut T, len: GLsizei) {
+    for i in 0..count.min(len).max(0) as usize {
+        let prop = unsafe { props.add(i).read() };
+        unsafe { write_query_result(prop, output.add(i), Some(1)) };
+    }
+}
+
+/// The number of values that OpenGL writes for a query of `pname`.
+fn query_len(pname: GLenum) -> usize {
+    // Lists of formats are as long as the mock says they are
+    let count_of = |num_pname| {
+        let count = with_state(|state| state.query_results.get(&num_pname).and_then(|values| values.first().copied()));
+        count.unwrap_or(0).max(0) as usize
+    };
+
+    match pname {
+        DEPTH_RANGE | MAX_VIEWPORT_DIMS | ALIASED_LINE_WIDTH_RANGE | SMOOTH_LINE_WIDTH_RANGE | POINT_SIZE_RANGE => 2,
+        #[cfg(feature = "gl41")]
+        VIEWPORT_BOUNDS_RANGE => 2,
+        #[cfg(feature = "gl45")]
+        COMPUTE_WORK_GROUP_SIZE => 3,
+        VIEWPORT | SCISSOR_BOX | COLOR_CLEAR_VALUE | COLOR_WRITEMASK | BLEND_COLOR => 4,
+        TEXTURE_BORDER_COLOR | TEXTURE_SWIZZLE_RGBA => 4,
+        COMPRESSED_TEXTURE_FORMATS => count_of(NUM_COMPRESSED_TEXTURE_FORMATS),
+        #[cfg(feature = "gl41")]
+        PROGRAM_BINARY_FORMATS => count_of(NUM_PROGRAM_BINARY_FORMATS),
+        #[cfg(feature = "gl41")]
+        SHADER_BINARY_FORMATS => count_of(NUM_SHADER_BINARY_FORMATS),
+        _ => 1,
+    }
+}
+
+fn respond<R: MockReturn>(function: &'static str) -> R {
+    if let Some(value) = with_state(|state| state.return_values.get(function).copied()) {
+        return R::from_mock(value);
+    }
+
+    R::from_mock(match function {
+        "glCreateShader" | "glCreateProgram" | "glCreateShaderProgramv" | "glFenceSync" => next_name() as i64,
+        "glCheckFramebufferStatus" | "glCheckNamedFramebufferStatus" => FRAMEBUFFER_COMPLETE as i64,
+        "glClientWaitSync" => ALREADY_SIGNALED as i64,
+        "glUnmapBuffer" | "glUnmapNamedBuffer" => TRUE as i64,
+        _ => 0,
+    })
+}
+
+fn get_string(name: GLenum, index: Option<GLuint>) -> *const GLubyte {
+    with_state(|state| {
+        let str = state.strings.entry((name, index)).or_default();
+        str.as_ptr().cast()
+    })
+}
+
+fn map_buffer(length: usize) -> *mut c_void {
+    with_state(|state| {
+        let mut buffer = vec![0; length].into_boxed_slice();
+        let ptr = buffer.as_mut_ptr().cast();
+        state.mapped_buffers.push(buffer);
+        ptr
+    })
+}
+
+
+// Stubs are named after the OpenGL functions they replace, and convert every argument the same way regardless of its
+// type
+#[allow(non_snake_case, unused_variables, clippy::unnecessary_cast)]
+mod stubs {
+    use super::*;
+    #[cfg(feature = "gl45")]
+    use crate::raw::types;
+
+    // Include the stub functions from the build script
+    include!(concat!(env!("OUT_DIR"), "/mock.rs"));
+}
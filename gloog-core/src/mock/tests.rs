@@ -0,0 +1,147 @@
+use super::*;
+#[cfg(feature = "gl45")]
+use crate::raw::FRAMEBUFFER_UNSUPPORTED;
+use crate::raw::{COMPILE_STATUS, MAX_TEXTURE_SIZE, VERSION, VIEWPORT};
+use crate::types::*;
+
+/// Returns the names of the functions called since the last check, ignoring the `glGetError` calls that are made when
+/// the `error-checking` feature is enabled.
+fn take_call_names() -> Vec<&'static str> {
+    take_calls()
+        .into_iter()
+        .map(|call| call.name)
+        .filter(|&name| name != "glGetError")
+        .collect()
+}
+
+
+#[test]
+fn shader_compile_success() {
+    let gl = context();
+    set_query_result(COMPILE_STATUS, &[1]);
+
+    let shader = gl.create_shader(ShaderType::Vertex);
+    assert_eq!(shader.into_raw(), 1);
+    assert!(gl.compile_shader(shader).is_ok());
+    assert_eq!(take_call_names(), ["glCreateShader", "glCompileShader", "glGetShaderiv"]);
+}
+
+
+#[test]
+fn shader_compile_failure() {
+    let gl = context();
+    set_query_result(COMPILE_STATUS, &[0]);
+
+    let shader = gl.create_shader(ShaderType::Fragment);
+    let result = gl.compile_shader(shader);
+
+    // No info log length was set, so the mock reports that there is no log
+    assert_eq!(result, Err("[NO SHADER INFO LOG]".to_string()));
+    assert_eq!(take_call_names(), ["glCreateShader", "glCompileShader", "glGetShaderiv", "glGetShaderiv"]);
+}
+
+
+#[test]
+fn query_results() {
+    let gl = context();
+    assert_eq!(gl.get(crate::params::MaxTextureSize), 0);
+
+    set_query_result(MAX_TEXTURE_SIZE, &[16384]);
+    assert_eq!(gl.get(crate::params::MaxTextureSize), 16384);
+
+    let calls = take_calls();
+    let last = calls.iter().rfind(|call| call.name == "glGetIntegerv").unwrap();
+    assert_eq!(last.arg("pname"), Some(&Arg::Enum(MAX_TEXTURE_SIZE)));
+}
+
+
+#[test]
+fn query_results_are_bounded() {
+    let gl = context();
+
+    // Only as many values are written as the query has room for, no matter how many are set
+    set_query_result(MAX_TEXTURE_SIZE, &[4096; 8]);
+    assert_eq!(gl.get(crate::params::MaxTextureSize), 4096);
+
+    set_query_result(VIEWPORT, &[0, 0, 640, 480, 1, 2, 3, 4]);
+    assert_eq!(gl.get(crate::params::Viewport), [0, 0, 640, 480]);
+}
+
+
+#[test]
+fn strings() {
+    let gl = context();
+    assert_eq!(gl.get_string(StringName::Version), "");
+
+    set_string(VERSION, "4.6.0 Mock");
+    assert_eq!(gl.get_string(StringName::Version), "4.6.0 Mock");
+}
+
+
+#[test]
+fn call_display() {
+    let gl = context();
+    gl.bind_buffer(BufferTarget::ArrayBuffer, BufferID::new(3));
+
+    let calls = take_calls();
+    assert_eq!(calls[0].to_string(), "glBindBuffer(target: GL_ARRAY_BUFFER, buffer: 3)");
+}
+
+
+#[test]
+fn threads_are_isolated() {
+    let gl = context();
+    gl.bind_buffer(BufferTarget::ArrayBuffer, BufferID::new(1));
+
+    std::thread::spawn(|| {
+        let gl = context();
+        gl.bind_buffer(BufferTarget::ElementArrayBuffer, BufferID::new(2));
+        assert_eq!(take_call_names(), ["glBindBuffer"]);
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(calls()[0].arg("buffer"), Some(&Arg::UInt(1)));
+}
+
+
+#[cfg(feature = "gl45")]
+#[test]
+fn create_buffers() {
+    let gl = context();
+    let buffers = gl.create_buffers(3);
+    assert_eq!(buffers, [BufferID::new(1), BufferID::new(2), BufferID::new(3)]);
+
+    let calls = take_calls();
+    assert_eq!(calls[0].name, "glCreateBuffers");
+    assert_eq!(calls[0].arg("n"), Some(&Arg::Int(3)));
+}
+
+
+#[cfg(feature = "gl45")]
+#[test]
+fn objects_are_deleted_on_drop() {
+    let gl = context();
+    let buffer = crate::objects::Buffer::new(&gl);
+    let id = buffer.id();
+    drop(buffer);
+
+    let calls = take_calls();
+    let delete = calls.iter().find(|call| call.name == "glDeleteBuffers").unwrap();
+    assert_eq!(delete.arg("n"), Some(&Arg::Int(1)));
+    assert_eq!(id.into_raw(), 1);
+}
+
+
+#[cfg(feature = "gl45")]
+#[test]
+fn framebuffer_status() {
+    let gl = context();
+    let framebuffer = FramebufferID::new(1);
+    let target = FramebufferTarget::DrawFramebuffer;
+    assert_eq!(gl.check_named_framebuffer_status(framebuffer, target), Ok(()));
+
+    set_return_value("glCheckNamedFramebufferStatus", FRAMEBUFFER_UNSUPPORTED as i64);
+    let status = gl.check_named_framebuffer_status(framebuffer, target);
+    assert_eq!(status, Err(FramebufferError::Unsupported));
+}
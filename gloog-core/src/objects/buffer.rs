@@ -0,0 +1,202 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use bytemuck::Pod;
+
+use super::gl_object;
+use crate::types::*;
+use crate::GLContext;
+
+
+gl_object! {
+    /// An owned OpenGL buffer object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Buffer(BufferID), delete_buffer;
+}
+
+
+impl<'gl> Buffer<'gl> {
+    /// Creates a new, empty buffer object.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        Self { gl, id: gl.create_buffer() }
+    }
+
+    /// Creates a new buffer object and initializes its data store with the given data.
+    pub fn with_data(gl: &'gl GLContext, data: &[u8], usage: BufferUsage) -> Self {
+        let mut buffer = Self::new(gl);
+        buffer.set_data(data, usage);
+        buffer
+    }
+
+    /// Creates a new buffer object with an immutable data store, initialized with the given data. See
+    /// [`GLContext::named_buffer_storage`].
+    pub fn with_storage(gl: &'gl GLContext, data: &[u8], flags: BufferStorageFlags) -> Self {
+        let buffer = Self::new(gl);
+        gl.named_buffer_storage(buffer.id, data, flags);
+        buffer
+    }
+
+    /// Creates a new buffer object with an immutable data store of `size` bytes, without initializing its contents.
+    pub fn with_storage_empty(gl: &'gl GLContext, size: usize, flags: BufferStorageFlags) -> Self {
+        let buffer = Self::new(gl);
+        gl.named_buffer_storage_empty(buffer.id, size, flags);
+        buffer
+    }
+
+    /// Re-creates this buffer's data store with the given data, discarding its old contents.
+    pub fn set_data(&mut self, data: &[u8], usage: BufferUsage) {
+        self.gl.named_buffer_data(self.id, data, usage)
+    }
+
+    /// Replaces part of this buffer's data store, starting at `offset` bytes, with the given data.
+    pub fn set_sub_data(&mut self, offset: usize, data: &[u8]) {
+        self.gl.named_buffer_sub_data(self.id, offset, data)
+    }
+
+    /// Reads part of this buffer's data store, starting at `offset` bytes, to fill `data`.
+    pub fn get_sub_data(&self, offset: usize, data: &mut [u8]) {
+        self.gl.get_named_buffer_sub_data(self.id, offset, data)
+    }
+
+    /// Copies `size` bytes from another buffer into this one. See [`GLContext::copy_named_buffer_sub_data`].
+    pub fn copy_from(&mut self, src: &Buffer, src_offset: usize, dst_offset: usize, size: usize) {
+        let (gl, id) = (self.gl, self.id);
+        gl.copy_named_buffer_sub_data(src.id, id, src_offset, dst_offset, size)
+    }
+
+    /// Fills this buffer's entire data store with copies of a single value. See [`GLContext::clear_named_buffer_data`].
+    pub fn clear_data(&mut self, internal_format: InternalFormat, format: PixelFormat, ty: PixelType, value: &[u8]) {
+        self.gl.clear_named_buffer_data(self.id, internal_format, format, ty, value)
+    }
+
+    /// Maps `length` bytes of this buffer's data store, starting at `offset` bytes, into client memory as a slice of
+    /// `T`. The buffer is unmapped when the returned guard is dropped.
+    ///
+    /// # Panics
+    ///
+    /// See [`GLContext::map_named_buffer_range`].
+    pub fn map_range<T: Pod>(&mut self, offset: usize, length: usize, access: MapAccessFlags) -> BufferMapping<'_, T> {
+        // SAFETY: the mapping mutably borrows `self`, so the buffer can't be deleted, re-allocated, or mapped again
+        // until the mapping is dropped.
+        unsafe { self.gl.map_named_buffer_range(self.id, offset, length, access) }
+    }
+
+    /// Binds this buffer to the given target.
+    pub fn bind(&self, target: BufferTarget) {
+        self.gl.bind_buffer(target, self.id)
+    }
+
+    /// Binds this entire buffer to one of the indexed binding points of the given target.
+    pub fn bind_base(&self, target: IndexedBufferTarget, index: u32) {
+        self.gl.bind_buffer_base(target, index, self.id)
+    }
+
+    /// Binds `size` bytes of this buffer, starting at `offset` bytes, to one of the indexed binding points of the given
+    /// target. See [`GLContext::bind_buffer_range`].
+    pub fn bind_range(&self, target: IndexedBufferTarget, index: u32, offset: usize, size: usize) {
+        self.gl.bind_buffer_range(target, index, self.id, offset, size)
+    }
+}
+
+
+/// A range of a buffer object's data store that has been mapped into client memory.
+///
+/// Mappings dereference to a slice of `T` and unmap the buffer when dropped. They are created with
+/// [`Buffer::map_range`] or [`GLContext::map_named_buffer_range`].
+///
+/// Reading from a mapping that was not created with [`MapAccessFlags::READ`] (or writing to one that was not created
+/// with [`MapAccessFlags::WRITE`]) is allowed by the types, but gives undefined results according to OpenGL.
+pub struct BufferMapping<'a, T: Pod> {
+    gl: &'a GLContext,
+    buffer: BufferID,
+    ptr: *mut T,
+    len: usize,
+    phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T: Pod> BufferMapping<'a, T> {
+    /// Wraps a pointer returned from `glMapNamedBufferRange`. `len` is the number of `T`s in the mapping.
+    pub(crate) fn new(gl: &'a GLContext, buffer: BufferID, ptr: *mut T, len: usize) -> Self {
+        let phantom = PhantomData;
+        Self { gl, buffer, ptr, len, phantom }
+    }
+
+    /// Returns the ID of the buffer that this mapping belongs to.
+    pub fn buffer(&self) -> BufferID {
+        self.buffer
+    }
+
+    /// Indicates that `count` elements, starting at index `start`, have been modified. This is only needed for
+    /// mappings created with [`MapAccessFlags::FLUSH_EXPLICIT`].
+    pub fn flush(&self, start: usize, count: usize) {
+        assert!(start + count <= self.len, "flushed range should be within the mapping");
+        let size = std::mem::size_of::<T>();
+        self.gl.flush_mapped_named_buffer_range(self.buffer, start * size, count * size)
+    }
+}
+
+impl<'a, T: Pod> Deref for BufferMapping<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the pointer is non-null, aligned, and valid for `len` elements until the buffer is unmapped on drop;
+        // any bit pattern is a valid `T` since it is `Pod`.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<'a, T: Pod> DerefMut for BufferMapping<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: see `deref`; `&mut self` guarantees unique access.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<'a, T: Pod> Drop for BufferMapping<'a, T> {
+    fn drop(&mut self) {
+        if !self.gl.release_buffer_mapping(self.buffer) {
+            log::warn!("contents of buffer {:?} were corrupted while it was mapped", self.buffer);
+        }
+    }
+}
+
+impl<'a, T: Pod + std::fmt::Debug> std::fmt::Debug for BufferMapping<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("BufferMapping")
+            .field("buffer", &self.buffer)
+            .field("data", &self.deref())
+            .finish()
+    }
+}
+
+
+#[cfg(all(test, feature = "mock", feature = "gl45"))]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    #[test]
+    #[should_panic = "while it is mapped"]
+    fn mapped_buffers_cannot_be_reallocated() {
+        let gl = mock::context();
+        let mut buffer = Buffer::with_data(&gl, &[0; 16], BufferUsage::StreamDraw);
+        let id = buffer.id();
+        let _mapping = buffer.map_range::<u8>(0, 16, MapAccessFlags::WRITE);
+        gl.named_buffer_data(id, &[0; 32], BufferUsage::StreamDraw);
+    }
+
+    #[test]
+    fn mappings_are_released_on_drop() {
+        let gl = mock::context();
+        let mut buffer = Buffer::with_data(&gl, &[0; 16], BufferUsage::StreamDraw);
+        mock::take_calls();
+
+        drop(buffer.map_range::<u8>(0, 16, MapAccessFlags::WRITE));
+        buffer.set_data(&[0; 32], BufferUsage::StreamDraw);
+
+        let calls = mock::take_calls().into_iter().filter(|call| call.name != "glGetError");
+        let names = calls.map(|call| call.name).collect::<Vec<_>>();
+        assert_eq!(names, ["glMapNamedBufferRange", "glUnmapNamedBuffer", "glNamedBufferData"]);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::GLContext;
+
+
+/// A scope guard for a named debug group, which is popped when the guard is dropped.
+///
+/// Debug groups label sections of the command stream, so that debug messages and captures from graphics debuggers show
+/// which part of a frame each command came from. Groups may be nested. See [`GLContext::push_debug_group`].
+///
+/// ```ignore
+/// let frame = gl.debug_group("frame");
+/// {
+///     let _pass = DebugGroup::new(&gl, "opaque pass");
+///     // ...
+/// }
+/// drop(frame);
+/// ```
+#[must_use = "the debug group is popped as soon as it is dropped"]
+pub struct DebugGroup<'gl> {
+    gl: &'gl GLContext,
+}
+
+impl<'gl> DebugGroup<'gl> {
+    /// Pushes a new debug group with the given message.
+    pub fn new(gl: &'gl GLContext, message: &str) -> Self {
+        gl.push_debug_group(0, message);
+        Self { gl }
+    }
+}
+
+impl<'gl> Drop for DebugGroup<'gl> {
+    fn drop(&mut self) {
+        self.gl.pop_debug_group();
+    }
+}
+
+impl<'gl> std::fmt::Debug for DebugGroup<'gl> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DebugGroup").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,115 @@
+use super::{gl_object, Renderbuffer, Texture};
+use crate::types::*;
+use crate::GLContext;
+
+
+gl_object! {
+    /// An owned OpenGL framebuffer object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Framebuffer(FramebufferID), delete_framebuffer;
+}
+
+
+impl<'gl> Framebuffer<'gl> {
+    /// Creates a new framebuffer object with no attachments.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        let id = gl.create_framebuffer();
+        Self { gl, id }
+    }
+
+    /// Binds this framebuffer to the given target.
+    pub fn bind(&self, target: FramebufferTarget) {
+        self.gl.bind_framebuffer(target, self.id)
+    }
+
+    /// Attaches a level of a texture to this framebuffer. See [`GLContext::named_framebuffer_texture`].
+    pub fn attach_texture(&mut self, attachment: FramebufferAttachment, texture: &Texture, level: usize) {
+        self.gl.named_framebuffer_texture(self.id, attachment, texture.id(), level)
+    }
+
+    /// Attaches a single layer of a texture to this framebuffer. See [`GLContext::named_framebuffer_texture_layer`].
+    pub fn attach_texture_layer(
+        &mut self,
+        attachment: FramebufferAttachment,
+        texture: &Texture,
+        level: usize,
+        layer: usize,
+    ) {
+        let (gl, id) = (self.gl, self.id);
+        gl.named_framebuffer_texture_layer(id, attachment, texture.id(), level, layer)
+    }
+
+    /// Attaches a renderbuffer to this framebuffer.
+    pub fn attach_renderbuffer(&mut self, attachment: FramebufferAttachment, renderbuffer: &Renderbuffer) {
+        self.gl.named_framebuffer_renderbuffer(self.id, attachment, renderbuffer.id())
+    }
+
+    /// Removes whatever image is attached to the given attachment point.
+    pub fn detach(&mut self, attachment: FramebufferAttachment) {
+        self.gl.named_framebuffer_detach(self.id, attachment)
+    }
+
+    /// Selects the color buffer that fragment shader output zero is written to.
+    pub fn set_draw_buffer(&mut self, buffer: ColorBuffer) {
+        self.gl.named_framebuffer_draw_buffer(self.id, buffer)
+    }
+
+    /// Selects the color buffers that each fragment shader output is written to, in order.
+    pub fn set_draw_buffers(&mut self, buffers: &[ColorBuffer]) {
+        self.gl.named_framebuffer_draw_buffers(self.id, buffers)
+    }
+
+    /// Selects the color buffer that pixel reads and blits read from.
+    pub fn set_read_buffer(&mut self, buffer: ColorBuffer) {
+        self.gl.named_framebuffer_read_buffer(self.id, buffer)
+    }
+
+    /// Checks whether this framebuffer is complete, and can be used with the given target.
+    pub fn check_status(&self, target: FramebufferTarget) -> Result<(), FramebufferError> {
+        self.gl.check_named_framebuffer_status(self.id, target)
+    }
+
+    /// Copies a rectangle of pixels from this framebuffer into another one, such as [the default
+    /// framebuffer][FramebufferID::DEFAULT]. See [`GLContext::blit_named_framebuffer`].
+    pub fn blit_to(
+        &self,
+        dst_framebuffer: FramebufferID,
+        src: [i32; 4],
+        dst: [i32; 4],
+        mask: ClearMask,
+        filter: BlitFilter,
+    ) {
+        self.gl.blit_named_framebuffer(self.id, dst_framebuffer, src, dst, mask, filter)
+    }
+
+    /// Clears one of this framebuffer's color buffers. See [`GLContext::clear_named_framebuffer_color`].
+    pub fn clear_color(&mut self, draw_buffer: usize, color: [f32; 4]) {
+        self.gl.clear_named_framebuffer_color(self.id, draw_buffer, color)
+    }
+
+    /// Clears one of this framebuffer's signed integer color buffers.
+    pub fn clear_color_i(&mut self, draw_buffer: usize, color: [i32; 4]) {
+        self.gl.clear_named_framebuffer_color_i(self.id, draw_buffer, color)
+    }
+
+    /// Clears one of this framebuffer's unsigned integer color buffers.
+    pub fn clear_color_ui(&mut self, draw_buffer: usize, color: [u32; 4]) {
+        self.gl.clear_named_framebuffer_color_ui(self.id, draw_buffer, color)
+    }
+
+    /// Clears this framebuffer's depth buffer.
+    pub fn clear_depth(&mut self, depth: f32) {
+        self.gl.clear_named_framebuffer_depth(self.id, depth)
+    }
+
+    /// Clears this framebuffer's stencil buffer.
+    pub fn clear_stencil(&mut self, stencil: i32) {
+        self.gl.clear_named_framebuffer_stencil(self.id, stencil)
+    }
+
+    /// Clears this framebuffer's depth and stencil buffers at the same time.
+    pub fn clear_depth_stencil(&mut self, depth: f32, stencil: i32) {
+        self.gl.clear_named_framebuffer_depth_stencil(self.id, depth, stencil)
+    }
+}
@@ -0,0 +1,165 @@
+//! Owned wrappers around OpenGL objects.
+//!
+//! Each of the types in this module holds a reference to the [`GLContext`] that created it and deletes its underlying
+//! object when dropped, which makes it impossible to leak an object or to use it after it has been deleted. Methods on
+//! these types use the [direct state access][dsa] variants of OpenGL functions wherever possible, so that they do not
+//! need to bind anything to modify the object.
+//!
+//! Since direct state access is core in OpenGL 4.5, only [`Shader`] and [`Program`] are available without the `gl45`
+//! feature, and some of their methods still need it.
+//!
+//! The raw, ID-based functions on [`GLContext`] are still available for when these wrappers get in the way. Any object
+//! can be converted to and from its raw ID with `into_raw` and `from_raw`.
+//!
+//! [dsa]: https://www.khronos.org/opengl/wiki/Direct_State_Access
+
+#[cfg(feature = "gl45")]
+mod buffer;
+#[cfg(feature = "gl45")]
+mod debug_group;
+#[cfg(feature = "gl45")]
+mod framebuffer;
+#[cfg(feature = "gl45")]
+mod profiler;
+mod program;
+#[cfg(feature = "gl45")]
+mod query;
+#[cfg(feature = "gl45")]
+mod readback;
+#[cfg(feature = "gl45")]
+mod renderbuffer;
+#[cfg(feature = "gl45")]
+mod ring_buffer;
+#[cfg(feature = "gl45")]
+mod sampler;
+mod shader;
+#[cfg(feature = "gl45")]
+mod texture;
+#[cfg(feature = "gl45")]
+mod vertex_array;
+
+#[cfg(feature = "gl45")]
+pub use buffer::*;
+#[cfg(feature = "gl45")]
+pub use debug_group::*;
+#[cfg(feature = "gl45")]
+pub use framebuffer::*;
+#[cfg(feature = "gl45")]
+pub use profiler::*;
+pub use program::*;
+#[cfg(feature = "gl45")]
+pub use query::*;
+#[cfg(feature = "gl45")]
+pub use readback::*;
+#[cfg(feature = "gl45")]
+pub use renderbuffer::*;
+#[cfg(feature = "gl45")]
+pub use ring_buffer::*;
+#[cfg(feature = "gl45")]
+pub use sampler::*;
+pub use shader::*;
+#[cfg(feature = "gl45")]
+pub use texture::*;
+#[cfg(feature = "gl45")]
+pub use vertex_array::*;
+
+
+/// Declares a struct that owns an OpenGL object through one of the [ID newtypes][crate::types].
+///
+/// Structs are automatically declared with:
+///
+/// - A reference to the [`GLContext`][crate::GLContext] they belong to, with the lifetime `'gl`;
+/// - A [`Drop`] implementation that calls the given `GLContext` method to delete the object;
+/// - Methods for getting the raw ID and for converting to/from raw IDs;
+/// - Methods for getting and setting the object's debug label; and
+/// - An implementation of [`Debug`][std::fmt::Debug] that prints the raw ID.
+///
+/// # Syntax
+///
+/// ```ignore
+/// gl_object! {
+///     /// An OpenGL buffer object.
+///     pub struct Buffer(BufferID), delete_buffer;
+/// }
+/// ```
+macro_rules! gl_object {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($id_type:ty), $delete_fn:ident$(;)?
+    ) => {
+        $(#[$attr])*
+        $vis struct $name<'gl> {
+            gl: &'gl $crate::GLContext,
+            id: $id_type,
+        }
+
+        impl<'gl> $name<'gl> {
+            /// Returns the raw ID of this object, for use with the ID-based methods on [`GLContext`].
+            ///
+            /// This object still owns the returned ID. It should not be deleted manually.
+            ///
+            /// [`GLContext`]: crate::GLContext
+            #[inline]
+            pub const fn id(&self) -> $id_type {
+                self.id
+            }
+
+            /// Returns the context that this object belongs to.
+            #[inline]
+            pub const fn context(&self) -> &'gl $crate::GLContext {
+                self.gl
+            }
+
+            /// Gives this object a human-readable label for debug messages and graphics debuggers. See
+            /// [`GLContext::object_label`].
+            ///
+            /// [`GLContext::object_label`]: crate::GLContext::object_label
+            #[cfg(feature = "gl45")]
+            #[inline]
+            pub fn set_label(&self, label: &str) {
+                self.gl.object_label(self.id, label)
+            }
+
+            /// Returns this object's label, or an empty string if it doesn't have one.
+            #[cfg(feature = "gl45")]
+            #[inline]
+            pub fn label(&self) -> String {
+                self.gl.get_object_label(self.id)
+            }
+
+            /// Releases ownership of this object and returns its raw ID. The underlying object is **not** deleted, so
+            /// it is up to the caller to delete it when they are done with it.
+            #[inline]
+            pub fn into_raw(self) -> $id_type {
+                let id = self.id;
+                std::mem::forget(self);
+                id
+            }
+
+            /// Takes ownership of an object from its raw ID.
+            ///
+            /// # Safety
+            ///
+            /// The given ID must refer to a live object of the correct type that was created by the given context. No
+            /// other owned wrapper may own the same object, otherwise it will be deleted twice.
+            #[inline]
+            pub unsafe fn from_raw(gl: &'gl $crate::GLContext, id: $id_type) -> Self {
+                Self { gl, id }
+            }
+        }
+
+        impl<'gl> Drop for $name<'gl> {
+            fn drop(&mut self) {
+                self.gl.$delete_fn(self.id);
+            }
+        }
+
+        impl<'gl> std::fmt::Debug for $name<'gl> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.id).finish()
+            }
+        }
+    };
+}
+
+use gl_object;
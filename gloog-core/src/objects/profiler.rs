@@ -0,0 +1,229 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::types::*;
+use crate::GLContext;
+
+
+/// The GPU time taken by one named scope of a frame, as measured by a [`GpuProfiler`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassTiming {
+    pub name: String,
+    /// How many scopes this one is nested inside of. Top-level scopes have a depth of zero.
+    pub depth: usize,
+    pub duration: Duration,
+}
+
+
+/// A timestamp pair recorded for one scope.
+#[derive(Debug)]
+struct Scope {
+    name: String,
+    depth: usize,
+    start: QueryID,
+    end: Option<QueryID>,
+}
+
+impl Scope {
+    /// Returns the queries used by this scope.
+    fn queries(&self) -> impl Iterator<Item = QueryID> {
+        std::iter::once(self.start).chain(self.end)
+    }
+}
+
+
+/// The scopes of a frame whose results have not been collected yet.
+#[derive(Debug)]
+struct Frame {
+    scopes: Vec<Scope>,
+    /// The last timestamp query issued during the frame. Queries complete in order, so all of the frame's results are
+    /// available once this one's is.
+    last: QueryID,
+}
+
+
+/// A small GPU profiler which measures how long named scopes of each frame take to execute on the GPU.
+///
+/// Each scope records a [timestamp query][QueryTarget::Timestamp] when it begins and ends. Since query results are only
+/// available once the GPU catches up, timings are collected a few frames late without ever blocking: calling
+/// [`end_frame`][Self::end_frame] collects every earlier frame whose results are ready, and [`timings`][Self::timings]
+/// returns those of the most recent one. Scopes may be nested.
+///
+/// ```ignore
+/// let mut profiler = GpuProfiler::new(&gl);
+///
+/// loop {
+///     profiler.begin("shadows");
+///     // ...
+///     profiler.end();
+///
+///     profiler.begin("lighting");
+///     // ...
+///     profiler.end();
+///
+///     profiler.end_frame();
+///     for timing in profiler.timings() {
+///         println!("{}: {:?}", timing.name, timing.duration);
+///     }
+/// }
+/// ```
+pub struct GpuProfiler<'gl> {
+    gl: &'gl GLContext,
+    /// Timestamp queries which are not currently in use.
+    free: Vec<QueryID>,
+    /// The scopes recorded so far during the current frame.
+    scopes: Vec<Scope>,
+    /// The last timestamp query issued during the current frame.
+    last: Option<QueryID>,
+    /// Indices into `scopes` of the scopes which have begun but not yet ended.
+    open: Vec<usize>,
+    /// Earlier frames whose results were not yet available the last time they were checked, oldest first.
+    pending: VecDeque<Frame>,
+    /// The timings of the most recent frame whose results have been collected.
+    timings: Vec<PassTiming>,
+}
+
+impl<'gl> GpuProfiler<'gl> {
+    /// Creates a new profiler. Queries are created as they are needed and reused between frames.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        Self {
+            gl,
+            free: Vec::new(),
+            scopes: Vec::new(),
+            last: None,
+            open: Vec::new(),
+            pending: VecDeque::new(),
+            timings: Vec::new(),
+        }
+    }
+
+    /// Begins a new named scope, nested inside whichever scope is currently open.
+    pub fn begin(&mut self, name: impl Into<String>) {
+        let start = self.timestamp();
+        self.open.push(self.scopes.len());
+        self.scopes.push(Scope {
+            name: name.into(),
+            depth: self.open.len() - 1,
+            start,
+            end: None,
+        });
+    }
+
+    /// Ends the innermost open scope.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there is no open scope.
+    pub fn end(&mut self) {
+        let index = self.open.pop().expect("GpuProfiler::end should be paired with a call to begin");
+        self.scopes[index].end = Some(self.timestamp());
+    }
+
+    /// Finishes the current frame, and collects the timings of any earlier frames whose results are now available.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if any scope is still open.
+    pub fn end_frame(&mut self) {
+        assert!(self.open.is_empty(), "every GpuProfiler scope should be ended before the end of the frame");
+
+        if let Some(last) = self.last.take() {
+            let scopes = std::mem::take(&mut self.scopes);
+            self.pending.push_back(Frame { scopes, last });
+        }
+
+        while let Some(frame) = self.pending.front() {
+            if !self.gl.is_query_result_available(frame.last) {
+                break;
+            }
+
+            let frame = self.pending.pop_front().unwrap();
+            self.timings = frame.scopes.iter().map(|scope| self.collect(scope)).collect();
+            self.free.extend(frame.scopes.iter().flat_map(Scope::queries));
+        }
+    }
+
+    /// Returns the timings of each scope in the most recent frame whose results have been collected, in the order that
+    /// the scopes began.
+    pub fn timings(&self) -> &[PassTiming] {
+        &self.timings
+    }
+
+    /// Returns the total GPU time of every top-level scope in the most recent frame whose results have been collected.
+    pub fn total(&self) -> Duration {
+        let top_level = self.timings.iter().filter(|timing| timing.depth == 0);
+        top_level.map(|timing| timing.duration).sum()
+    }
+
+    /// Records a timestamp into a free query, creating a new one if there are none.
+    fn timestamp(&mut self) -> QueryID {
+        let query = self.free.pop().unwrap_or_else(|| self.gl.create_query(QueryTarget::Timestamp));
+        self.gl.query_counter(query);
+        self.last = Some(query);
+        query
+    }
+
+    fn collect(&self, scope: &Scope) -> PassTiming {
+        let start = self.gl.get_query_result(scope.start);
+        let end = scope.end.map_or(start, |end| self.gl.get_query_result(end));
+        PassTiming {
+            name: scope.name.clone(),
+            depth: scope.depth,
+            duration: Duration::from_nanos(end.saturating_sub(start)),
+        }
+    }
+}
+
+impl<'gl> Drop for GpuProfiler<'gl> {
+    fn drop(&mut self) {
+        let pending = self.pending.iter().flat_map(|frame| &frame.scopes);
+        let scopes = self.scopes.iter().chain(pending);
+        let mut queries = scopes.flat_map(Scope::queries).collect::<Vec<_>>();
+        queries.append(&mut self.free);
+        self.gl.delete_queries(&queries);
+    }
+}
+
+impl<'gl> std::fmt::Debug for GpuProfiler<'gl> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("GpuProfiler")
+            .field("scopes", &self.scopes)
+            .field("pending_frames", &self.pending.len())
+            .field("timings", &self.timings)
+            .finish()
+    }
+}
+
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::mock::{self, Arg};
+    use crate::raw::QUERY_RESULT_AVAILABLE;
+
+    #[test]
+    fn frames_wait_for_their_last_timestamp() {
+        let gl = mock::context();
+        let mut profiler = GpuProfiler::new(&gl);
+        profiler.begin("outer");
+        profiler.begin("inner");
+        profiler.end();
+        profiler.end();
+
+        // The outer scope ends after the inner one, so its end query is the one that has to be waited on.
+        let outer_end = profiler.scopes[0].end.unwrap().into_raw();
+        mock::set_query_result(QUERY_RESULT_AVAILABLE, &[0]);
+        mock::take_calls();
+        profiler.end_frame();
+
+        let polls = mock::take_calls().into_iter().filter(|call| call.name == "glGetQueryObjectiv");
+        let polled = polls.map(|call| call.arg("id").cloned()).collect::<Vec<_>>();
+        assert_eq!(polled, [Some(Arg::UInt(outer_end as u64))]);
+        assert!(profiler.timings().is_empty());
+
+        mock::set_query_result(QUERY_RESULT_AVAILABLE, &[1]);
+        profiler.end_frame();
+        let names = profiler.timings().iter().map(|timing| (timing.name.as_str(), timing.depth));
+        assert_eq!(names.collect::<Vec<_>>(), [("outer", 0), ("inner", 1)]);
+    }
+}
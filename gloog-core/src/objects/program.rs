@@ -0,0 +1,127 @@
+use super::{gl_object, Shader};
+use crate::types::*;
+use crate::GLContext;
+#[cfg(feature = "gl45")]
+use crate::Uniform;
+
+
+gl_object! {
+    /// An owned OpenGL program object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Program(ProgramID), delete_program;
+}
+
+
+impl<'gl> Program<'gl> {
+    /// Creates a new, empty program object.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        Self { gl, id: gl.create_program() }
+    }
+
+    /// Creates a new program object and links it from the given shaders.
+    ///
+    /// The shaders are detached again after linking, so they may be dropped as soon as this function returns. If
+    /// linking fails, the program is deleted and its info log is returned as an error.
+    pub fn from_shaders(gl: &'gl GLContext, shaders: &[&Shader]) -> Result<Self, String> {
+        let mut program = Self::new(gl);
+
+        for shader in shaders {
+            program.attach_shader(shader);
+        }
+
+        let result = program.link();
+
+        for shader in shaders {
+            program.detach_shader(shader);
+        }
+
+        result.map(|_| program)
+    }
+
+    /// Attaches a shader to this program, to be included the next time it is linked.
+    pub fn attach_shader(&mut self, shader: &Shader) {
+        self.gl.attach_shader(self.id, shader.id())
+    }
+
+    /// Detaches a shader from this program.
+    pub fn detach_shader(&mut self, shader: &Shader) {
+        self.gl.detach_shader(self.id, shader.id())
+    }
+
+    /// Links this program, returning its info log if linking fails.
+    pub fn link(&mut self) -> Result<(), String> {
+        self.gl.link_program(self.id)
+    }
+
+    /// Gets this program's info log, if it has one.
+    pub fn info_log(&self) -> Option<String> {
+        self.gl.get_program_info_log(self.id)
+    }
+
+    /// Installs this program as part of the current rendering state.
+    pub fn use_program(&self) {
+        self.gl.use_program(self.id)
+    }
+
+    /// Looks up the location of a uniform in this program. See [`GLContext::get_uniform_location`].
+    pub fn get_uniform_location(&self, name: &str) -> Option<UniformLocation> {
+        self.gl.get_uniform_location(self.id, name)
+    }
+
+    /// Looks up the location of a uniform in this program and checks its type. See
+    /// [`GLContext::get_typed_uniform_location`].
+    #[cfg(feature = "gl45")]
+    pub fn get_typed_uniform_location<T: Uniform>(
+        &self,
+        name: &str,
+    ) -> Result<TypedUniformLocation<T>, UniformLocationError> {
+        self.gl.get_typed_uniform_location(self.id, name)
+    }
+
+    /// Looks up the location of a vertex attribute in this program. See [`GLContext::get_attrib_location`].
+    pub fn get_attrib_location(&self, name: &str) -> Option<VertexAttribLocation> {
+        self.gl.get_attrib_location(self.id, name)
+    }
+
+    /// Lists this program's active uniforms. See [`GLContext::get_active_uniforms`].
+    #[cfg(feature = "gl45")]
+    pub fn active_uniforms(&self) -> Vec<ActiveUniform> {
+        self.gl.get_active_uniforms(self.id)
+    }
+
+    /// Lists this program's active vertex attributes.
+    #[cfg(feature = "gl45")]
+    pub fn active_attributes(&self) -> Vec<ActiveAttribute> {
+        self.gl.get_active_attributes(self.id)
+    }
+
+    /// Lists this program's active uniform blocks.
+    #[cfg(feature = "gl45")]
+    pub fn active_uniform_blocks(&self) -> Vec<ActiveBlock> {
+        self.gl.get_active_uniform_blocks(self.id)
+    }
+
+    /// Lists this program's active shader storage blocks.
+    #[cfg(feature = "gl45")]
+    pub fn active_shader_storage_blocks(&self) -> Vec<ActiveBlock> {
+        self.gl.get_active_shader_storage_blocks(self.id)
+    }
+
+    /// Lists the active variables in all of this program's shader storage blocks.
+    #[cfg(feature = "gl45")]
+    pub fn active_buffer_variables(&self) -> Vec<ActiveBufferVariable> {
+        self.gl.get_active_buffer_variables(self.id)
+    }
+
+    /// Changes the binding point that one of this program's uniform blocks reads from.
+    pub fn set_uniform_block_binding(&mut self, block_index: u32, binding: u32) {
+        self.gl.uniform_block_binding(self.id, block_index, binding)
+    }
+
+    /// Changes the binding point that one of this program's shader storage blocks reads from.
+    #[cfg(feature = "gl45")]
+    pub fn set_shader_storage_block_binding(&mut self, block_index: u32, binding: u32) {
+        self.gl.shader_storage_block_binding(self.id, block_index, binding)
+    }
+}
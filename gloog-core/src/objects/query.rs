@@ -0,0 +1,45 @@
+use super::gl_object;
+use crate::types::*;
+use crate::GLContext;
+
+
+gl_object! {
+    /// An owned OpenGL query object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Query(QueryID), delete_query;
+}
+
+
+impl<'gl> Query<'gl> {
+    /// Creates a new query object for the given target.
+    pub fn new(gl: &'gl GLContext, target: QueryTarget) -> Self {
+        let id = gl.create_query(target);
+        Self { gl, id }
+    }
+
+    /// Starts measuring with this query. See [`GLContext::begin_query`].
+    pub fn begin(&self, target: QueryTarget) {
+        self.gl.begin_query(target, self.id)
+    }
+
+    /// Stops measuring with whichever query is active for the given target. See [`GLContext::end_query`].
+    pub fn end(&self, target: QueryTarget) {
+        self.gl.end_query(target)
+    }
+
+    /// Records the GPU's time into this query. See [`GLContext::query_counter`].
+    pub fn record_timestamp(&self) {
+        self.gl.query_counter(self.id)
+    }
+
+    /// Gets this query's result, blocking until it is available.
+    pub fn result(&self) -> u64 {
+        self.gl.get_query_result(self.id)
+    }
+
+    /// Gets this query's result if it is available, without blocking.
+    pub fn try_result(&self) -> Option<u64> {
+        self.gl.try_get_query_result(self.id)
+    }
+}
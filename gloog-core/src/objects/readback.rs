@@ -0,0 +1,112 @@
+use super::Buffer;
+use crate::types::*;
+use crate::GLContext;
+
+
+/// Asynchronous read-back of framebuffer pixels through a pixel pack buffer.
+///
+/// [`GLContext::read_pixels`] has to wait for all rendering to finish before it can return, which stalls the CPU. A
+/// `PixelReadback` instead copies the pixels into a buffer object on the GPU and inserts a fence after the copy. The
+/// pixels can then be collected a frame or two later once the fence has been signaled, without stalling.
+///
+/// ```ignore
+/// let mut readback = PixelReadback::new(&gl);
+/// readback.start(0, 0, width, height, PixelFormat::RGBA, PixelType::UnsignedByte);
+///
+/// // ... some time later ...
+/// if let Some(pixels) = readback.poll() {
+///     // `pixels` holds `width × height` tightly packed RGBA pixels, starting from the bottom row.
+/// }
+/// ```
+pub struct PixelReadback<'gl> {
+    gl: &'gl GLContext,
+    buffer: Buffer<'gl>,
+    /// The current size of `buffer`'s data store.
+    capacity: usize,
+    /// The fence and size in bytes of the read-back that is currently in flight, if there is one.
+    pending: Option<(SyncID, usize)>,
+}
+
+impl<'gl> PixelReadback<'gl> {
+    /// Creates a new pixel read-back with an empty pixel pack buffer. The buffer grows as needed.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        Self {
+            gl,
+            buffer: Buffer::new(gl),
+            capacity: 0,
+            pending: None,
+        }
+    }
+
+    /// Starts reading a rectangle of pixels from the current read framebuffer. See [`GLContext::read_pixels`].
+    ///
+    /// If a previous read-back has not been collected yet, it is discarded.
+    pub fn start(&mut self, x: i32, y: i32, width: usize, height: usize, format: PixelFormat, ty: PixelType) {
+        self.cancel();
+
+        let size = format.pixel_size(ty) * width * height;
+        if size > self.capacity {
+            self.buffer.set_data(&vec![0; size], BufferUsage::StreamRead);
+            self.capacity = size;
+        }
+
+        let (gl, buffer) = (self.gl, self.buffer.id());
+        gl.read_pixels_into_buffer(buffer, 0, x, y, width, height, format, ty);
+        self.pending = Some((gl.fence_sync(), size));
+    }
+
+    /// Checks whether there is a read-back in flight.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Checks whether the read-back in flight has finished, without blocking.
+    pub fn is_ready(&self) -> bool {
+        match self.pending {
+            Some((fence, _)) => self.gl.client_wait_sync(fence, true, 0).is_signaled(),
+            None => false,
+        }
+    }
+
+    /// Collects the pixels from the read-back in flight if it has finished, without blocking.
+    ///
+    /// Returns `None` if the read-back has not finished yet, or if there is none in flight.
+    pub fn poll(&mut self) -> Option<Vec<u8>> {
+        if self.is_ready() {
+            self.collect()
+        } else {
+            None
+        }
+    }
+
+    /// Collects the pixels from the read-back in flight, blocking until it has finished.
+    ///
+    /// Returns `None` if there is no read-back in flight.
+    pub fn wait(&mut self) -> Option<Vec<u8>> {
+        let (fence, _) = self.pending?;
+        while self.gl.client_wait_sync(fence, true, u64::MAX) == SyncStatus::TimeoutExpired {}
+        self.collect()
+    }
+
+    /// Discards the read-back in flight, if there is one.
+    pub fn cancel(&mut self) {
+        if let Some((fence, _)) = self.pending.take() {
+            self.gl.delete_sync(fence);
+        }
+    }
+
+    fn collect(&mut self) -> Option<Vec<u8>> {
+        let (fence, size) = self.pending.take()?;
+        self.gl.delete_sync(fence);
+
+        let mut data = vec![0; size];
+        self.gl.get_named_buffer_sub_data(self.buffer.id(), 0, &mut data);
+        Some(data)
+    }
+}
+
+impl<'gl> Drop for PixelReadback<'gl> {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
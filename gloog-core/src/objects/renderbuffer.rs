@@ -0,0 +1,51 @@
+use super::gl_object;
+use crate::types::*;
+use crate::GLContext;
+
+
+gl_object! {
+    /// An owned OpenGL renderbuffer object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Renderbuffer(RenderbufferID), delete_renderbuffer;
+}
+
+
+impl<'gl> Renderbuffer<'gl> {
+    /// Creates a new renderbuffer object with no storage.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        let id = gl.create_renderbuffer();
+        Self { gl, id }
+    }
+
+    /// Creates a new renderbuffer object and allocates storage for it.
+    pub fn with_storage(gl: &'gl GLContext, format: InternalFormat, width: usize, height: usize) -> Self {
+        let mut renderbuffer = Self::new(gl);
+        renderbuffer.storage(format, width, height);
+        renderbuffer
+    }
+
+    /// Creates a new renderbuffer object and allocates multisampled storage for it.
+    pub fn with_storage_multisample(
+        gl: &'gl GLContext,
+        samples: usize,
+        format: InternalFormat,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        let mut renderbuffer = Self::new(gl);
+        renderbuffer.storage_multisample(samples, format, width, height);
+        renderbuffer
+    }
+
+    /// (Re-)allocates storage for this renderbuffer, discarding its old contents.
+    pub fn storage(&mut self, format: InternalFormat, width: usize, height: usize) {
+        self.gl.named_renderbuffer_storage(self.id, format, width, height)
+    }
+
+    /// (Re-)allocates multisampled storage for this renderbuffer, discarding its old contents.
+    pub fn storage_multisample(&mut self, samples: usize, format: InternalFormat, width: usize, height: usize) {
+        let gl = self.gl;
+        gl.named_renderbuffer_storage_multisample(self.id, samples, format, width, height)
+    }
+}
@@ -0,0 +1,235 @@
+use std::ops::{Deref, DerefMut};
+
+use bytemuck::Pod;
+
+use super::{Buffer, BufferMapping};
+use crate::types::*;
+use crate::GLContext;
+
+
+/// A persistently mapped buffer for streaming data to the GPU every frame.
+///
+/// The buffer is split into a fixed number of equally sized _regions_, one per frame in flight. Each frame, data is
+/// written into the current region through typed [sub-allocations][RingAllocation], which the GPU then reads from while
+/// the CPU moves on to the next region. When a frame is [finished][Self::finish_frame], a fence is inserted behind it,
+/// and that region is not handed out again until the fence has been signaled. With three regions, the CPU can run up to
+/// two frames ahead of the GPU before it has to wait.
+///
+/// The buffer is mapped once, when it is created, and stays mapped for its entire lifetime, so writing into it is
+/// nothing more than a `memcpy`.
+///
+/// ```ignore
+/// let mut ring = RingBuffer::new(&gl, 64 * 1024, 3);
+///
+/// loop {
+///     let mut lights = ring.allocate_uniform::<LightData>(num_lights).expect("ring buffer region is full");
+///     lights.copy_from_slice(&light_data);
+///     let (buffer, offset, size) = (lights.buffer(), lights.offset(), lights.size());
+///     // ... bind `size` bytes of `buffer` starting at `offset` to a uniform block, and draw ...
+///
+///     ring.finish_frame();
+/// }
+/// ```
+pub struct RingBuffer<'gl> {
+    gl: &'gl GLContext,
+    // NB: the mapping must be declared before the buffer so that it is dropped (and unmapped) first.
+    mapping: BufferMapping<'gl, u8>,
+    buffer: Buffer<'gl>,
+    region_size: usize,
+    /// The fence inserted after the last frame that used each region, if that frame may still be in flight.
+    fences: Vec<Option<SyncID>>,
+    /// The index of the region that allocations are currently being made from.
+    region: usize,
+    /// The offset of the next free byte, relative to the start of the current region.
+    head: usize,
+    uniform_alignment: usize,
+    storage_alignment: usize,
+}
+
+impl<'gl> RingBuffer<'gl> {
+    /// Creates a new ring buffer with `regions` regions of `region_size` bytes each.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `regions` or `region_size` is zero, or if the buffer cannot be mapped.
+    #[cfg(feature = "gl45")]
+    pub fn new(gl: &'gl GLContext, region_size: usize, regions: usize) -> Self {
+        assert!(regions > 0 && region_size > 0, "ring buffer should have at least one non-empty region");
+
+        let storage_flags = BufferStorageFlags::MAP_WRITE | BufferStorageFlags::MAP_PERSISTENT;
+        let storage_flags = storage_flags | BufferStorageFlags::MAP_COHERENT;
+        let map_flags = MapAccessFlags::WRITE | MapAccessFlags::PERSISTENT | MapAccessFlags::COHERENT;
+
+        let size = region_size * regions;
+        let buffer = Buffer::with_storage_empty(gl, size, storage_flags);
+
+        // SAFETY: the buffer's storage is immutable, so it can't be re-allocated; it is only deleted when this struct
+        // is dropped, after the mapping; and while the mapping is alive, the context refuses to unmap or delete the
+        // buffer through the ID returned from `buffer`.
+        let mapping = unsafe { gl.map_named_buffer_range(buffer.id(), 0, size, map_flags) };
+
+        Self {
+            gl,
+            mapping,
+            buffer,
+            region_size,
+            fences: vec![None; regions],
+            region: 0,
+            head: 0,
+            uniform_alignment: gl.get_uniform_buffer_offset_alignment(),
+            storage_alignment: gl.get_shader_storage_buffer_offset_alignment(),
+        }
+    }
+
+    /// Returns the ID of the underlying buffer object.
+    ///
+    /// The buffer stays mapped for as long as the ring buffer is alive, so the context will panic if this ID is used to
+    /// delete, re-allocate, or unmap it.
+    pub fn buffer(&self) -> BufferID {
+        self.buffer.id()
+    }
+
+    /// Returns the number of regions (frames in flight) in this ring buffer.
+    pub fn regions(&self) -> usize {
+        self.fences.len()
+    }
+
+    /// Returns the size of each region, in bytes.
+    pub fn region_size(&self) -> usize {
+        self.region_size
+    }
+
+    /// Returns the number of bytes still free in the current region, ignoring any padding needed for alignment.
+    pub fn remaining(&self) -> usize {
+        self.region_size - self.head
+    }
+
+    /// Allocates space for `count` values of type `T` from the current region, with the start of the allocation aligned
+    /// to a multiple of `alignment` bytes (and of `T`'s own alignment).
+    ///
+    /// The contents of the allocation are whatever was written to that part of the buffer the last time it was used.
+    /// Returns `None` if there is not enough space left in the current region.
+    pub fn allocate<T: Pod>(&mut self, count: usize, alignment: usize) -> Option<RingAllocation<'_, T>> {
+        let alignment = alignment.max(std::mem::align_of::<T>()).max(1);
+        let size = count * std::mem::size_of::<T>();
+
+        let region_start = self.region * self.region_size;
+        let start = (region_start + self.head).next_multiple_of(alignment);
+        let end = start + size;
+        if end > region_start + self.region_size {
+            return None;
+        }
+
+        self.head = end - region_start;
+        Some(RingAllocation {
+            buffer: self.buffer.id(),
+            offset: start,
+            data: bytemuck::cast_slice_mut(&mut self.mapping[start..end]),
+        })
+    }
+
+    /// Allocates space for `count` values of type `T`, aligned so that the allocation can be bound to a
+    /// [uniform buffer][BufferTarget::UniformBuffer] binding point.
+    pub fn allocate_uniform<T: Pod>(&mut self, count: usize) -> Option<RingAllocation<'_, T>> {
+        self.allocate(count, self.uniform_alignment)
+    }
+
+    /// Allocates space for `count` values of type `T`, aligned so that the allocation can be bound to a
+    /// [shader storage buffer][BufferTarget::ShaderStorageBuffer] binding point.
+    pub fn allocate_storage<T: Pod>(&mut self, count: usize) -> Option<RingAllocation<'_, T>> {
+        self.allocate(count, self.storage_alignment)
+    }
+
+    /// Marks the end of a frame's allocations and moves on to the next region.
+    ///
+    /// A fence is inserted after all of the commands issued so far, so this should be called once all of the draw calls
+    /// that read from the current region have been issued. If the GPU is still using the next region, this function
+    /// blocks until it is done with it.
+    pub fn finish_frame(&mut self) {
+        let gl = self.gl;
+
+        if let Some(old) = self.fences[self.region].replace(gl.fence_sync()) {
+            gl.delete_sync(old);
+        }
+
+        self.region = (self.region + 1) % self.fences.len();
+        self.head = 0;
+
+        if let Some(fence) = self.fences[self.region].take() {
+            while gl.client_wait_sync(fence, true, u64::MAX) == SyncStatus::TimeoutExpired {}
+            gl.delete_sync(fence);
+        }
+    }
+}
+
+impl<'gl> Drop for RingBuffer<'gl> {
+    fn drop(&mut self) {
+        for fence in self.fences.drain(..).flatten() {
+            self.gl.delete_sync(fence);
+        }
+    }
+}
+
+impl<'gl> std::fmt::Debug for RingBuffer<'gl> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RingBuffer")
+            .field("buffer", &self.buffer)
+            .field("region_size", &self.region_size)
+            .field("regions", &self.fences.len())
+            .field("region", &self.region)
+            .field("head", &self.head)
+            .finish()
+    }
+}
+
+
+/// A typed sub-allocation from a [`RingBuffer`].
+///
+/// Allocations dereference to a slice of `T` that writes straight into the mapped buffer. They only stay valid for the
+/// frame that they were allocated in.
+pub struct RingAllocation<'a, T: Pod> {
+    buffer: BufferID,
+    offset: usize,
+    data: &'a mut [T],
+}
+
+impl<'a, T: Pod> RingAllocation<'a, T> {
+    /// Returns the ID of the buffer that this allocation belongs to.
+    pub fn buffer(&self) -> BufferID {
+        self.buffer
+    }
+
+    /// Returns the offset of the start of this allocation within its buffer, in bytes.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the size of this allocation, in bytes.
+    pub fn size(&self) -> usize {
+        std::mem::size_of_val(self.data)
+    }
+}
+
+impl<'a, T: Pod> Deref for RingAllocation<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.data
+    }
+}
+
+impl<'a, T: Pod> DerefMut for RingAllocation<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.data
+    }
+}
+
+impl<'a, T: Pod + std::fmt::Debug> std::fmt::Debug for RingAllocation<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RingAllocation")
+            .field("buffer", &self.buffer)
+            .field("offset", &self.offset)
+            .field("data", &self.data)
+            .finish()
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use super::gl_object;
+use crate::types::*;
+use crate::GLContext;
+
+
+gl_object! {
+    /// An owned OpenGL sampler object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Sampler(SamplerID), delete_sampler;
+}
+
+
+impl<'gl> Sampler<'gl> {
+    /// Creates a new sampler object with OpenGL's default parameters.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        let id = gl.create_sampler();
+        Self { gl, id }
+    }
+
+    /// Creates a new sampler object with the parameters in the given description.
+    pub fn from_desc(gl: &'gl GLContext, desc: &SamplerDesc) -> Self {
+        let id = gl.create_sampler_from_desc(desc);
+        Self { gl, id }
+    }
+
+    /// Binds this sampler to the given texture unit.
+    pub fn bind_unit(&self, unit: u32) {
+        self.gl.bind_sampler(unit, self.id)
+    }
+
+    /// Sets every one of this sampler's parameters from a description. See [`GLContext::sampler_desc`].
+    pub fn set_desc(&mut self, desc: &SamplerDesc) {
+        self.gl.sampler_desc(self.id, desc)
+    }
+
+    /// Sets the wrapping mode used for the given texture coordinate.
+    pub fn set_wrap(&mut self, axis: TextureWrapAxis, mode: TextureWrap) {
+        self.gl.sampler_wrap(self.id, axis, mode)
+    }
+
+    /// Sets the filter used when textures are minified.
+    pub fn set_min_filter(&mut self, filter: TextureMinFilter) {
+        self.gl.sampler_min_filter(self.id, filter)
+    }
+
+    /// Sets the filter used when textures are magnified.
+    pub fn set_mag_filter(&mut self, filter: TextureMagFilter) {
+        self.gl.sampler_mag_filter(self.id, filter)
+    }
+
+    /// Sets the comparison used when sampling depth textures, or `None` to return depth values directly.
+    pub fn set_compare(&mut self, compare: Option<CompareFunction>) {
+        match compare {
+            Some(func) => {
+                self.gl.sampler_compare_mode(self.id, TextureCompareMode::CompareRefToTexture);
+                self.gl.sampler_compare_func(self.id, func);
+            },
+            None => self.gl.sampler_compare_mode(self.id, TextureCompareMode::None),
+        }
+    }
+
+    /// Sets the color returned when sampling outside of a texture with [`TextureWrap::ClampToBorder`].
+    pub fn set_border_color(&mut self, color: BorderColor) {
+        self.gl.sampler_border_color(self.id, color)
+    }
+}
+
+
+/// A cache of sampler objects, so that only one sampler is ever created for each distinct [`SamplerDesc`].
+///
+/// ```ignore
+/// let mut samplers = SamplerCache::new(&gl);
+/// let linear = SamplerDesc::new().with_wrap(TextureWrap::ClampToEdge);
+///
+/// // Both of these bind the same sampler object.
+/// gl.bind_sampler(0, samplers.get(&linear));
+/// gl.bind_sampler(1, samplers.get(&linear));
+/// ```
+pub struct SamplerCache<'gl> {
+    gl: &'gl GLContext,
+    samplers: HashMap<SamplerDesc, Sampler<'gl>>,
+}
+
+impl<'gl> SamplerCache<'gl> {
+    /// Creates a new, empty cache.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        Self { gl, samplers: HashMap::new() }
+    }
+
+    /// Returns the sampler for the given description, creating it if this cache doesn't already have one.
+    pub fn get(&mut self, desc: &SamplerDesc) -> SamplerID {
+        let gl = self.gl;
+        let sampler = self.samplers.entry(*desc).or_insert_with(|| Sampler::from_desc(gl, desc));
+        sampler.id()
+    }
+
+    /// Returns the number of samplers in this cache.
+    pub fn len(&self) -> usize {
+        self.samplers.len()
+    }
+
+    /// Returns `true` if this cache has not created any samplers.
+    pub fn is_empty(&self) -> bool {
+        self.samplers.is_empty()
+    }
+
+    /// Deletes every sampler in this cache.
+    pub fn clear(&mut self) {
+        self.samplers.clear();
+    }
+}
+
+impl<'gl> std::fmt::Debug for SamplerCache<'gl> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_map().entries(self.samplers.iter()).finish()
+    }
+}
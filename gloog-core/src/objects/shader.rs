@@ -0,0 +1,52 @@
+use super::gl_object;
+use crate::types::*;
+use crate::GLContext;
+
+
+gl_object! {
+    /// An owned OpenGL shader object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Shader(ShaderID), delete_shader;
+}
+
+
+impl<'gl> Shader<'gl> {
+    /// Creates a new, empty shader object of the given type.
+    pub fn new(gl: &'gl GLContext, shader_type: ShaderType) -> Self {
+        Self { gl, id: gl.create_shader(shader_type) }
+    }
+
+    /// Creates a new shader object, sets its source code, and compiles it.
+    ///
+    /// If compilation fails, the shader is deleted and its info log is returned as an error.
+    pub fn from_source<I, S>(gl: &'gl GLContext, shader_type: ShaderType, strings: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut shader = Self::new(gl, shader_type);
+        shader.set_source(strings);
+        shader.compile()?;
+        Ok(shader)
+    }
+
+    /// Replaces this shader's source code. See [`GLContext::shader_source`].
+    pub fn set_source<I, S>(&mut self, strings: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.gl.shader_source(self.id, strings)
+    }
+
+    /// Compiles this shader, returning its info log if compilation fails.
+    pub fn compile(&mut self) -> Result<(), String> {
+        self.gl.compile_shader(self.id)
+    }
+
+    /// Gets this shader's info log, if it has one.
+    pub fn info_log(&self) -> Option<String> {
+        self.gl.get_shader_info_log(self.id)
+    }
+}
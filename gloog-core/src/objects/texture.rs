@@ -0,0 +1,185 @@
+use std::ops::Deref;
+
+use image::{EncodableLayout, ImageBuffer};
+
+use super::gl_object;
+use crate::types::*;
+use crate::{GLContext, TexturePixel};
+
+
+gl_object! {
+    /// An owned OpenGL texture object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct Texture(TextureID), delete_texture;
+}
+
+
+impl<'gl> Texture<'gl> {
+    /// Creates a new texture object with the given target. Its storage must be allocated with one of the `storage_*`
+    /// methods before any data can be uploaded into it.
+    pub fn new(gl: &'gl GLContext, target: TextureTarget) -> Self {
+        let id = gl.create_texture(target);
+        Self { gl, id }
+    }
+
+    /// Creates a new two-dimensional texture from an image, with a full chain of mipmaps.
+    ///
+    /// The image is uploaded as-is, so its top row ends up at `t = 0`. See
+    /// [`GLContext::texture_sub_image_2d_from_image`] for more details.
+    pub fn from_image<P, C>(gl: &'gl GLContext, image: &ImageBuffer<P, C>, format: InternalFormat) -> Self
+    where
+        P: TexturePixel,
+        [P::Subpixel]: EncodableLayout,
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        let (width, height) = image.dimensions();
+        let (width, height) = (width as usize, height as usize);
+
+        let mut texture = Self::new(gl, TextureTarget::Texture2D);
+        texture.storage_2d(Self::mip_levels(width, height, 1), format, width, height);
+        texture.upload_image(0, (0, 0), image);
+        texture.generate_mipmap();
+        texture
+    }
+
+    /// Computes the number of levels in a full mipmap chain for a texture of the given size, all the way down to 1×1.
+    pub fn mip_levels(width: usize, height: usize, depth: usize) -> usize {
+        let largest = width.max(height).max(depth).max(1);
+        (usize::BITS - largest.leading_zeros()) as usize
+    }
+
+    /// Allocates immutable storage for a one-dimensional texture. See [`GLContext::texture_storage_1d`].
+    pub fn storage_1d(&mut self, levels: usize, format: InternalFormat, width: usize) {
+        self.gl.texture_storage_1d(self.id, levels, format, width)
+    }
+
+    /// Allocates immutable storage for a two-dimensional texture, a one-dimensional array texture, or a cube map. See
+    /// [`GLContext::texture_storage_2d`].
+    pub fn storage_2d(&mut self, levels: usize, format: InternalFormat, width: usize, height: usize) {
+        self.gl.texture_storage_2d(self.id, levels, format, width, height)
+    }
+
+    /// Allocates immutable storage for a three-dimensional texture, a two-dimensional array texture, or a cube map
+    /// array. See [`GLContext::texture_storage_3d`].
+    pub fn storage_3d(&mut self, levels: usize, format: InternalFormat, width: usize, height: usize, depth: usize) {
+        self.gl.texture_storage_3d(self.id, levels, format, width, height, depth)
+    }
+
+    /// Uploads pixel data into a region of this texture. See [`GLContext::texture_sub_image_1d`].
+    pub fn sub_image_1d(
+        &mut self,
+        level: usize,
+        x_offset: usize,
+        width: usize,
+        format: PixelFormat,
+        ty: PixelType,
+        data: &[u8],
+    ) {
+        self.gl.texture_sub_image_1d(self.id, level, x_offset, width, format, ty, data)
+    }
+
+    /// Uploads pixel data into a region of this texture. See [`GLContext::texture_sub_image_2d`].
+    pub fn sub_image_2d(
+        &mut self,
+        level: usize,
+        offset: (usize, usize),
+        size: (usize, usize),
+        format: PixelFormat,
+        ty: PixelType,
+        data: &[u8],
+    ) {
+        self.gl.texture_sub_image_2d(self.id, level, offset, size, format, ty, data)
+    }
+
+    /// Uploads pixel data into a region of this texture. See [`GLContext::texture_sub_image_3d`].
+    pub fn sub_image_3d(
+        &mut self,
+        level: usize,
+        offset: (usize, usize, usize),
+        size: (usize, usize, usize),
+        format: PixelFormat,
+        ty: PixelType,
+        data: &[u8],
+    ) {
+        self.gl.texture_sub_image_3d(self.id, level, offset, size, format, ty, data)
+    }
+
+    /// Uploads an image into a region of this texture. See [`GLContext::texture_sub_image_2d_from_image`].
+    pub fn upload_image<P, C>(&mut self, level: usize, offset: (usize, usize), image: &ImageBuffer<P, C>)
+    where
+        P: TexturePixel,
+        [P::Subpixel]: EncodableLayout,
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        self.gl.texture_sub_image_2d_from_image(self.id, level, offset, image)
+    }
+
+    /// Uploads an image into one face of this cube map texture.
+    ///
+    /// As with [`upload_image`][Self::upload_image], the image is not flipped. Cube maps use a left-handed coordinate
+    /// system with the origin of each face in its top-left corner, so images can usually be uploaded without flipping.
+    pub fn upload_cube_face<P, C>(&mut self, level: usize, face: CubeMapFace, image: &ImageBuffer<P, C>)
+    where
+        P: TexturePixel,
+        [P::Subpixel]: EncodableLayout,
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        let (width, height) = image.dimensions();
+        let size = (width as usize, height as usize, 1);
+        let data = image.as_raw().as_bytes();
+        let (gl, id) = (self.gl, self.id);
+        gl.with_tight_unpacking(|| {
+            gl.texture_sub_image_3d(id, level, (0, 0, face.layer()), size, P::FORMAT, P::TYPE, data);
+        });
+    }
+
+    /// Generates all of this texture's mipmap levels from its base level.
+    pub fn generate_mipmap(&mut self) {
+        self.gl.generate_texture_mipmap(self.id)
+    }
+
+    /// Binds this texture to the given texture unit.
+    pub fn bind_unit(&self, unit: u32) {
+        self.gl.bind_texture_unit(unit, self.id)
+    }
+
+    /// Binds one mipmap level of this texture to the given image unit. See [`GLContext::bind_image_texture`].
+    pub fn bind_image(&self, unit: u32, level: usize, access: ImageAccess, format: ImageUnitFormat) {
+        self.gl.bind_image_texture(unit, self.id, level, None, access, format)
+    }
+
+    /// Sets the wrapping mode used for the given texture coordinate.
+    pub fn set_wrap(&mut self, axis: TextureWrapAxis, mode: TextureWrap) {
+        self.gl.texture_wrap(self.id, axis, mode)
+    }
+
+    /// Sets the wrapping mode used for all texture coordinates.
+    pub fn set_wrap_all(&mut self, mode: TextureWrap) {
+        self.set_wrap(TextureWrapAxis::S, mode);
+        self.set_wrap(TextureWrapAxis::T, mode);
+        self.set_wrap(TextureWrapAxis::R, mode);
+    }
+
+    /// Sets the filters used when this texture is minified and magnified.
+    pub fn set_filters(&mut self, min: TextureMinFilter, mag: TextureMagFilter) {
+        self.gl.texture_min_filter(self.id, min);
+        self.gl.texture_mag_filter(self.id, mag);
+    }
+
+    /// Sets the maximum degree of anisotropic filtering. See [`GLContext::texture_max_anisotropy`].
+    #[cfg(any(feature = "gl46", feature = "ext-texture-filter-anisotropic"))]
+    pub fn set_max_anisotropy(&mut self, max: f32) {
+        self.gl.texture_max_anisotropy(self.id, max)
+    }
+
+    /// Sets which components are returned when sampling this texture. See [`GLContext::texture_swizzle`].
+    pub fn set_swizzle(&mut self, swizzle: [TextureSwizzle; 4]) {
+        self.gl.texture_swizzle(self.id, swizzle)
+    }
+
+    /// Sets the color returned when sampling outside of this texture with [`TextureWrap::ClampToBorder`].
+    pub fn set_border_color(&mut self, color: [f32; 4]) {
+        self.gl.texture_border_color(self.id, color)
+    }
+}
@@ -0,0 +1,71 @@
+use super::{gl_object, Buffer};
+use crate::types::*;
+use crate::vertex::VertexLayout;
+use crate::GLContext;
+
+
+gl_object! {
+    /// An owned OpenGL vertex array object.
+    ///
+    /// See the [module-level documentation][super] for more information.
+    pub struct VertexArray(VertexArrayID), delete_vertex_array;
+}
+
+
+impl<'gl> VertexArray<'gl> {
+    /// Creates a new vertex array object.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        Self { gl, id: gl.create_vertex_array() }
+    }
+
+    /// Binds this vertex array so that it will be used for subsequent draw calls.
+    pub fn bind(&self) {
+        self.gl.bind_vertex_array(self.id)
+    }
+
+    /// Enables the given vertex attribute for this vertex array.
+    pub fn enable_attrib(&mut self, index: impl Into<VertexAttribLocation>) {
+        self.gl.enable_vertex_array_attrib(self.id, index)
+    }
+
+    /// Disables the given vertex attribute for this vertex array.
+    pub fn disable_attrib(&mut self, index: impl Into<VertexAttribLocation>) {
+        self.gl.disable_vertex_array_attrib(self.id, index)
+    }
+
+    /// Sets the buffer that this vertex array will source its indices from for indexed draw calls.
+    pub fn set_element_buffer(&mut self, buffer: &Buffer) {
+        self.gl.vertex_array_element_buffer(self.id, buffer.id())
+    }
+
+    /// Binds a buffer to one of this vertex array's vertex buffer binding points. See
+    /// [`GLContext::vertex_array_vertex_buffer`].
+    pub fn set_vertex_buffer(&mut self, binding_index: u32, buffer: &Buffer, offset: usize, stride: usize) {
+        let (vao, buffer) = (self.id, buffer.id());
+        self.gl.vertex_array_vertex_buffer(vao, binding_index, buffer, offset, stride)
+    }
+
+    /// Sets which vertex buffer binding point one of this vertex array's attributes reads from.
+    pub fn set_attrib_binding(&mut self, index: impl Into<VertexAttribLocation>, binding_index: u32) {
+        self.gl.vertex_array_attrib_binding(self.id, index, binding_index)
+    }
+
+    /// Sets the instance divisor of one of this vertex array's vertex buffer binding points. See
+    /// [`GLContext::vertex_array_binding_divisor`].
+    pub fn set_binding_divisor(&mut self, binding_index: u32, divisor: u32) {
+        self.gl.vertex_array_binding_divisor(self.id, binding_index, divisor)
+    }
+
+    /// Configures and enables all of the attributes of `V`, reading them from the given vertex buffer binding point.
+    /// See [`GLContext::vertex_array_layout`].
+    pub fn set_layout<V: VertexLayout>(&mut self, binding_index: u32) {
+        self.gl.vertex_array_layout::<V>(self.id, binding_index)
+    }
+
+    /// Binds a buffer of `V`s to the given vertex buffer binding point, and configures and enables all of `V`'s
+    /// attributes to read from it.
+    pub fn set_vertex_buffer_with_layout<V: VertexLayout>(&mut self, binding_index: u32, buffer: &Buffer) {
+        self.set_vertex_buffer(binding_index, buffer, 0, V::STRIDE);
+        self.set_layout::<V>(binding_index);
+    }
+}
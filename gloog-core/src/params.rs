@@ -0,0 +1,524 @@
+//! Typed parameters for querying OpenGL state with `glGet*`.
+//!
+//! Each parameter is a zero-sized marker type that knows its OpenGL name and the type of value that it returns, so
+//! querying state doesn't require picking the right `glGet*` function or allocating a buffer of the right size. Pass a
+//! parameter to [`GLContext::get`] (or [`GLContext::get_indexed`], for indexed state):
+//!
+//! ```ignore
+//! use gloog_core::params::*;
+//!
+//! let max_size: i32 = gl.get(MaxTextureSize);
+//! let [x, y, width, height] = gl.get(Viewport);
+//! let depth_test: bool = gl.get(DepthTest);
+//! let depth_func: CompareFunction = gl.get(DepthFunc);
+//! let [max_x, ..] = [0, 1, 2].map(|i| gl.get_indexed(MaxComputeWorkGroupCount, i));
+//! ```
+//!
+//! [`GLContext::get`]: crate::GLContext::get
+//! [`GLContext::get_indexed`]: crate::GLContext::get_indexed
+
+use crate::raw::types::*;
+use crate::raw::GLPointers;
+use crate::types::*;
+
+
+/// A piece of OpenGL state that can be queried with [`GLContext::get`][crate::GLContext::get].
+///
+/// # Safety
+///
+/// Querying [`PNAME`][Self::PNAME] must write no more values than [`Value`][Self::Value] has room for.
+pub unsafe trait Parameter {
+    /// The type of value returned by this parameter.
+    type Value: GetValue;
+
+    /// The raw name of this parameter, passed to `glGet*`.
+    const PNAME: GLenum;
+}
+
+
+/// A piece of indexed OpenGL state that can be queried with
+/// [`GLContext::get_indexed`][crate::GLContext::get_indexed].
+///
+/// # Safety
+///
+/// Querying [`PNAME`][Self::PNAME] at any index must write no more values than [`Value`][Self::Value] has room for.
+pub unsafe trait IndexedParameter {
+    /// The type of value returned by this parameter.
+    type Value: GetValue;
+
+    /// The raw name of this parameter, passed to `glGet*i_v`.
+    const PNAME: GLenum;
+}
+
+
+/// A type that can be read out of OpenGL with one of the `glGet*` functions.
+///
+/// The function that is used depends on the type: `glGetIntegerv` for `i32`, `glGetInteger64v` for `i64`, `glGetFloatv`
+/// for `f32`, `glGetDoublev` for `f64`, and `glGetBooleanv` for `bool`. Arrays of these types use the same functions,
+/// and enums and object IDs are read as integers.
+pub trait GetValue: Sized {
+    /// Queries a value with the non-indexed form of this type's `glGet*` function.
+    ///
+    /// # Safety
+    ///
+    /// Querying `pname` must write no more values than this type has room for.
+    unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self;
+
+    /// Queries a value with the indexed form of this type's `glGet*` function.
+    ///
+    /// # Safety
+    ///
+    /// Querying `pname` at `index` must write no more values than this type has room for.
+    unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self;
+}
+
+
+// ---------------------------------------------------------------------------------------------------------------------
+// Value implementations
+// ---------------------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_get_value {
+    ($rs_type:ty, $get:ident $(, $get_i:ident)?) => {
+        impl GetValue for $rs_type {
+            unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self {
+                let [value] = unsafe { <[$rs_type; 1]>::get(gl, pname) };
+                value
+            }
+
+            unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self {
+                let [value] = unsafe { <[$rs_type; 1]>::get_indexed(gl, pname, index) };
+                value
+            }
+        }
+
+        impl<const N: usize> GetValue for [$rs_type; N] {
+            unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self {
+                let mut values = [Default::default(); N];
+                unsafe { gl.$get(pname, values.as_mut_ptr()) };
+                values
+            }
+
+            unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self {
+                let mut values = [Default::default(); N];
+                impl_get_value!(@get_indexed gl, pname, index, values, $get $(, $get_i)?);
+                values
+            }
+        }
+    };
+
+    (@get_indexed $gl:ident, $pname:ident, $index:ident, $values:ident, $get:ident, $get_i:ident) => {
+        unsafe { $gl.$get_i($pname, $index, $values.as_mut_ptr()) }
+    };
+
+    // Without an indexed `glGet*` function there is no indexed state of this type, so only index 0 makes sense.
+    (@get_indexed $gl:ident, $pname:ident, $index:ident, $values:ident, $get:ident) => {
+        assert_eq!($index, 0, "indexed state of this type requires OpenGL 4.1");
+        unsafe { $gl.$get($pname, $values.as_mut_ptr()) }
+    };
+}
+
+impl_get_value!(GLint, get_integer_v, get_integer_i_v);
+impl_get_value!(GLint64, get_integer_64v, get_integer_64i_v);
+
+// `glGetFloati_v` and `glGetDoublei_v` were added alongside viewport arrays in OpenGL 4.1.
+#[cfg(feature = "gl41")]
+impl_get_value!(GLfloat, get_float_v, get_float_i_v);
+#[cfg(feature = "gl41")]
+impl_get_value!(GLdouble, get_double_v, get_double_i_v);
+#[cfg(not(feature = "gl41"))]
+impl_get_value!(GLfloat, get_float_v);
+#[cfg(not(feature = "gl41"))]
+impl_get_value!(GLdouble, get_double_v);
+
+
+impl GetValue for bool {
+    unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self {
+        let [value] = unsafe { <[bool; 1]>::get(gl, pname) };
+        value
+    }
+
+    unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self {
+        let [value] = unsafe { <[bool; 1]>::get_indexed(gl, pname, index) };
+        value
+    }
+}
+
+impl<const N: usize> GetValue for [bool; N] {
+    unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self {
+        let mut values: [GLboolean; N] = [0; N];
+        unsafe { gl.get_boolean_v(pname, values.as_mut_ptr()) };
+        values.map(|b| b != 0)
+    }
+
+    unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self {
+        let mut values: [GLboolean; N] = [0; N];
+        unsafe { gl.get_boolean_i_v(pname, index, values.as_mut_ptr()) };
+        values.map(|b| b != 0)
+    }
+}
+
+
+macro_rules! impl_get_value_via_int {
+    // Enums are returned from `glGetIntegerv` as their raw values.
+    (enum $name:ident) => {
+        impl GetValue for $name {
+            unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self {
+                let value = unsafe { GLint::get(gl, pname) };
+                Self::from_raw(value as GLenum).expect(concat!("OpenGL should return a valid ", stringify!($name)))
+            }
+
+            unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self {
+                let value = unsafe { GLint::get_indexed(gl, pname, index) };
+                Self::from_raw(value as GLenum).expect(concat!("OpenGL should return a valid ", stringify!($name)))
+            }
+        }
+    };
+    // Object bindings return zero when nothing is bound.
+    (object $name:ident) => {
+        impl GetValue for Option<$name> {
+            unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self {
+                let value = unsafe { GLint::get(gl, pname) };
+                (value != 0).then(|| $name::new(value as GLuint))
+            }
+
+            unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self {
+                let value = unsafe { GLint::get_indexed(gl, pname, index) };
+                (value != 0).then(|| $name::new(value as GLuint))
+            }
+        }
+    };
+}
+
+impl_get_value_via_int!(enum BlendEquation);
+impl_get_value_via_int!(enum BlendFactor);
+impl_get_value_via_int!(enum CompareFunction);
+impl_get_value_via_int!(enum FrontFaceDirection);
+impl_get_value_via_int!(enum StencilOp);
+impl_get_value_via_int!(enum TriangleFace);
+
+impl_get_value_via_int!(object BufferID);
+impl_get_value_via_int!(object ProgramID);
+impl_get_value_via_int!(object RenderbufferID);
+impl_get_value_via_int!(object TextureID);
+impl_get_value_via_int!(object VertexArrayID);
+
+
+// The default framebuffer is a real framebuffer, so it is not returned as `None`.
+impl GetValue for FramebufferID {
+    unsafe fn get(gl: &GLPointers, pname: GLenum) -> Self {
+        FramebufferID::new(unsafe { GLint::get(gl, pname) } as GLuint)
+    }
+
+    unsafe fn get_indexed(gl: &GLPointers, pname: GLenum, index: GLuint) -> Self {
+        FramebufferID::new(unsafe { GLint::get_indexed(gl, pname, index) } as GLuint)
+    }
+}
+
+
+// ---------------------------------------------------------------------------------------------------------------------
+// Parameters
+// ---------------------------------------------------------------------------------------------------------------------
+
+macro_rules! parameters {
+    (
+        $(
+            $(#[doc = $doc:literal])*
+            $(#[cfg($cfg:meta)])*
+            $name:ident: $value:ty = $pname:ident $(, $kind:ident)?;
+        )*
+    ) => {
+        $(
+            $(#[doc = $doc])*
+            $(#[cfg($cfg)])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub struct $name;
+
+            $(#[cfg($cfg)])*
+            parameters!(@impl $name, $value, $pname $(, $kind)?);
+        )*
+    };
+
+    (@impl $name:ident, $value:ty, $pname:ident) => {
+        unsafe impl Parameter for $name {
+            type Value = $value;
+            const PNAME: GLenum = crate::raw::$pname;
+        }
+    };
+
+    (@impl $name:ident, $value:ty, $pname:ident, indexed) => {
+        parameters!(@impl $name, $value, $pname);
+        parameters!(@impl $name, $value, $pname, indexed_only);
+    };
+
+    (@impl $name:ident, $value:ty, $pname:ident, indexed_only) => {
+        unsafe impl IndexedParameter for $name {
+            type Value = $value;
+            const PNAME: GLenum = crate::raw::$pname;
+        }
+    };
+}
+
+
+parameters! {
+    // Context information
+
+    /// The major version number of the current context.
+    MajorVersion: i32 = MAJOR_VERSION;
+    /// The minor version number of the current context.
+    MinorVersion: i32 = MINOR_VERSION;
+    /// The number of extensions supported by the current context, for use with
+    /// [`GLContext::get_string_i`][crate::GLContext::get_string_i].
+    NumExtensions: i32 = NUM_EXTENSIONS;
+    /// The current time on the GPU, in nanoseconds.
+    Timestamp: i64 = TIMESTAMP;
+
+    // Implementation limits
+
+    /// The largest width or height of a 1D or 2D texture.
+    MaxTextureSize: i32 = MAX_TEXTURE_SIZE;
+    /// The largest width, height, or depth of a 3D texture.
+    Max3DTextureSize: i32 = MAX_3D_TEXTURE_SIZE;
+    /// The largest width or height of a cube map texture.
+    MaxCubeMapTextureSize: i32 = MAX_CUBE_MAP_TEXTURE_SIZE;
+    /// The largest number of layers in an array texture.
+    MaxArrayTextureLayers: i32 = MAX_ARRAY_TEXTURE_LAYERS;
+    /// The largest width or height of a renderbuffer.
+    MaxRenderbufferSize: i32 = MAX_RENDERBUFFER_SIZE;
+    /// The largest absolute texture level-of-detail bias.
+    MaxTextureLodBias: f32 = MAX_TEXTURE_LOD_BIAS;
+    /// The largest degree of anisotropic filtering.
+    #[cfg(any(feature = "gl46", feature = "ext-texture-filter-anisotropic"))]
+    MaxTextureMaxAnisotropy: f32 = MAX_TEXTURE_MAX_ANISOTROPY;
+    /// The number of texture units that a fragment shader can read from.
+    MaxTextureImageUnits: i32 = MAX_TEXTURE_IMAGE_UNITS;
+    /// The number of texture units that all shader stages combined can read from.
+    MaxCombinedTextureImageUnits: i32 = MAX_COMBINED_TEXTURE_IMAGE_UNITS;
+    /// The number of vertex attributes available to a vertex shader.
+    MaxVertexAttribs: i32 = MAX_VERTEX_ATTRIBS;
+    /// The number of vertex buffer binding points in a vertex array.
+    #[cfg(feature = "gl45")]
+    MaxVertexAttribBindings: i32 = MAX_VERTEX_ATTRIB_BINDINGS;
+    /// The number of individual components available for uniforms in a vertex shader.
+    MaxVertexUniformComponents: i32 = MAX_VERTEX_UNIFORM_COMPONENTS;
+    /// The number of individual components available for uniforms in a fragment shader.
+    MaxFragmentUniformComponents: i32 = MAX_FRAGMENT_UNIFORM_COMPONENTS;
+    /// The number of draw buffers that a fragment shader can write to at once.
+    MaxDrawBuffers: i32 = MAX_DRAW_BUFFERS;
+    /// The number of color attachments in a framebuffer.
+    MaxColorAttachments: i32 = MAX_COLOR_ATTACHMENTS;
+    /// The largest number of samples in a multisampled texture or renderbuffer.
+    MaxSamples: i32 = MAX_SAMPLES;
+    /// The number of viewports.
+    #[cfg(feature = "gl41")]
+    MaxViewports: i32 = MAX_VIEWPORTS;
+    /// The largest width and height of a viewport.
+    MaxViewportDims: [i32; 2] = MAX_VIEWPORT_DIMS;
+    /// The number of uniform buffer binding points.
+    MaxUniformBufferBindings: i32 = MAX_UNIFORM_BUFFER_BINDINGS;
+    /// The largest size of a uniform block, in bytes.
+    MaxUniformBlockSize: i32 = MAX_UNIFORM_BLOCK_SIZE;
+    /// The number of shader storage buffer binding points.
+    #[cfg(feature = "gl45")]
+    MaxShaderStorageBufferBindings: i32 = MAX_SHADER_STORAGE_BUFFER_BINDINGS;
+    /// The largest size of a shader storage block, in bytes.
+    #[cfg(feature = "gl45")]
+    MaxShaderStorageBlockSize: i64 = MAX_SHADER_STORAGE_BLOCK_SIZE;
+    /// The alignment, in bytes, of offsets into buffers bound to uniform buffer binding points.
+    UniformBufferOffsetAlignment: i32 = UNIFORM_BUFFER_OFFSET_ALIGNMENT;
+    /// The alignment, in bytes, of offsets into buffers bound to shader storage buffer binding points.
+    #[cfg(feature = "gl45")]
+    ShaderStorageBufferOffsetAlignment: i32 = SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT;
+    /// The largest index that may be used by an indexed draw command.
+    #[cfg(feature = "gl45")]
+    MaxElementIndex: i64 = MAX_ELEMENT_INDEX;
+    /// The largest timeout, in nanoseconds, that may be given to `glWaitSync`.
+    MaxServerWaitTimeout: i64 = MAX_SERVER_WAIT_TIMEOUT;
+    /// The number of work groups that may be dispatched in each dimension (index 0, 1, or 2).
+    #[cfg(feature = "gl45")]
+    MaxComputeWorkGroupCount: i32 = MAX_COMPUTE_WORK_GROUP_COUNT, indexed_only;
+    /// The largest size of a compute work group in each dimension (index 0, 1, or 2).
+    #[cfg(feature = "gl45")]
+    MaxComputeWorkGroupSize: i32 = MAX_COMPUTE_WORK_GROUP_SIZE, indexed_only;
+    /// The largest total number of invocations in a compute work group.
+    #[cfg(feature = "gl45")]
+    MaxComputeWorkGroupInvocations: i32 = MAX_COMPUTE_WORK_GROUP_INVOCATIONS;
+    /// The largest total size, in bytes, of all `shared` variables in a compute shader.
+    #[cfg(feature = "gl45")]
+    MaxComputeSharedMemorySize: i32 = MAX_COMPUTE_SHARED_MEMORY_SIZE;
+    /// The longest debug message, in bytes, including its null terminator.
+    #[cfg(feature = "gl45")]
+    MaxDebugMessageLength: i32 = MAX_DEBUG_MESSAGE_LENGTH;
+    /// The longest object label, in bytes.
+    #[cfg(feature = "gl45")]
+    MaxLabelLength: i32 = MAX_LABEL_LENGTH;
+    /// The range of widths supported for aliased lines.
+    AliasedLineWidthRange: [f32; 2] = ALIASED_LINE_WIDTH_RANGE;
+    /// The range of widths supported for antialiased lines.
+    SmoothLineWidthRange: [f32; 2] = SMOOTH_LINE_WIDTH_RANGE;
+
+    // Capabilities
+
+    /// Whether [blending][EnableCap::Blend] is enabled, for all draw buffers or a single one.
+    Blend: bool = BLEND, indexed;
+    /// Whether [face culling][EnableCap::CullFace] is enabled.
+    CullFace: bool = CULL_FACE;
+    /// Whether the [depth test][EnableCap::DepthTest] is enabled.
+    DepthTest: bool = DEPTH_TEST;
+    /// Whether the [stencil test][EnableCap::StencilTest] is enabled.
+    StencilTest: bool = STENCIL_TEST;
+    /// Whether the [scissor test][EnableCap::ScissorTest] is enabled, for all viewports or a single one.
+    ScissorTest: bool = SCISSOR_TEST, indexed;
+    /// Whether [polygon offset][EnableCap::PolygonOffsetFill] is enabled for filled polygons.
+    PolygonOffsetFill: bool = POLYGON_OFFSET_FILL;
+    /// Whether [multisampling][EnableCap::Multisample] is enabled.
+    Multisample: bool = MULTISAMPLE;
+    /// Whether [sRGB conversion][EnableCap::FramebufferSRGB] is enabled for framebuffer writes.
+    FramebufferSrgb: bool = FRAMEBUFFER_SRGB;
+    /// Whether [primitive restart][EnableCap::PrimitiveRestartFixedIndex] is enabled with the maximum index.
+    #[cfg(feature = "gl45")]
+    PrimitiveRestartFixedIndex: bool = PRIMITIVE_RESTART_FIXED_INDEX;
+    /// Whether [debug output][EnableCap::DebugOutput] is enabled.
+    #[cfg(feature = "gl45")]
+    DebugOutput: bool = DEBUG_OUTPUT;
+    /// Whether [debug output][EnableCap::DebugOutputSynchronous] is synchronous.
+    #[cfg(feature = "gl45")]
+    DebugOutputSynchronous: bool = DEBUG_OUTPUT_SYNCHRONOUS;
+
+    // Rasterization and fragment state
+
+    /// The current viewport, as `[x, y, width, height]`, for the first viewport or a single one.
+    Viewport: [i32; 4] = VIEWPORT, indexed;
+    /// The current scissor rectangle, as `[x, y, width, height]`, for the first viewport or a single one.
+    ScissorBox: [i32; 4] = SCISSOR_BOX, indexed;
+    /// The current mapping of depth values to window coordinates, as `[near, far]`.
+    DepthRange: [f64; 2] = DEPTH_RANGE, indexed;
+    /// Which faces are culled when face culling is enabled.
+    CullFaceMode: TriangleFace = CULL_FACE_MODE;
+    /// Which winding is considered to be front-facing.
+    FrontFace: FrontFaceDirection = FRONT_FACE;
+    /// The current line width.
+    LineWidth: f32 = LINE_WIDTH;
+    /// The current point size.
+    PointSize: f32 = POINT_SIZE;
+    /// The current polygon offset factor.
+    PolygonOffsetFactor: f32 = POLYGON_OFFSET_FACTOR;
+    /// The current polygon offset units.
+    PolygonOffsetUnits: f32 = POLYGON_OFFSET_UNITS;
+    /// Which color components may be written to, for the first draw buffer or a single one.
+    ColorWritemask: [bool; 4] = COLOR_WRITEMASK, indexed;
+    /// The color used to clear color buffers.
+    ColorClearValue: [f32; 4] = COLOR_CLEAR_VALUE;
+    /// The value used to clear the depth buffer.
+    DepthClearValue: f64 = DEPTH_CLEAR_VALUE;
+    /// The value used to clear the stencil buffer.
+    StencilClearValue: i32 = STENCIL_CLEAR_VALUE;
+
+    // Blend state
+
+    /// The constant blend color.
+    BlendColor: [f32; 4] = BLEND_COLOR;
+    /// The source blend factor for color components, for the first draw buffer or a single one.
+    BlendSrcRgb: BlendFactor = BLEND_SRC_RGB, indexed;
+    /// The destination blend factor for color components, for the first draw buffer or a single one.
+    BlendDstRgb: BlendFactor = BLEND_DST_RGB, indexed;
+    /// The source blend factor for the alpha component, for the first draw buffer or a single one.
+    BlendSrcAlpha: BlendFactor = BLEND_SRC_ALPHA, indexed;
+    /// The destination blend factor for the alpha component, for the first draw buffer or a single one.
+    BlendDstAlpha: BlendFactor = BLEND_DST_ALPHA, indexed;
+    /// The blend equation for color components, for the first draw buffer or a single one.
+    BlendEquationRgb: BlendEquation = BLEND_EQUATION_RGB, indexed;
+    /// The blend equation for the alpha component, for the first draw buffer or a single one.
+    BlendEquationAlpha: BlendEquation = BLEND_EQUATION_ALPHA, indexed;
+
+    // Depth and stencil state
+
+    /// The comparison used by the depth test.
+    DepthFunc: CompareFunction = DEPTH_FUNC;
+    /// Whether the depth buffer may be written to.
+    DepthWritemask: bool = DEPTH_WRITEMASK;
+    /// The comparison used by the stencil test for front-facing polygons.
+    StencilFunc: CompareFunction = STENCIL_FUNC;
+    /// The stencil reference value for front-facing polygons.
+    StencilRef: i32 = STENCIL_REF;
+    /// The mask applied to stencil values before comparison, for front-facing polygons.
+    StencilValueMask: i32 = STENCIL_VALUE_MASK;
+    /// The mask of stencil bits that may be written to, for front-facing polygons.
+    StencilWritemask: i32 = STENCIL_WRITEMASK;
+    /// The stencil operation used when the stencil test fails, for front-facing polygons.
+    StencilFail: StencilOp = STENCIL_FAIL;
+    /// The stencil operation used when the depth test fails, for front-facing polygons.
+    StencilPassDepthFail: StencilOp = STENCIL_PASS_DEPTH_FAIL;
+    /// The stencil operation used when both tests pass, for front-facing polygons.
+    StencilPassDepthPass: StencilOp = STENCIL_PASS_DEPTH_PASS;
+    /// The comparison used by the stencil test for back-facing polygons.
+    StencilBackFunc: CompareFunction = STENCIL_BACK_FUNC;
+    /// The stencil reference value for back-facing polygons.
+    StencilBackRef: i32 = STENCIL_BACK_REF;
+    /// The mask applied to stencil values before comparison, for back-facing polygons.
+    StencilBackValueMask: i32 = STENCIL_BACK_VALUE_MASK;
+    /// The mask of stencil bits that may be written to, for back-facing polygons.
+    StencilBackWritemask: i32 = STENCIL_BACK_WRITEMASK;
+    /// The stencil operation used when the stencil test fails, for back-facing polygons.
+    StencilBackFail: StencilOp = STENCIL_BACK_FAIL;
+    /// The stencil operation used when the depth test fails, for back-facing polygons.
+    StencilBackPassDepthFail: StencilOp = STENCIL_BACK_PASS_DEPTH_FAIL;
+    /// The stencil operation used when both tests pass, for back-facing polygons.
+    StencilBackPassDepthPass: StencilOp = STENCIL_BACK_PASS_DEPTH_PASS;
+
+    // Pixel storage
+
+    /// The alignment of rows of pixel data read back from OpenGL.
+    PackAlignment: i32 = PACK_ALIGNMENT;
+    /// The alignment of rows of pixel data sent to OpenGL.
+    UnpackAlignment: i32 = UNPACK_ALIGNMENT;
+    /// The number of pixels in each row of pixel data sent to OpenGL, or 0 if rows are as wide as the upload.
+    UnpackRowLength: i32 = UNPACK_ROW_LENGTH;
+    /// The number of rows in each image of three-dimensional pixel data sent to OpenGL, or 0 if images are as tall as
+    /// the upload.
+    UnpackImageHeight: i32 = UNPACK_IMAGE_HEIGHT;
+    /// The number of pixels skipped at the start of each row of pixel data sent to OpenGL.
+    UnpackSkipPixels: i32 = UNPACK_SKIP_PIXELS;
+    /// The number of rows skipped at the start of pixel data sent to OpenGL.
+    UnpackSkipRows: i32 = UNPACK_SKIP_ROWS;
+    /// The number of images skipped at the start of three-dimensional pixel data sent to OpenGL.
+    UnpackSkipImages: i32 = UNPACK_SKIP_IMAGES;
+
+    // Bindings
+
+    /// The program in use.
+    CurrentProgram: Option<ProgramID> = CURRENT_PROGRAM;
+    /// The bound vertex array.
+    VertexArrayBinding: Option<VertexArrayID> = VERTEX_ARRAY_BINDING;
+    /// The buffer bound to [`BufferTarget::ArrayBuffer`].
+    ArrayBufferBinding: Option<BufferID> = ARRAY_BUFFER_BINDING;
+    /// The buffer bound to [`BufferTarget::ElementArrayBuffer`] in the bound vertex array.
+    ElementArrayBufferBinding: Option<BufferID> = ELEMENT_ARRAY_BUFFER_BINDING;
+    /// The buffer bound to the general [`BufferTarget::UniformBuffer`] binding point, or to one of the indexed ones.
+    UniformBufferBinding: Option<BufferID> = UNIFORM_BUFFER_BINDING, indexed;
+    /// The offset into the buffer bound to an indexed uniform buffer binding point.
+    UniformBufferStart: i64 = UNIFORM_BUFFER_START, indexed_only;
+    /// The size of the range of the buffer bound to an indexed uniform buffer binding point.
+    UniformBufferSize: i64 = UNIFORM_BUFFER_SIZE, indexed_only;
+    /// The buffer bound to the general [`BufferTarget::ShaderStorageBuffer`] binding point, or to one of the indexed
+    /// ones.
+    #[cfg(feature = "gl45")]
+    ShaderStorageBufferBinding: Option<BufferID> = SHADER_STORAGE_BUFFER_BINDING, indexed;
+    /// The offset into the buffer bound to an indexed shader storage buffer binding point.
+    #[cfg(feature = "gl45")]
+    ShaderStorageBufferStart: i64 = SHADER_STORAGE_BUFFER_START, indexed_only;
+    /// The size of the range of the buffer bound to an indexed shader storage buffer binding point.
+    #[cfg(feature = "gl45")]
+    ShaderStorageBufferSize: i64 = SHADER_STORAGE_BUFFER_SIZE, indexed_only;
+    /// The buffer bound to [`BufferTarget::PixelPackBuffer`].
+    PixelPackBufferBinding: Option<BufferID> = PIXEL_PACK_BUFFER_BINDING;
+    /// The buffer bound to [`BufferTarget::PixelUnpackBuffer`].
+    PixelUnpackBufferBinding: Option<BufferID> = PIXEL_UNPACK_BUFFER_BINDING;
+    /// The 2D texture bound to the active texture unit.
+    TextureBinding2D: Option<TextureID> = TEXTURE_BINDING_2D;
+    /// The bound renderbuffer.
+    RenderbufferBinding: Option<RenderbufferID> = RENDERBUFFER_BINDING;
+    /// The framebuffer bound for drawing.
+    DrawFramebufferBinding: FramebufferID = DRAW_FRAMEBUFFER_BINDING;
+    /// The framebuffer bound for reading.
+    ReadFramebufferBinding: FramebufferID = READ_FRAMEBUFFER_BINDING;
+}
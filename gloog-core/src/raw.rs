@@ -6,6 +6,9 @@ use core::ffi::{c_char, c_void};
 
 use types::*;
 
+#[cfg(feature = "trace")]
+use crate::trace::{self, Tracer, Value};
+
 type VoidPtr = *const c_void;
 
 /// FFI-style type aliases to allow for nicer use of
@@ -159,5 +162,106 @@ pub enum InitFailureMode {
 }
 
 
+/// What the generated bindings do when `glGetError` reports an error after a call. Only available with the
+/// `error-checking` feature.
+#[cfg(feature = "error-checking")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ErrorCheckMode {
+    /// Errors are ignored, as if the `error-checking` feature was disabled (though `glGetError` is still called).
+    Ignore,
+    /// Errors are [logged](https://docs.rs/log) along with the name and arguments of the function that caused them.
+    #[default]
+    Log,
+    /// Errors cause a panic, with the name and arguments of the function that caused them.
+    Panic,
+}
+
+#[cfg(feature = "error-checking")]
+impl GLPointers {
+    /// Sets what happens when a call reports an error.
+    pub fn set_error_check_mode(&self, mode: ErrorCheckMode) {
+        self.error_check_mode.set(mode);
+    }
+
+    /// Returns what happens when a call reports an error.
+    pub fn error_check_mode(&self) -> ErrorCheckMode {
+        self.error_check_mode.get()
+    }
+
+    /// Drains every error flag reported by `glGetError`, then logs or panics if there were any. `describe_call` formats
+    /// the call that was just made, and is only called if there was an error.
+    fn check_errors(&self, describe_call: impl FnOnce() -> String) {
+        let mut errors = Vec::new();
+        loop {
+            // SAFETY: `glGetError` has no preconditions, so this is only as unsafe as the call that is being checked.
+            match unsafe { self.get_error() } {
+                NO_ERROR => break,
+                // If the context is lost, `glGetError` keeps reporting it forever, so stop here.
+                #[cfg(feature = "gl45")]
+                CONTEXT_LOST => {
+                    errors.push(CONTEXT_LOST);
+                    break;
+                },
+                err => errors.push(err),
+            }
+        }
+
+        if errors.is_empty() {
+            return;
+        }
+
+        let names = errors.into_iter().map(error_name).collect::<Vec<_>>().join(", ");
+        match self.error_check_mode.get() {
+            ErrorCheckMode::Ignore => {},
+            ErrorCheckMode::Log => log::error!("{names} in call to {}", describe_call()),
+            ErrorCheckMode::Panic => panic!("{names} in call to {}", describe_call()),
+        }
+    }
+}
+
+/// Returns the name of an error code returned by `glGetError`.
+#[cfg(feature = "error-checking")]
+fn error_name(error: GLenum) -> String {
+    match crate::types::ErrorCode::from_raw(error) {
+        Some(code) => format!("{code:?}"),
+        // The registry leaves this one out of the `ErrorCode` group
+        #[cfg(feature = "gl45")]
+        None if error == CONTEXT_LOST => "ContextLost".to_string(),
+        None => format!("unknown error {error:#06X}"),
+    }
+}
+
+
+#[cfg(feature = "trace")]
+impl GLPointers {
+    /// Starts recording every call to the given tracer, replacing the one that was already running, if any.
+    pub(crate) fn start_trace(&self, tracer: Tracer) {
+        *self.tracer.borrow_mut() = Some(tracer);
+    }
+
+    /// Stops recording calls, and returns the tracer that was recording them.
+    pub(crate) fn stop_trace(&self) -> Option<Tracer> {
+        self.tracer.borrow_mut().take()
+    }
+
+    /// Checks whether calls are currently being recorded. Only available with the `trace` feature.
+    pub fn is_tracing(&self) -> bool {
+        self.tracer.borrow().is_some()
+    }
+
+    /// Borrows the running tracer, if there is one.
+    fn tracer(&self) -> Option<core::cell::RefMut<'_, Tracer>> {
+        core::cell::RefMut::filter_map(self.tracer.borrow_mut(), Option::as_mut).ok()
+    }
+}
+
+
 // Include the rest of the bindings, including `GLPointers`, from the build script
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+// Anisotropic filtering only became core in OpenGL 4.6; before that, the extension's constants have the same values.
+#[cfg(all(feature = "ext-texture-filter-anisotropic", not(feature = "gl46")))]
+pub use self::{
+    MAX_TEXTURE_MAX_ANISOTROPY_EXT as MAX_TEXTURE_MAX_ANISOTROPY,
+    TEXTURE_MAX_ANISOTROPY_EXT as TEXTURE_MAX_ANISOTROPY,
+};
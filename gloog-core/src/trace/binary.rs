@@ -0,0 +1,205 @@
+//! The binary trace format.
+//!
+//! A binary trace starts with [`MAGIC`], followed by a series of records. Each record starts with a one-byte tag:
+//!
+//! - `FUNCTION` gives a function a 16-bit ID, the first time that it is called: the ID, then the length of the name
+//!   (one byte), then the name.
+//! - `CALL` records a call: the ID of the function, the number of arguments (one byte), each argument, then a one-byte
+//!   flag saying whether the call has a return value, and the return value.
+//!
+//! Values also start with a one-byte tag, followed by their contents. Numbers are little-endian; strings and blobs are
+//! prefixed by their length.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Read, Write};
+
+use super::{invalid_data, Blob, Call, Value};
+
+
+/// The bytes at the start of every binary trace, including its version number.
+pub(super) const MAGIC: &[u8] = b"GLTRACE\x01";
+
+const FUNCTION: u8 = 1;
+const CALL: u8 = 2;
+
+const ENUM: u8 = 0;
+const BITFIELD: u8 = 1;
+const BOOL: u8 = 2;
+const INT: u8 = 3;
+const UINT: u8 = 4;
+const FLOAT: u8 = 5;
+const STR: u8 = 6;
+const BLOB: u8 = 7;
+const OFFSET: u8 = 8;
+const PTR: u8 = 9;
+
+
+pub(super) fn write_call(
+    output: &mut impl Write,
+    functions: &mut HashMap<&'static str, u16>,
+    name: &'static str,
+    args: &[Value],
+    ret: Option<&Value>,
+) -> io::Result<()> {
+    let id = match functions.get(name) {
+        Some(&id) => id,
+        None => {
+            let id = u16::try_from(functions.len()).map_err(|_| invalid_data("too many functions for one trace"))?;
+            let len = u8::try_from(name.len()).map_err(|_| invalid_data("function name is too long"))?;
+            output.write_all(&[FUNCTION])?;
+            output.write_all(&id.to_le_bytes())?;
+            output.write_all(&[len])?;
+            output.write_all(name.as_bytes())?;
+            functions.insert(name, id);
+            id
+        },
+    };
+
+    let argc = u8::try_from(args.len()).map_err(|_| invalid_data("too many arguments"))?;
+    output.write_all(&[CALL])?;
+    output.write_all(&id.to_le_bytes())?;
+    output.write_all(&[argc])?;
+
+    for arg in args {
+        write_value(output, arg)?;
+    }
+
+    match ret {
+        Some(ret) => {
+            output.write_all(&[1])?;
+            write_value(output, ret)
+        },
+        None => output.write_all(&[0]),
+    }
+}
+
+
+fn write_value(output: &mut impl Write, value: &Value) -> io::Result<()> {
+    match value {
+        Value::Enum(value) => {
+            output.write_all(&[ENUM])?;
+            output.write_all(&value.to_le_bytes())
+        },
+        Value::Bitfield(bits) => {
+            output.write_all(&[BITFIELD])?;
+            output.write_all(&bits.to_le_bytes())
+        },
+        Value::Bool(value) => output.write_all(&[BOOL, *value as u8]),
+        Value::Int(value) => {
+            output.write_all(&[INT])?;
+            output.write_all(&value.to_le_bytes())
+        },
+        Value::UInt(value) => {
+            output.write_all(&[UINT])?;
+            output.write_all(&value.to_le_bytes())
+        },
+        Value::Float(value) => {
+            output.write_all(&[FLOAT])?;
+            output.write_all(&value.to_le_bytes())
+        },
+        Value::Str(str) => {
+            output.write_all(&[STR])?;
+            output.write_all(&(str.len() as u64).to_le_bytes())?;
+            output.write_all(str.as_bytes())
+        },
+        Value::Blob(blob) => {
+            let bytes = blob.as_bytes();
+            output.write_all(&[BLOB])?;
+            output.write_all(&(bytes.len() as u64).to_le_bytes())?;
+            output.write_all(bytes)
+        },
+        Value::Offset(offset) => {
+            output.write_all(&[OFFSET])?;
+            output.write_all(&offset.to_le_bytes())
+        },
+        Value::Ptr(ptr) => {
+            output.write_all(&[PTR])?;
+            output.write_all(&ptr.to_le_bytes())
+        },
+    }
+}
+
+
+pub(super) fn read_call(input: &mut impl BufRead, functions: &mut HashMap<u16, String>) -> io::Result<Option<Call>> {
+    loop {
+        // A trace may end after any complete record
+        if input.fill_buf()?.is_empty() {
+            return Ok(None);
+        }
+
+        match read_array::<1>(input)? {
+            [FUNCTION] => {
+                let id = u16::from_le_bytes(read_array(input)?);
+                let [len] = read_array(input)?;
+                let name = read_string(input, len as u64)?;
+                functions.insert(id, name);
+            },
+            [CALL] => {
+                let id = u16::from_le_bytes(read_array(input)?);
+                let name = functions
+                    .get(&id)
+                    .ok_or_else(|| invalid_data(format!("unknown function ID {id}")))?;
+                let name = name.clone();
+
+                let [argc] = read_array(input)?;
+                let args = (0..argc).map(|_| read_value(input)).collect::<io::Result<Vec<_>>>()?;
+
+                let ret = match read_array(input)? {
+                    [0] => None,
+                    [_] => Some(read_value(input)?),
+                };
+
+                return Ok(Some(Call { name, args, ret }));
+            },
+            [tag] => return Err(invalid_data(format!("unknown record tag {tag}"))),
+        }
+    }
+}
+
+
+fn read_value(input: &mut impl Read) -> io::Result<Value> {
+    let [tag] = read_array(input)?;
+    Ok(match tag {
+        ENUM => Value::Enum(u32::from_le_bytes(read_array(input)?)),
+        BITFIELD => Value::Bitfield(u32::from_le_bytes(read_array(input)?)),
+        BOOL => Value::Bool(read_array::<1>(input)? != [0]),
+        INT => Value::Int(i64::from_le_bytes(read_array(input)?)),
+        UINT => Value::UInt(u64::from_le_bytes(read_array(input)?)),
+        FLOAT => Value::Float(f64::from_le_bytes(read_array(input)?)),
+        STR => {
+            let len = u64::from_le_bytes(read_array(input)?);
+            Value::Str(read_string(input, len)?)
+        },
+        BLOB => {
+            let len = u64::from_le_bytes(read_array(input)?);
+            Value::Blob(Blob::new(&read_bytes(input, len)?))
+        },
+        OFFSET => Value::Offset(u64::from_le_bytes(read_array(input)?)),
+        PTR => Value::Ptr(u64::from_le_bytes(read_array(input)?)),
+        _ => return Err(invalid_data(format!("unknown value tag {tag}"))),
+    })
+}
+
+
+fn read_array<const N: usize>(input: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    input.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads `len` bytes. Lengths come from the trace, so the buffer only grows as data actually arrives, instead of
+/// trusting a corrupt length enough to allocate it all up front.
+fn read_bytes(input: &mut impl Read, len: u64) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    input.take(len).read_to_end(&mut bytes)?;
+
+    if bytes.len() as u64 != len {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "trace ends in the middle of a value"));
+    }
+
+    Ok(bytes)
+}
+
+fn read_string(input: &mut impl Read, len: u64) -> io::Result<String> {
+    String::from_utf8(read_bytes(input, len)?).map_err(|_| invalid_data("string is not valid UTF-8"))
+}
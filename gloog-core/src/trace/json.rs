@@ -0,0 +1,399 @@
+//! The JSON-lines trace format.
+//!
+//! Each line of a JSON-lines trace is one call, like:
+//!
+//! ```text
+//! {"fn":"glNamedBufferData","args":[{"uint":1},{"int":12},{"blob":"AACAPwAAAEAAAEBA"},{"enum":35044}]}
+//! {"fn":"glCreateShader","args":[{"enum":35633}],"ret":{"uint":2}}
+//! ```
+//!
+//! Each value is an object with a single key, saying what kind of value it is (`enum`, `bits`, `bool`, `int`, `uint`,
+//! `float`, `str`, `blob`, `offset`, or `ptr`). Blobs are base64-encoded, and floats that JSON can't represent (NaN and
+//! infinities) are written as the strings `"NaN"`, `"inf"`, and `"-inf"`.
+//!
+//! Only as much of JSON as these traces need is supported, so that reading them doesn't need any extra dependencies.
+
+use std::io::{self, BufRead, Write};
+
+use super::{invalid_data, Blob, Call, Value};
+
+
+pub(super) fn write_call(output: &mut impl Write, name: &str, args: &[Value], ret: Option<&Value>) -> io::Result<()> {
+    write!(output, "{{\"fn\":")?;
+    write_string(output, name)?;
+
+    write!(output, ",\"args\":[")?;
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            write!(output, ",")?;
+        }
+        write_value(output, arg)?;
+    }
+    write!(output, "]")?;
+
+    if let Some(ret) = ret {
+        write!(output, ",\"ret\":")?;
+        write_value(output, ret)?;
+    }
+
+    writeln!(output, "}}")
+}
+
+
+fn write_value(output: &mut impl Write, value: &Value) -> io::Result<()> {
+    match value {
+        Value::Enum(value) => write!(output, "{{\"enum\":{value}}}"),
+        Value::Bitfield(bits) => write!(output, "{{\"bits\":{bits}}}"),
+        Value::Bool(value) => write!(output, "{{\"bool\":{value}}}"),
+        Value::Int(value) => write!(output, "{{\"int\":{value}}}"),
+        Value::UInt(value) => write!(output, "{{\"uint\":{value}}}"),
+        Value::Float(value) if value.is_nan() => write!(output, "{{\"float\":\"NaN\"}}"),
+        Value::Float(value) if value.is_infinite() => write!(output, "{{\"float\":\"{value}\"}}"),
+        // `Display` for floats never uses exponents, so it is always valid JSON, and it round-trips exactly.
+        Value::Float(value) => write!(output, "{{\"float\":{value}}}"),
+        Value::Str(str) => {
+            write!(output, "{{\"str\":")?;
+            write_string(output, str)?;
+            write!(output, "}}")
+        },
+        Value::Blob(blob) => write!(output, "{{\"blob\":\"{}\"}}", base64_encode(blob.as_bytes())),
+        Value::Offset(offset) => write!(output, "{{\"offset\":{offset}}}"),
+        Value::Ptr(ptr) => write!(output, "{{\"ptr\":{ptr}}}"),
+    }
+}
+
+
+fn write_string(output: &mut impl Write, str: &str) -> io::Result<()> {
+    write!(output, "\"")?;
+    for c in str.chars() {
+        match c {
+            '"' => write!(output, "\\\"")?,
+            '\\' => write!(output, "\\\\")?,
+            '\n' => write!(output, "\\n")?,
+            '\r' => write!(output, "\\r")?,
+            '\t' => write!(output, "\\t")?,
+            c if c.is_control() => write!(output, "\\u{:04x}", c as u32)?,
+            c => write!(output, "{c}")?,
+        }
+    }
+    write!(output, "\"")
+}
+
+
+pub(super) fn read_call(input: &mut impl BufRead) -> io::Result<Option<Call>> {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        if !line.trim().is_empty() {
+            break;
+        }
+    }
+
+    let json = Parser { input: line.as_bytes(), pos: 0 }.parse()?;
+    let name = match json.get("fn") {
+        Some(Json::String(name)) => name.clone(),
+        _ => return Err(invalid_data("call is missing its function name")),
+    };
+
+    let args = match json.get("args") {
+        Some(Json::Array(args)) => args.iter().map(to_value).collect::<io::Result<Vec<_>>>()?,
+        _ => return Err(invalid_data(format!("call to {name} is missing its arguments"))),
+    };
+
+    let ret = json.get("ret").map(to_value).transpose()?;
+    Ok(Some(Call { name, args, ret }))
+}
+
+
+fn to_value(json: &Json) -> io::Result<Value> {
+    let Json::Object(fields) = json else {
+        return Err(invalid_data("values should be objects"));
+    };
+
+    let [(kind, value)] = &fields[..] else {
+        return Err(invalid_data("values should have exactly one field"));
+    };
+
+    let number = |json: &Json| match json {
+        Json::Number(num) => Ok(num.clone()),
+        _ => Err(invalid_data(format!("`{kind}` value should be a number"))),
+    };
+
+    let parse_err = |_| invalid_data(format!("invalid `{kind}` value"));
+    Ok(match (kind.as_str(), value) {
+        ("enum", value) => Value::Enum(number(value)?.parse().map_err(parse_err)?),
+        ("bits", value) => Value::Bitfield(number(value)?.parse().map_err(parse_err)?),
+        ("bool", Json::Bool(value)) => Value::Bool(*value),
+        ("int", value) => Value::Int(number(value)?.parse().map_err(parse_err)?),
+        ("uint", value) => Value::UInt(number(value)?.parse().map_err(parse_err)?),
+        ("float", Json::String(value)) => match value.as_str() {
+            "NaN" => Value::Float(f64::NAN),
+            "inf" => Value::Float(f64::INFINITY),
+            "-inf" => Value::Float(f64::NEG_INFINITY),
+            _ => return Err(invalid_data(format!("invalid float `{value}`"))),
+        },
+        ("float", value) => Value::Float(number(value)?.parse().map_err(|_| invalid_data("invalid `float` value"))?),
+        ("str", Json::String(str)) => Value::Str(str.clone()),
+        ("blob", Json::String(data)) => Value::Blob(base64_decode(data)?),
+        ("offset", value) => Value::Offset(number(value)?.parse().map_err(parse_err)?),
+        ("ptr", value) => Value::Ptr(number(value)?.parse().map_err(parse_err)?),
+        _ => return Err(invalid_data(format!("invalid `{kind}` value"))),
+    })
+}
+
+
+/// A parsed JSON value. Numbers are kept as text, so that they can be parsed as whichever type they need to be.
+#[derive(Debug)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Finds a field of an object.
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    /// Parses a single value that makes up the whole input.
+    fn parse(mut self) -> io::Result<Json> {
+        let value = self.value()?;
+        self.skip_whitespace();
+        if self.pos < self.input.len() {
+            return Err(self.error("trailing characters"));
+        }
+        Ok(value)
+    }
+
+    fn error(&self, message: &str) -> io::Error {
+        invalid_data(format!("invalid JSON at column {}: {message}", self.pos + 1))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.input.get(self.pos).is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> io::Result<()> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", byte as char)))
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: Json) -> io::Result<Json> {
+        if self.input[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error("unknown literal"))
+        }
+    }
+
+    fn value(&mut self) -> io::Result<Json> {
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(b'n') => self.literal("null", Json::Null),
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.pos;
+                while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.input.get(self.pos) {
+                    self.pos += 1;
+                }
+                // Numbers are only ever made of ASCII characters
+                Ok(Json::Number(String::from_utf8_lossy(&self.input[start..self.pos]).into_owned()))
+            },
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn object(&mut self) -> io::Result<Json> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(fields));
+        }
+
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected a key"));
+            }
+
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.push((key, self.value()?));
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(fields));
+                },
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> io::Result<Json> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(items));
+                },
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        self.expect(b'"')?;
+        let mut bytes = Vec::new();
+        loop {
+            let Some(&byte) = self.input.get(self.pos) else {
+                return Err(self.error("unterminated string"));
+            };
+            self.pos += 1;
+
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let Some(&escape) = self.input.get(self.pos) else {
+                        return Err(self.error("unterminated string"));
+                    };
+                    self.pos += 1;
+
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\x08',
+                        b'f' => '\x0C',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                },
+                byte => bytes.push(byte),
+            }
+        }
+
+        String::from_utf8(bytes).map_err(|_| self.error("string is not valid UTF-8"))
+    }
+
+    /// Parses the rest of a `\uXXXX` escape, including the second half of a surrogate pair.
+    fn unicode_escape(&mut self) -> io::Result<char> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if !self.input[self.pos..].starts_with(b"\\u") {
+                return Err(self.error("unpaired surrogate"));
+            }
+            self.pos += 2;
+            let low = self.hex4()?;
+            0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+        } else {
+            high
+        };
+
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex4(&mut self) -> io::Result<u32> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| self.error("unterminated escape"))?;
+        let digits = std::str::from_utf8(digits).map_err(|_| self.error("invalid unicode escape"))?;
+        let value = u32::from_str_radix(digits, 16).map_err(|_| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(value)
+    }
+}
+
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn base64_decode(str: &str) -> io::Result<Blob> {
+    let digits = str.trim_end_matches('=').as_bytes();
+    let mut blob = Blob::zeroed(digits.len() * 3 / 4);
+    let bytes = blob.as_bytes_mut();
+
+    for (i, chunk) in digits.chunks(4).enumerate() {
+        let mut n = 0;
+        for (j, &digit) in chunk.iter().enumerate() {
+            let value = match digit {
+                b'A'..=b'Z' => digit - b'A',
+                b'a'..=b'z' => digit - b'a' + 26,
+                b'0'..=b'9' => digit - b'0' + 52,
+                b'+' => 62,
+                b'/' => 63,
+                _ => return Err(invalid_data("invalid base64 in blob")),
+            };
+            n |= (value as u32) << (18 - 6 * j);
+        }
+
+        for (j, byte) in bytes[i * 3..].iter_mut().take(chunk.len() - 1).enumerate() {
+            *byte = (n >> (16 - 8 * j)) as u8;
+        }
+    }
+
+    Ok(blob)
+}
@@ -0,0 +1,612 @@
+//! Tracing of OpenGL calls to a file, and replaying them later. Only available with the `trace` feature.
+//!
+//! While a trace is running (see [`GLContext::start_trace`]), every OpenGL call made through a context is written to
+//! the trace along with its arguments and return value. Data that OpenGL reads through pointers, like buffer contents,
+//! texture uploads, uniform arrays, and shader sources, is copied into the trace, so that it can be re-issued later
+//! without the program that made the calls.
+//!
+//! Traces are written either as a compact binary file or as JSON-lines (one call per line), which is larger but easy to
+//! read, grep, and diff. Either kind can be read back with a [`TraceReader`], printed with [`Call`]'s `Display`
+//! implementation, and re-issued against another context with a [`Replayer`].
+//!
+//! ```ignore
+//! gl.start_trace(File::create("frame.gltrace")?, TraceFormat::Binary);
+//! draw_frame(&gl);
+//! gl.stop_trace()?;
+//! ```
+//!
+//! # Limitations
+//!
+//! Tracing records what was passed to OpenGL, not what OpenGL did with it, so a few things can't be replayed exactly:
+//!
+//! - Writes through mapped buffers are not captured, and mapping functions are skipped on replay;
+//! - Queries (`glGet*`, `glIs*`, and the like) are recorded, but skipped on replay, since their results go nowhere;
+//! - Uniform and attribute locations are replayed as they were recorded, so traces should be replayed on a driver that
+//!   assigns locations the same way (usually, the same driver that recorded them); and
+//! - Calls that pass other pointers to client memory, like `glTransformFeedbackVaryings`, can't be replayed.
+//!
+//! Object names, on the other hand, don't need to match: names created during a replay are mapped to the ones that were
+//! recorded.
+
+mod binary;
+mod json;
+mod replay;
+#[cfg(test)] mod tests;
+
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::fmt;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+
+pub use self::replay::{ReplayError, Replayer};
+use crate::funcs::textures::PixelStore;
+use crate::raw::types::*;
+use crate::raw::*;
+use crate::GLContext;
+
+
+/// The file formats that a trace can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TraceFormat {
+    /// A compact binary format, with payloads stored as raw bytes.
+    Binary,
+    /// One JSON object per line, with payloads stored as base64. Easier to inspect, but much larger.
+    JsonLines,
+}
+
+
+/// A single recorded argument or return value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A `GLenum`.
+    Enum(GLenum),
+    /// A `GLbitfield`.
+    Bitfield(GLbitfield),
+    /// A `GLboolean`.
+    Bool(bool),
+    /// Any signed integer type, including sizes and offsets.
+    Int(i64),
+    /// Any unsigned integer type, including object names.
+    UInt(u64),
+    /// A `GLfloat` or `GLdouble`.
+    Float(f64),
+    /// A string, such as a uniform name or an object label.
+    Str(String),
+    /// Data that was read through a pointer, such as the contents of a buffer.
+    Blob(Blob),
+    /// A pointer that OpenGL treats as an offset into a bound buffer, like the `indices` of `glDrawElements`.
+    Offset(u64),
+    /// Any other pointer, including function pointers and syncs. Only its address is recorded.
+    Ptr(u64),
+}
+
+impl Value {
+    /// Gets this value as a signed integer, converting it if it is any other integer type.
+    pub fn to_i64(&self) -> Option<i64> {
+        match *self {
+            Value::Enum(value) | Value::Bitfield(value) => Some(value as i64),
+            Value::Bool(value) => Some(value as i64),
+            Value::Int(value) => Some(value),
+            Value::UInt(value) | Value::Offset(value) | Value::Ptr(value) => Some(value as i64),
+            _ => None,
+        }
+    }
+
+    /// Gets this value as an unsigned integer, converting it if it is any other integer type.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_i64().map(|value| value as u64)
+    }
+
+    /// Gets this value as a floating-point number.
+    pub fn to_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Enum(value) => match generated::enum_name(*value) {
+                Some(name) => write!(f, "{name}"),
+                None => write!(f, "{value:#06X}"),
+            },
+            Value::Bitfield(bits) => write!(f, "{bits:#X}"),
+            Value::Bool(true) => write!(f, "GL_TRUE"),
+            Value::Bool(false) => write!(f, "GL_FALSE"),
+            Value::Int(value) => write!(f, "{value}"),
+            Value::UInt(value) => write!(f, "{value}"),
+            Value::Float(value) => write!(f, "{value}"),
+            Value::Str(str) => write!(f, "{str:?}"),
+            Value::Blob(blob) => write!(f, "{blob}"),
+            Value::Offset(offset) => write!(f, "+{offset}"),
+            // Actual addresses change from run to run, so they aren't useful to print
+            Value::Ptr(0) => write!(f, "NULL"),
+            Value::Ptr(_) => write!(f, "<pointer>"),
+        }
+    }
+}
+
+
+/// A block of data that was read through a pointer.
+///
+/// Blobs are stored in 8-byte words, so that replaying can hand them back to OpenGL as arrays of any type without
+/// copying them again.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Blob {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Blob {
+    /// Copies a byte slice into a new blob.
+    pub fn new(bytes: &[u8]) -> Self {
+        let mut blob = Self::zeroed(bytes.len());
+        blob.as_bytes_mut().copy_from_slice(bytes);
+        blob
+    }
+
+    /// Creates a blob of `len` zero bytes, to be filled in with [`as_bytes_mut`][Self::as_bytes_mut].
+    fn zeroed(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(8)],
+            len,
+        }
+    }
+
+    /// The contents of this blob.
+    pub fn as_bytes(&self) -> &[u8] {
+        &bytemuck::cast_slice(&self.words)[..self.len]
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut bytemuck::cast_slice_mut(&mut self.words)[..self.len]
+    }
+
+    /// A pointer to the start of this blob, aligned to 8 bytes.
+    pub fn as_ptr(&self) -> *const c_void {
+        self.words.as_ptr().cast()
+    }
+
+    /// A short hash of this blob's contents, so that blobs can be told apart when printed.
+    pub fn hash(&self) -> u32 {
+        // 32-bit FNV-1a: not strong, but stable between runs and platforms, unlike `DefaultHasher`.
+        self.as_bytes()
+            .iter()
+            .fold(0x811c9dc5, |hash, &b| (hash ^ b as u32).wrapping_mul(0x01000193))
+    }
+}
+
+impl fmt::Debug for Blob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Blob({self})")
+    }
+}
+
+impl fmt::Display for Blob {
+    /// Formats this blob like `<1024 bytes, 0x1234abcd>`, with its hash.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{} bytes, {:#010x}>", self.len, self.hash())
+    }
+}
+
+
+/// A single recorded call to an OpenGL function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    /// The name of the function that was called, such as `glBindBuffer`.
+    pub name: String,
+    /// The values of each of its arguments, in order.
+    pub args: Vec<Value>,
+    /// The value it returned, if it returns one.
+    pub ret: Option<Value>,
+}
+
+impl Call {
+    /// The names of this call's parameters, in order, or `None` if it isn't a function that these bindings know.
+    pub fn param_names(&self) -> Option<&'static [&'static str]> {
+        generated::param_names(&self.name)
+    }
+}
+
+impl fmt::Display for Call {
+    /// Formats this call like `glBindBuffer(target: GL_ARRAY_BUFFER, buffer: 1)`, or `glCreateShader(type:
+    /// GL_VERTEX_SHADER) -> 1` for calls that return a value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self.param_names().unwrap_or(&[]);
+
+        write!(f, "{}(", self.name)?;
+        for (i, arg) in self.args.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            match names.get(i) {
+                Some(name) => write!(f, "{sep}{name}: {arg}")?,
+                None => write!(f, "{sep}{arg}")?,
+            }
+        }
+        write!(f, ")")?;
+
+        match &self.ret {
+            Some(ret) => write!(f, " -> {ret}"),
+            None => Ok(()),
+        }
+    }
+}
+
+
+/// Reads the calls in a trace, in either format.
+pub struct TraceReader<R: Read> {
+    input: BufReader<R>,
+    format: TraceFormat,
+    /// Binary traces give each function a number the first time that they call it.
+    functions: HashMap<u16, String>,
+}
+
+impl<R: Read> TraceReader<R> {
+    /// Starts reading a trace, detecting which format it is in.
+    pub fn new(input: R) -> io::Result<Self> {
+        let mut input = BufReader::new(input);
+        let format = if input.fill_buf()?.starts_with(binary::MAGIC) {
+            input.consume(binary::MAGIC.len());
+            TraceFormat::Binary
+        } else {
+            TraceFormat::JsonLines
+        };
+
+        Ok(Self {
+            input,
+            format,
+            functions: HashMap::new(),
+        })
+    }
+
+    /// The format that this trace is in.
+    pub fn format(&self) -> TraceFormat {
+        self.format
+    }
+
+    /// Reads the next call from the trace, or `None` at the end of the trace.
+    pub fn read_call(&mut self) -> io::Result<Option<Call>> {
+        match self.format {
+            TraceFormat::Binary => binary::read_call(&mut self.input, &mut self.functions),
+            TraceFormat::JsonLines => json::read_call(&mut self.input),
+        }
+    }
+}
+
+impl<R: Read> Iterator for TraceReader<R> {
+    type Item = io::Result<Call>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_call().transpose()
+    }
+}
+
+
+/// Creates an error for a trace that couldn't be parsed.
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+
+impl GLContext {
+    /// Starts writing every OpenGL call made through this context to `output`, replacing any trace that was already
+    /// running. See the [`trace`][crate::trace] module for more information.
+    ///
+    /// Since OpenGL calls can't return errors, any errors from writing to `output` stop the trace, and are returned
+    /// from [`stop_trace`][Self::stop_trace].
+    ///
+    /// The current unpack [pixel storage][Self::pixel_store] parameters and `GL_PIXEL_UNPACK_BUFFER` binding are
+    /// recorded at the start of the trace, since they decide how much data texture uploads read. Other state is not, so
+    /// replaying a trace that doesn't set up everything it uses may not draw the same thing.
+    pub fn start_trace(&self, output: impl Write + 'static, format: TraceFormat) {
+        let mut unpack = UNPACK_PARAMETERS.map(|(pname, _)| (pname, 0));
+        let mut buffer = 0;
+        unsafe {
+            for (pname, value) in &mut unpack {
+                self.gl.get_integer_v(*pname, value);
+            }
+            self.gl.get_integer_v(PIXEL_UNPACK_BUFFER_BINDING, &mut buffer);
+        }
+
+        let mut tracer = Tracer::new(Box::new(output), format);
+        tracer.initial_unpack_state(&unpack, buffer as GLuint);
+        self.gl.start_trace(tracer);
+    }
+
+    /// Stops the current trace, if there is one, and flushes it to its output.
+    pub fn stop_trace(&self) -> io::Result<()> {
+        match self.gl.stop_trace() {
+            Some(tracer) => tracer.finish(),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks whether OpenGL calls are currently being traced.
+    pub fn is_tracing(&self) -> bool {
+        self.gl.is_tracing()
+    }
+}
+
+
+// ---------------------------------------------------------------------------------------------------------------------
+// Recording
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// Writes calls to a trace. The generated bindings call into this after every OpenGL function.
+pub(crate) struct Tracer {
+    output: BufWriter<Box<dyn Write>>,
+    format: TraceFormat,
+    functions: HashMap<&'static str, u16>,
+    unpack: PixelStore,
+    /// Whether a buffer is bound to `GL_PIXEL_UNPACK_BUFFER`, in which case pixel pointers are offsets into it.
+    unpack_buffer_bound: bool,
+    /// The first error encountered while writing. Once there is one, nothing else is written.
+    error: Option<io::Error>,
+}
+
+/// The unpack pixel storage parameters, and their initial values.
+const UNPACK_PARAMETERS: [(GLenum, GLint); 6] = [
+    (UNPACK_ALIGNMENT, 4),
+    (UNPACK_ROW_LENGTH, 0),
+    (UNPACK_IMAGE_HEIGHT, 0),
+    (UNPACK_SKIP_PIXELS, 0),
+    (UNPACK_SKIP_ROWS, 0),
+    (UNPACK_SKIP_IMAGES, 0),
+];
+
+impl Tracer {
+    fn new(output: Box<dyn Write>, format: TraceFormat) -> Self {
+        let mut tracer = Self {
+            output: BufWriter::new(output),
+            format,
+            functions: HashMap::new(),
+            unpack: PixelStore::default(),
+            unpack_buffer_bound: false,
+            error: None,
+        };
+
+        if format == TraceFormat::Binary {
+            tracer.write(|output, _| output.write_all(binary::MAGIC));
+        }
+
+        tracer
+    }
+
+    /// Records calls that set up the unpack state the trace starts with, for any parameters that aren't at their
+    /// initial values. Invalid values, which a context can't actually be in, are skipped.
+    fn initial_unpack_state(&mut self, unpack: &[(GLenum, GLint)], buffer: GLuint) {
+        for (&(pname, value), &(_, initial)) in unpack.iter().zip(&UNPACK_PARAMETERS) {
+            if value != initial && PixelStore::is_valid(pname, value.into()) {
+                self.call("glPixelStorei", &[Value::Enum(pname), Value::Int(value.into())], None);
+            }
+        }
+
+        if buffer != 0 {
+            self.call("glBindBuffer", &[Value::Enum(PIXEL_UNPACK_BUFFER), Value::UInt(buffer.into())], None);
+        }
+    }
+
+    /// Flushes the trace, returning the first error that happened while it was being written.
+    fn finish(mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => self.output.flush(),
+        }
+    }
+
+    fn write(
+        &mut self,
+        f: impl FnOnce(&mut BufWriter<Box<dyn Write>>, &mut HashMap<&'static str, u16>) -> io::Result<()>,
+    ) {
+        if self.error.is_none() {
+            if let Err(err) = f(&mut self.output, &mut self.functions) {
+                log::error!("failed to write OpenGL trace, stopping: {err}");
+                self.error = Some(err);
+            }
+        }
+    }
+
+    /// Records a call to an OpenGL function.
+    pub(crate) fn call(&mut self, name: &'static str, args: &[Value], ret: Option<Value>) {
+        self.track_pixel_store(name, args);
+
+        let format = self.format;
+        self.write(|output, functions| match format {
+            TraceFormat::Binary => binary::write_call(output, functions, name, args, ret.as_ref()),
+            TraceFormat::JsonLines => json::write_call(output, name, args, ret.as_ref()),
+        });
+    }
+
+    /// Records data that OpenGL reads as pixels, like the `pixels` of `glTexSubImage2D`, which is either `len` bytes of
+    /// client memory or an offset into the buffer bound to `GL_PIXEL_UNPACK_BUFFER`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `len` bytes if no buffer is bound to `GL_PIXEL_UNPACK_BUFFER`.
+    pub(crate) unsafe fn pixel_blob(&mut self, ptr: *const c_void, len: usize) -> Value {
+        if self.unpack_buffer_bound {
+            Value::Offset(ptr as usize as u64)
+        } else {
+            unsafe { blob(ptr, len) }
+        }
+    }
+
+    /// Records the pixels for a texture upload of the given size, taking the current pixel storage parameters into
+    /// account. `dims` is the number of dimensions of the upload.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to pixel data of the given size, format, and type, laid out as the pixel storage parameters
+    /// say, if no buffer is bound to `GL_PIXEL_UNPACK_BUFFER`.
+    pub(crate) unsafe fn pixels(
+        &mut self,
+        ptr: *const c_void,
+        format: GLenum,
+        ty: GLenum,
+        size: [GLsizei; 3],
+        dims: usize,
+    ) -> Value {
+        let size = size.map(|n| n.max(0) as usize);
+        let len = pixel_layout(format, ty).and_then(|(components, component_size)| {
+            self.unpack.image_size(components * component_size, component_size, size, dims)
+        });
+        match len {
+            Some(len) => unsafe { self.pixel_blob(ptr, len) },
+            None if self.unpack_buffer_bound => Value::Offset(ptr as usize as u64),
+            None => Value::Ptr(ptr as usize as u64),
+        }
+    }
+
+    /// Keeps track of the state that [`pixels`][Self::pixels] needs.
+    fn track_pixel_store(&mut self, name: &str, args: &[Value]) {
+        let (pname, value) = match (name, args) {
+            ("glBindBuffer", [Value::Enum(PIXEL_UNPACK_BUFFER), buffer]) => {
+                self.unpack_buffer_bound = buffer.to_u64() != Some(0);
+                return;
+            },
+            ("glPixelStorei", [Value::Enum(pname), Value::Int(value)]) => (*pname, *value),
+            ("glPixelStoref", [Value::Enum(pname), Value::Float(value)]) => (*pname, value.round() as i64),
+            _ => return,
+        };
+
+        self.unpack.set(pname, value);
+    }
+}
+
+/// Finds the number of components in each pixel of the given format, and the size of each component of the given type.
+/// Packed types have a single component, the size of the whole pixel.
+fn pixel_layout(format: GLenum, ty: GLenum) -> Option<(usize, usize)> {
+    let size = match ty {
+        UNSIGNED_BYTE | BYTE | UNSIGNED_BYTE_3_3_2 | UNSIGNED_BYTE_2_3_3_REV => 1,
+        UNSIGNED_SHORT | SHORT | HALF_FLOAT => 2,
+        UNSIGNED_INT | INT | FLOAT => 4,
+        UNSIGNED_SHORT_5_6_5
+        | UNSIGNED_SHORT_5_6_5_REV
+        | UNSIGNED_SHORT_4_4_4_4
+        | UNSIGNED_SHORT_4_4_4_4_REV
+        | UNSIGNED_SHORT_5_5_5_1
+        | UNSIGNED_SHORT_1_5_5_5_REV => return Some((1, 2)),
+        UNSIGNED_INT_8_8_8_8
+        | UNSIGNED_INT_8_8_8_8_REV
+        | UNSIGNED_INT_10_10_10_2
+        | UNSIGNED_INT_2_10_10_10_REV
+        | UNSIGNED_INT_24_8
+        | UNSIGNED_INT_10F_11F_11F_REV
+        | UNSIGNED_INT_5_9_9_9_REV => return Some((1, 4)),
+        FLOAT_32_UNSIGNED_INT_24_8_REV => return Some((1, 8)),
+        _ => return None,
+    };
+
+    let components = match format {
+        RED | GREEN | BLUE | RED_INTEGER | GREEN_INTEGER | BLUE_INTEGER | DEPTH_COMPONENT | STENCIL_INDEX => 1,
+        RG | RG_INTEGER | DEPTH_STENCIL => 2,
+        RGB | BGR | RGB_INTEGER | BGR_INTEGER => 3,
+        RGBA | BGRA | RGBA_INTEGER | BGRA_INTEGER => 4,
+        _ => return None,
+    };
+
+    Some((components, size))
+}
+
+
+// Helpers for the generated bindings
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// Copies `len` bytes from `ptr` into a [`Value::Blob`]. Null pointers are recorded as [`Value::Ptr`] instead.
+///
+/// # Safety
+///
+/// `ptr` must either be null or valid for reads of `len` bytes.
+pub(crate) unsafe fn blob(ptr: *const c_void, len: usize) -> Value {
+    if ptr.is_null() {
+        Value::Ptr(0)
+    } else {
+        Value::Blob(Blob::new(unsafe { std::slice::from_raw_parts(ptr.cast(), len) }))
+    }
+}
+
+/// Records a NUL-terminated string.
+///
+/// # Safety
+///
+/// `ptr` must either be null or point to a NUL-terminated string.
+pub(crate) unsafe fn c_str(ptr: *const GLchar) -> Value {
+    if ptr.is_null() {
+        Value::Ptr(0)
+    } else {
+        Value::Str(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+    }
+}
+
+/// Records a string with an explicit length, or a NUL-terminated string if `len` is negative.
+///
+/// # Safety
+///
+/// `ptr` must either be null or point to a string of `len` bytes (or a NUL-terminated string if `len` is negative).
+pub(crate) unsafe fn str_with_len(ptr: *const GLchar, len: GLsizei) -> Value {
+    if ptr.is_null() || len < 0 {
+        unsafe { c_str(ptr) }
+    } else {
+        let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len as usize) };
+        Value::Str(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+/// Records the strings passed to `glShaderSource` and `glCreateShaderProgramv` as a single string. The generated
+/// bindings record those calls as if all of their strings were passed as one.
+///
+/// # Safety
+///
+/// `strings` must point to `count` strings, which are NUL-terminated if `lengths` is null or their length is negative.
+pub(crate) unsafe fn shader_sources(count: GLsizei, strings: *const *const GLchar, lengths: *const GLint) -> Value {
+    let mut source = String::new();
+    for i in 0..count.max(0) as usize {
+        let len = if lengths.is_null() { -1 } else { unsafe { *lengths.add(i) } };
+        if let Value::Str(str) = unsafe { str_with_len(*strings.add(i), len) } {
+            source.push_str(&str);
+        }
+    }
+
+    Value::Str(source)
+}
+
+/// The number of bytes in a single pixel of the given format and type, such as the clear value of
+/// `glClearTexImage`.
+#[cfg(feature = "gl45")]
+pub(crate) fn pixel_size(format: GLenum, ty: GLenum) -> usize {
+    pixel_layout(format, ty).map_or(0, |(components, size)| components * size)
+}
+
+/// The number of values passed to `glClearBuffer*v` for the given buffer.
+pub(crate) fn clear_value_len(buffer: GLenum) -> usize {
+    if buffer == COLOR {
+        4
+    } else {
+        1
+    }
+}
+
+/// The number of values passed to functions like `glTexParameterfv` and `glPatchParameterfv` for the given parameter.
+pub(crate) fn parameter_len(pname: GLenum) -> usize {
+    match pname {
+        TEXTURE_BORDER_COLOR | TEXTURE_SWIZZLE_RGBA => 4,
+        #[cfg(feature = "gl41")]
+        PATCH_DEFAULT_OUTER_LEVEL => 4,
+        #[cfg(feature = "gl41")]
+        PATCH_DEFAULT_INNER_LEVEL => 2,
+        _ => 1,
+    }
+}
+
+
+// Include the parameter names, enum names, and replay function from the build script
+#[allow(clippy::unnecessary_cast)]
+mod generated {
+    use super::replay::*;
+    use super::*;
+    use crate::raw::GLPointers;
+
+    include!(concat!(env!("OUT_DIR"), "/trace.rs"));
+}
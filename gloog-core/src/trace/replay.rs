@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::ffi::{c_void, CString};
+use std::fmt;
+
+use super::{generated, Call, Value};
+use crate::raw::types::*;
+#[cfg(feature = "gl45")]
+use crate::raw::{
+    BUFFER,
+    FRAMEBUFFER,
+    PROGRAM,
+    PROGRAM_PIPELINE,
+    QUERY,
+    RENDERBUFFER,
+    SAMPLER,
+    SHADER,
+    TEXTURE,
+    TRANSFORM_FEEDBACK,
+    VERTEX_ARRAY,
+};
+use crate::GLContext;
+
+
+/// Re-issues recorded calls against a context.
+///
+/// Objects created during the replay are given whatever names the context hands out, so the replayer keeps track of
+/// which new name belongs to each recorded one, and swaps them in as it goes. Names that weren't created during the
+/// replay are passed through as they are.
+///
+/// ```ignore
+/// let mut replayer = Replayer::new(&gl);
+/// for call in TraceReader::new(File::open("frame.gltrace")?)? {
+///     // SAFETY: this trace was recorded by this program
+///     unsafe { replayer.replay(&call?)? };
+/// }
+/// ```
+pub struct Replayer<'gl> {
+    gl: &'gl GLContext,
+    names: Names,
+}
+
+impl<'gl> Replayer<'gl> {
+    /// Creates a replayer that issues calls to the given context.
+    pub fn new(gl: &'gl GLContext) -> Self {
+        Self { gl, names: Names::default() }
+    }
+
+    /// Re-issues a single call.
+    ///
+    /// Calls that only query OpenGL state, and ones whose effects can't be recorded (like mapping a buffer) are
+    /// skipped, in which case this returns `Ok(false)`.
+    ///
+    /// # Safety
+    ///
+    /// Calls are passed to OpenGL as they were recorded, so they can only be replayed if they were well-formed when
+    /// they were recorded, and haven't been tampered with since. In particular:
+    ///
+    /// - Every array, string, and blob must be at least as long as the other arguments say it is (for example, the
+    ///   `value` blob of `glUniform4fv` must hold `count` vectors); and
+    /// - Every [offset][Value::Offset] must be valid for the buffer that is bound to its target when it is replayed,
+    ///   since offsets are passed as pointers, and are read as client memory if no buffer is bound.
+    ///
+    /// Traces written by [`GLContext::start_trace`] meet these requirements as long as the calls that were traced were
+    /// valid themselves. Traces from untrusted sources do not.
+    pub unsafe fn replay(&mut self, call: &Call) -> Result<bool, ReplayError> {
+        unsafe { generated::replay(&self.gl.gl, &mut self.names, call) }
+    }
+}
+
+
+/// The reasons that a call may fail to replay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The call is to a function that isn't part of these bindings. It may be from a newer version of OpenGL or an
+    /// extension that wasn't enabled.
+    UnknownFunction(String),
+    /// The call has the wrong number of arguments, or an argument of the wrong type.
+    InvalidArguments(String),
+    /// The call passes a pointer to memory that wasn't recorded in the trace.
+    UnrecordedPointer(String),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFunction(name) => write!(f, "`{name}` is not a known OpenGL function"),
+            Self::InvalidArguments(name) => write!(f, "call to `{name}` has invalid arguments"),
+            Self::UnrecordedPointer(name) => write!(f, "call to `{name}` passes a pointer that was not recorded"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+
+/// The different namespaces of OpenGL object names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum NameKind {
+    Buffer,
+    Framebuffer,
+    Program,
+    #[cfg(feature = "gl41")]
+    ProgramPipeline,
+    Query,
+    Renderbuffer,
+    Sampler,
+    Shader,
+    Sync,
+    Texture,
+    #[cfg(feature = "gl41")]
+    TransformFeedback,
+    VertexArray,
+}
+
+#[cfg(feature = "gl45")]
+impl NameKind {
+    /// The kind of object identified by one of the enums passed to `glObjectLabel`, if it has a name that can be
+    /// remapped.
+    fn object(identifier: GLenum) -> Option<Self> {
+        Some(match identifier {
+            BUFFER => NameKind::Buffer,
+            FRAMEBUFFER => NameKind::Framebuffer,
+            PROGRAM => NameKind::Program,
+            PROGRAM_PIPELINE => NameKind::ProgramPipeline,
+            QUERY => NameKind::Query,
+            RENDERBUFFER => NameKind::Renderbuffer,
+            SAMPLER => NameKind::Sampler,
+            SHADER => NameKind::Shader,
+            TEXTURE => NameKind::Texture,
+            TRANSFORM_FEEDBACK => NameKind::TransformFeedback,
+            VERTEX_ARRAY => NameKind::VertexArray,
+            _ => return None,
+        })
+    }
+}
+
+
+/// Maps the object names in a trace to the ones created while replaying it.
+#[derive(Debug, Default)]
+pub(super) struct Names {
+    map: HashMap<(NameKind, u64), u64>,
+}
+
+impl Names {
+    /// Finds the new name for a recorded one.
+    pub(super) fn get(&self, kind: NameKind, name: u64) -> u64 {
+        self.map.get(&(kind, name)).copied().unwrap_or(name)
+    }
+
+    /// Finds the new name for an image passed to `glCopyImageSubData`, which is either a texture or a renderbuffer
+    /// depending on its target.
+    #[cfg(feature = "gl45")]
+    pub(super) fn get_image(&self, target: GLenum, name: u64) -> u64 {
+        let kind = if target == RENDERBUFFER { NameKind::Renderbuffer } else { NameKind::Texture };
+        self.get(kind, name)
+    }
+
+    /// Finds the new name for an object passed to `glObjectLabel`, based on the enum that identifies its kind.
+    #[cfg(feature = "gl45")]
+    pub(super) fn get_object(&self, identifier: GLenum, name: u64) -> u64 {
+        match NameKind::object(identifier) {
+            Some(kind) => self.get(kind, name),
+            None => name,
+        }
+    }
+
+    /// Remembers the new name for a recorded one.
+    pub(super) fn insert(&mut self, kind: NameKind, recorded: Option<&Value>, new: u64) {
+        if let Some(recorded) = recorded.and_then(Value::to_u64) {
+            self.map.insert((kind, recorded), new);
+        }
+    }
+
+    /// Remembers the new names for an array of recorded ones, from a `glGen*` or `glCreate*` function.
+    pub(super) fn insert_all(&mut self, kind: NameKind, recorded: &Value, new: &[GLuint]) {
+        if let Value::Blob(recorded) = recorded {
+            let recorded = recorded.as_bytes().chunks_exact(4);
+            for (recorded, &new) in recorded.zip(new) {
+                let recorded = GLuint::from_ne_bytes(recorded.try_into().unwrap());
+                self.map.insert((kind, recorded as u64), new as u64);
+            }
+        }
+    }
+
+    /// Finds the new names for an array of recorded ones, like the ones passed to `glDeleteBuffers`. Returns `None` if
+    /// the array was null.
+    pub(super) fn get_all(
+        &self,
+        kind: NameKind,
+        recorded: &Value,
+        function: &str,
+    ) -> Result<Option<Vec<GLuint>>, ReplayError> {
+        match recorded {
+            Value::Blob(recorded) => Ok(Some(
+                recorded
+                    .as_bytes()
+                    .chunks_exact(4)
+                    .map(|name| self.get(kind, GLuint::from_ne_bytes(name.try_into().unwrap()) as u64) as GLuint)
+                    .collect(),
+            )),
+            Value::Ptr(0) => Ok(None),
+            _ => Err(ReplayError::UnrecordedPointer(function.to_string())),
+        }
+    }
+}
+
+
+// Helpers for the generated replay function
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// Gets a call's arguments, checking that there are the right number of them.
+pub(super) fn args<const N: usize>(call: &Call) -> Result<&[Value; N], ReplayError> {
+    call.args
+        .as_slice()
+        .try_into()
+        .map_err(|_| ReplayError::InvalidArguments(call.name.clone()))
+}
+
+pub(super) fn int(value: &Value, function: &str) -> Result<i64, ReplayError> {
+    value
+        .to_i64()
+        .ok_or_else(|| ReplayError::InvalidArguments(function.to_string()))
+}
+
+pub(super) fn uint(value: &Value, function: &str) -> Result<u64, ReplayError> {
+    value
+        .to_u64()
+        .ok_or_else(|| ReplayError::InvalidArguments(function.to_string()))
+}
+
+pub(super) fn float(value: &Value, function: &str) -> Result<f64, ReplayError> {
+    value
+        .to_f64()
+        .ok_or_else(|| ReplayError::InvalidArguments(function.to_string()))
+}
+
+/// Turns a recorded pointer back into one that can be passed to OpenGL: blobs point to their data, and offsets are
+/// passed as they were.
+pub(super) fn ptr(value: &Value, function: &str) -> Result<*const c_void, ReplayError> {
+    match value {
+        Value::Blob(blob) => Ok(blob.as_ptr()),
+        Value::Offset(offset) => Ok(*offset as usize as *const c_void),
+        Value::Ptr(0) => Ok(std::ptr::null()),
+        Value::Ptr(_) => Err(ReplayError::UnrecordedPointer(function.to_string())),
+        _ => Err(ReplayError::InvalidArguments(function.to_string())),
+    }
+}
+
+/// Turns a recorded string back into a NUL-terminated one.
+pub(super) fn c_string(value: &Value, function: &str) -> Result<Option<CString>, ReplayError> {
+    match value {
+        Value::Str(str) => CString::new(str.as_str())
+            .map(Some)
+            .map_err(|_| ReplayError::InvalidArguments(function.to_string())),
+        Value::Ptr(0) => Ok(None),
+        _ => Err(ReplayError::InvalidArguments(function.to_string())),
+    }
+}
+
+/// Gets a pointer to an optional string or array, or null if there isn't one.
+pub(super) fn opt_ptr<T: AsPtr>(value: &Option<T>) -> *const T::Target {
+    value.as_ref().map_or(std::ptr::null(), AsPtr::as_ptr)
+}
+
+pub(super) trait AsPtr {
+    type Target;
+    fn as_ptr(&self) -> *const Self::Target;
+}
+
+impl AsPtr for CString {
+    type Target = GLchar;
+
+    fn as_ptr(&self) -> *const GLchar {
+        self.as_c_str().as_ptr()
+    }
+}
+
+impl<T> AsPtr for Vec<T> {
+    type Target = T;
+
+    fn as_ptr(&self) -> *const T {
+        self.as_slice().as_ptr()
+    }
+}
@@ -0,0 +1,275 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::*;
+use crate::mock::{self, Arg};
+
+/// A trace output that can still be read after the context has taken ownership of it.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Traces a few calls against the mock in the given format, and reads them back.
+fn record(format: TraceFormat) -> Vec<Call> {
+    let gl = mock::context();
+    let output = SharedBuffer::default();
+    gl.start_trace(output.clone(), format);
+    assert!(gl.is_tracing());
+
+    let data = [1.0f32, 2.0, 3.0];
+    let mut buffers = [0; 2];
+    unsafe {
+        gl.gl.gen_buffers(2, buffers.as_mut_ptr());
+        gl.gl.bind_buffer(ARRAY_BUFFER, buffers[1]);
+        gl.gl.buffer_data(ARRAY_BUFFER, 12, data.as_ptr().cast(), STATIC_DRAW);
+        gl.gl.draw_arrays(TRIANGLES, 0, 3);
+    }
+
+    gl.stop_trace().unwrap();
+    assert!(!gl.is_tracing());
+
+    let bytes = output.0.take();
+    let reader = TraceReader::new(bytes.as_slice()).unwrap();
+    assert_eq!(reader.format(), format);
+    reader
+        .filter(|call| call.as_ref().map_or(true, |call| call.name != "glGetError"))
+        .collect::<io::Result<_>>()
+        .unwrap()
+}
+
+fn expected_calls() -> Vec<Call> {
+    let call = |name: &str, args: Vec<Value>| Call {
+        name: name.to_string(),
+        args,
+        ret: None,
+    };
+    let data = [1.0f32, 2.0, 3.0];
+    vec![
+        call("glGenBuffers", vec![Value::Int(2), Value::Blob(Blob::new(bytemuck::cast_slice(&[1u32, 2])))]),
+        call("glBindBuffer", vec![Value::Enum(ARRAY_BUFFER), Value::UInt(2)]),
+        call("glBufferData", vec![
+            Value::Enum(ARRAY_BUFFER),
+            Value::Int(12),
+            Value::Blob(Blob::new(bytemuck::cast_slice(&data))),
+            Value::Enum(STATIC_DRAW),
+        ]),
+        call("glDrawArrays", vec![Value::Enum(TRIANGLES), Value::Int(0), Value::Int(3)]),
+    ]
+}
+
+
+#[test]
+fn binary_round_trip() {
+    assert_eq!(record(TraceFormat::Binary), expected_calls());
+}
+
+
+#[test]
+fn json_round_trip() {
+    assert_eq!(record(TraceFormat::JsonLines), expected_calls());
+}
+
+
+#[test]
+fn json_values() {
+    let args = vec![
+        Value::Str("quote \" backslash \\ newline \n unicode é".to_string()),
+        Value::Float(f64::INFINITY),
+        Value::Float(-0.5),
+        Value::Int(-7),
+        Value::Offset(64),
+        Value::Ptr(0),
+        Value::Bitfield(0x4100),
+        Value::Bool(true),
+        Value::Blob(Blob::new(b"hello, world")),
+    ];
+
+    let mut bytes = Vec::new();
+    json::write_call(&mut bytes, "glFake", &args, Some(&Value::UInt(u64::MAX))).unwrap();
+    let call = json::read_call(&mut bytes.as_slice()).unwrap().unwrap();
+    assert_eq!(call.args, args);
+    assert_eq!(call.ret, Some(Value::UInt(u64::MAX)));
+}
+
+
+#[test]
+fn corrupt_traces_are_errors() {
+    // A blob that claims to be far longer than the rest of the trace
+    let mut bytes = binary::MAGIC.to_vec();
+    bytes.extend([1, 0, 0, 6]);
+    bytes.extend(b"glFake");
+    bytes.extend([2, 0, 0, 1, 7]);
+    bytes.extend(u64::MAX.to_le_bytes());
+    bytes.extend([0; 16]);
+
+    let err = TraceReader::new(bytes.as_slice()).unwrap().next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    // A call to a function that was never given an ID
+    let mut bytes = binary::MAGIC.to_vec();
+    bytes.extend([2, 9, 0, 0, 0]);
+
+    let err = TraceReader::new(bytes.as_slice()).unwrap().next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+
+#[test]
+fn call_display() {
+    let calls = expected_calls();
+    assert_eq!(calls[1].to_string(), "glBindBuffer(target: GL_ARRAY_BUFFER, buffer: 2)");
+    assert_eq!(
+        calls[2].to_string(),
+        format!(
+            "glBufferData(target: GL_ARRAY_BUFFER, size: 12, data: <12 bytes, {:#010x}>, usage: GL_STATIC_DRAW)",
+            {
+                let Value::Blob(blob) = &calls[2].args[2] else {
+                    unreachable!()
+                };
+                blob.hash()
+            }
+        )
+    );
+
+    let call = Call {
+        name: "glCreateShader".to_string(),
+        args: vec![Value::Enum(VERTEX_SHADER)],
+        ret: Some(Value::UInt(1)),
+    };
+    assert_eq!(call.to_string(), "glCreateShader(ty: GL_VERTEX_SHADER) -> 1");
+}
+
+
+#[test]
+fn pixels_respect_unpack_alignment() {
+    let gl = mock::context();
+    let output = SharedBuffer::default();
+    gl.start_trace(output.clone(), TraceFormat::Binary);
+
+    // Three RGB pixels make nine-byte rows, which are padded to twelve bytes, except for the last one
+    let pixels = [0u8; 21];
+    unsafe {
+        gl.gl
+            .tex_sub_image_2_d(TEXTURE_2D, 0, 0, 0, 3, 2, RGB, UNSIGNED_BYTE, pixels.as_ptr().cast())
+    };
+    gl.stop_trace().unwrap();
+
+    let bytes = output.0.take();
+    let call = TraceReader::new(bytes.as_slice()).unwrap().next().unwrap().unwrap();
+    assert_eq!(call.args[8], Value::Blob(Blob::new(&pixels)));
+}
+
+
+#[test]
+fn pixel_store_starts_from_current_state() {
+    let gl = mock::context();
+    let read = |output: SharedBuffer| {
+        let bytes = output.0.take();
+        TraceReader::new(bytes.as_slice())
+            .unwrap()
+            .filter(|call| call.as_ref().map_or(true, |call| call.name != "glGetError"))
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap()
+    };
+
+    mock::set_query_result(UNPACK_ALIGNMENT, &[1]);
+    mock::set_query_result(UNPACK_ROW_LENGTH, &[-4]);
+    let output = SharedBuffer::default();
+    gl.start_trace(output.clone(), TraceFormat::Binary);
+
+    // Rows are tightly packed, so two rows of three RGB pixels are eighteen bytes; negative row lengths, from before
+    // the trace or during it, are ignored
+    let pixels = [0u8; 18];
+    unsafe {
+        gl.gl.pixel_store_i(UNPACK_ROW_LENGTH, -1);
+        gl.gl
+            .tex_sub_image_2_d(TEXTURE_2D, 0, 0, 0, 3, 2, RGB, UNSIGNED_BYTE, pixels.as_ptr().cast());
+    }
+    gl.stop_trace().unwrap();
+
+    let calls = read(output);
+    assert_eq!(calls[0].name, "glPixelStorei");
+    assert_eq!(calls[0].args, [Value::Enum(UNPACK_ALIGNMENT), Value::Int(1)]);
+    assert_eq!(calls[2].args[8], Value::Blob(Blob::new(&pixels)));
+
+    // With a buffer already bound, pixels are offsets into it instead
+    mock::set_query_result(PIXEL_UNPACK_BUFFER_BINDING, &[3]);
+    let output = SharedBuffer::default();
+    gl.start_trace(output.clone(), TraceFormat::Binary);
+    unsafe {
+        gl.gl
+            .tex_sub_image_2_d(TEXTURE_2D, 0, 0, 0, 3, 2, RGB, UNSIGNED_BYTE, 16 as *const _)
+    };
+    gl.stop_trace().unwrap();
+
+    let calls = read(output);
+    assert_eq!(calls[1].args, [Value::Enum(PIXEL_UNPACK_BUFFER), Value::UInt(3)]);
+    assert_eq!(calls[2].args[8], Value::Offset(16));
+}
+
+
+#[test]
+fn replay_remaps_names() {
+    let calls = record(TraceFormat::Binary);
+
+    // Use up the names that were recorded, so that the replay gets different ones
+    let gl = mock::context();
+    let mut taken = [0; 3];
+    unsafe { gl.gl.gen_buffers(3, taken.as_mut_ptr()) };
+    mock::take_calls();
+
+    let mut replayer = Replayer::new(&gl);
+    for call in &calls {
+        assert_eq!(unsafe { replayer.replay(call) }, Ok(true));
+    }
+
+    let replayed = mock::take_calls()
+        .into_iter()
+        .filter(|call| call.name != "glGetError")
+        .collect::<Vec<_>>();
+    let names = replayed.iter().map(|call| call.name).collect::<Vec<_>>();
+    assert_eq!(names, ["glGenBuffers", "glBindBuffer", "glBufferData", "glDrawArrays"]);
+
+    // The recorded buffer 2 was created as buffer 5
+    assert_eq!(replayed[1].arg("buffer"), Some(&Arg::UInt(5)));
+}
+
+
+#[test]
+fn replay_skips_queries() {
+    let gl = mock::context();
+    let mut replayer = Replayer::new(&gl);
+
+    let query = Call {
+        name: "glGetIntegerv".to_string(),
+        args: vec![Value::Enum(MAX_TEXTURE_SIZE), Value::Ptr(0x1234)],
+        ret: None,
+    };
+    assert_eq!(unsafe { replayer.replay(&query) }, Ok(false));
+
+    let unknown = Call {
+        name: "glFake".to_string(),
+        args: vec![],
+        ret: None,
+    };
+    assert_eq!(unsafe { replayer.replay(&unknown) }, Err(ReplayError::UnknownFunction("glFake".to_string())));
+
+    let wrong_args = Call {
+        name: "glDrawArrays".to_string(),
+        args: vec![],
+        ret: None,
+    };
+    assert_eq!(
+        unsafe { replayer.replay(&wrong_args) },
+        Err(ReplayError::InvalidArguments("glDrawArrays".to_string()))
+    );
+}
@@ -0,0 +1,125 @@
+//! A snapshot of what the current OpenGL implementation supports.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+
+/// The version, driver information, extensions, and important limits of an OpenGL implementation, as returned by
+/// [`GLContext::capabilities`].
+///
+/// This type's [`Display`][fmt::Display] implementation prints a short multi-line report, suitable for logging on
+/// startup. The full list of extensions is only included by the alternate form (`{:#}`).
+///
+/// [`GLContext::capabilities`]: crate::GLContext::capabilities
+#[derive(Debug, Clone, PartialEq)]
+pub struct Capabilities {
+    /// The major and minor version numbers of the context.
+    pub version: (u32, u32),
+    /// The full version string, which often includes extra information about the driver.
+    pub version_string: String,
+    /// The version string of the highest supported version of GLSL.
+    pub shading_language_version: String,
+    /// The company responsible for the implementation.
+    pub vendor: String,
+    /// The name of the renderer, usually the name of the GPU.
+    pub renderer: String,
+    /// The names of all supported extensions.
+    pub extensions: BTreeSet<String>,
+    /// Implementation-dependent limits.
+    pub limits: Limits,
+}
+
+impl Capabilities {
+    /// Checks if the context's version is at least the given version.
+    pub fn is_version_at_least(&self, major: u32, minor: u32) -> bool {
+        self.version >= (major, minor)
+    }
+
+    /// Checks if the given extension (such as `"GL_ARB_bindless_texture"`) is supported.
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.contains(name)
+    }
+}
+
+
+/// Implementation-dependent limits that most often affect how an application needs to be structured.
+///
+/// Every limit can also be queried individually through the [`params`][crate::params] module.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    /// The largest width or height of a 1D or 2D texture.
+    pub max_texture_size: usize,
+    /// The largest width, height, or depth of a 3D texture.
+    pub max_3d_texture_size: usize,
+    /// The largest number of layers in an array texture.
+    pub max_array_texture_layers: usize,
+    /// The largest degree of anisotropic filtering.
+    #[cfg(any(feature = "gl46", feature = "ext-texture-filter-anisotropic"))]
+    pub max_texture_max_anisotropy: f32,
+    /// The number of texture units that all shader stages combined can read from.
+    pub max_combined_texture_image_units: usize,
+    /// The number of vertex attributes available to a vertex shader.
+    pub max_vertex_attribs: usize,
+    /// The number of draw buffers that a fragment shader can write to at once.
+    pub max_draw_buffers: usize,
+    /// The number of color attachments in a framebuffer.
+    pub max_color_attachments: usize,
+    /// The largest number of samples in a multisampled texture or renderbuffer.
+    pub max_samples: usize,
+    /// The largest size of a uniform block, in bytes.
+    pub max_uniform_block_size: usize,
+    /// The number of uniform buffer binding points.
+    pub max_uniform_buffer_bindings: usize,
+    /// The alignment, in bytes, of offsets into buffers bound to uniform buffer binding points.
+    pub uniform_buffer_offset_alignment: usize,
+    /// The largest size of a shader storage block, in bytes.
+    #[cfg(feature = "gl45")]
+    pub max_shader_storage_block_size: usize,
+    /// The number of shader storage buffer binding points.
+    #[cfg(feature = "gl45")]
+    pub max_shader_storage_buffer_bindings: usize,
+    /// The alignment, in bytes, of offsets into buffers bound to shader storage buffer binding points.
+    #[cfg(feature = "gl45")]
+    pub shader_storage_buffer_offset_alignment: usize,
+    /// The number of work groups that may be dispatched in each dimension.
+    #[cfg(feature = "gl45")]
+    pub max_compute_work_group_count: [u32; 3],
+    /// The largest size of a compute work group in each dimension.
+    #[cfg(feature = "gl45")]
+    pub max_compute_work_group_size: [u32; 3],
+    /// The longest debug message, in bytes, including its null terminator.
+    #[cfg(feature = "gl45")]
+    pub max_debug_message_length: usize,
+    /// The longest object label, in bytes.
+    #[cfg(feature = "gl45")]
+    pub max_label_length: usize,
+}
+
+
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (major, minor) = self.version;
+        let limits = &self.limits;
+
+        writeln!(f, "OpenGL {major}.{minor} ({})", self.version_string)?;
+        writeln!(f, "  vendor:                   {}", self.vendor)?;
+        writeln!(f, "  renderer:                 {}", self.renderer)?;
+        writeln!(f, "  GLSL version:             {}", self.shading_language_version)?;
+        writeln!(f, "  max texture size:         {}", limits.max_texture_size)?;
+        writeln!(f, "  max texture units:        {}", limits.max_combined_texture_image_units)?;
+        writeln!(f, "  max vertex attributes:    {}", limits.max_vertex_attribs)?;
+        writeln!(f, "  max uniform block size:   {}", limits.max_uniform_block_size)?;
+        writeln!(f, "  UBO offset alignment:     {}", limits.uniform_buffer_offset_alignment)?;
+        #[cfg(feature = "gl45")]
+        writeln!(f, "  max debug message length: {}", limits.max_debug_message_length)?;
+        write!(f, "  extensions:               {}", self.extensions.len())?;
+
+        if f.alternate() {
+            for extension in &self.extensions {
+                write!(f, "\n    {extension}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
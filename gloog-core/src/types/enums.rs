@@ -4,10 +4,13 @@ use crate::macros::gl_enum;
 gl_enum! {
     /// A type of shader used in an OpenGL program.
     pub enum ShaderType {
+        #[cfg(feature = "gl45")]
         Compute => COMPUTE_SHADER,
         Fragment => FRAGMENT_SHADER,
         Geometry => GEOMETRY_SHADER,
+        #[cfg(feature = "gl41")]
         TessellationControl => TESS_CONTROL_SHADER,
+        #[cfg(feature = "gl41")]
         TessellationEvaluation => TESS_EVALUATION_SHADER,
         Vertex => VERTEX_SHADER,
     }
@@ -56,6 +59,7 @@ gl_enum! {
         ArrayBuffer => ARRAY_BUFFER,
 
         /// Buffer target for atomic counter storage.
+        #[cfg(feature = "gl45")]
         AtomicCounterBuffer => ATOMIC_COUNTER_BUFFER,
 
         /// Buffer target for the source of buffer copies.
@@ -65,9 +69,11 @@ gl_enum! {
         CopyWriteBuffer => COPY_WRITE_BUFFER,
 
         /// Buffer target for indirect compute dispatch commands.
+        #[cfg(feature = "gl45")]
         DispatchIndirectBuffer => DISPATCH_INDIRECT_BUFFER,
 
         /// Buffer target for indirect command arguments.
+        #[cfg(feature = "gl41")]
         DrawIndirectBuffer => DRAW_INDIRECT_BUFFER,
 
         /// Buffer target for vertex array indices.
@@ -80,9 +86,11 @@ gl_enum! {
         PixelUnpackBuffer => PIXEL_UNPACK_BUFFER,
 
         /// Buffer target for the query results.
+        #[cfg(feature = "gl45")]
         QueryBuffer => QUERY_BUFFER,
 
         /// Buffer target for read-write storage for shaders.
+        #[cfg(feature = "gl45")]
         ShaderStorageBuffer => SHADER_STORAGE_BUFFER,
 
         /// Buffer target for texture data.
@@ -97,6 +105,26 @@ gl_enum! {
 }
 
 
+gl_enum! {
+    /// Buffer targets that have an array of indexed binding points, which shader interface blocks are linked to.
+    pub enum IndexedBufferTarget {
+        /// Indexed binding points for atomic counter storage.
+        #[cfg(feature = "gl45")]
+        AtomicCounterBuffer => ATOMIC_COUNTER_BUFFER,
+
+        /// Indexed binding points for shader storage blocks.
+        #[cfg(feature = "gl45")]
+        ShaderStorageBuffer => SHADER_STORAGE_BUFFER,
+
+        /// Indexed binding points for transform feedback outputs.
+        TransformFeedbackBuffer => TRANSFORM_FEEDBACK_BUFFER,
+
+        /// Indexed binding points for uniform blocks.
+        UniformBuffer => UNIFORM_BUFFER,
+    }
+}
+
+
 gl_enum! {
     pub enum DrawElementsType {
         UnsignedByte => UNSIGNED_BYTE,
@@ -111,6 +139,7 @@ gl_enum! {
         Float => FLOAT,
         HalfFloat => HALF_FLOAT,
         Double => DOUBLE,
+        #[cfg(feature = "gl41")]
         Fixed => FIXED,
 
         Byte => BYTE,
@@ -180,6 +209,7 @@ gl_enum! {
         Triangles => TRIANGLES,
         TriangleStripAdjacency => TRIANGLE_STRIP_ADJACENCY,
         TrianglesAdjacency => TRIANGLES_ADJACENCY,
+        #[cfg(feature = "gl41")]
         Patches => PATCHES,
     }
 }
@@ -218,12 +248,14 @@ gl_enum! {
         /// If enabled, debug messages are produced by a debug context. When disabled, the debug message log is
         /// silenced. Note that in a non-debug context, very few, if any messages might be produced, even when
         /// `GL_DEBUG_OUTPUT` is enabled.
+        #[cfg(feature = "gl45")]
         DebugOutput => DEBUG_OUTPUT,
 
         /// If enabled, debug messages are produced synchronously by a debug context. If disabled, debug messages may be
         /// produced asynchronously. In particular, they may be delayed relative to the execution of GL commands, and
         /// the debug callback function may be called from a thread other than that in which the commands are executed.
         /// See `glDebugMessageCallback`.
+        #[cfg(feature = "gl45")]
         DebugOutputSynchronous => DEBUG_OUTPUT_SYNCHRONOUS,
 
         /// If enabled, the `-wc ≤ zc ≤ wc` plane equation is ignored by view volume clipping (effectively, there is no
@@ -275,6 +307,7 @@ gl_enum! {
         /// set of generic attribute array elements to the GL will restart the primitive when the index of the vertex is
         /// equal to the fixed primitive index for the specified index type. The fixed index is equal to `2n-1` where
         /// `n` is equal to 8 for `GL_UNSIGNED_BYTE`, 16 for `GL_UNSIGNED_SHORT` and 32 for `GL_UNSIGNED_INT`.
+        #[cfg(feature = "gl45")]
         PrimitiveRestartFixedIndex => PRIMITIVE_RESTART_FIXED_INDEX,
 
         /// If enabled, primitives are discarded after the optional transform feedback stage, but before rasterization.
@@ -295,6 +328,7 @@ gl_enum! {
 
         /// If enabled, the active fragment shader is run once for each covered sample, or at fraction of this rate as
         /// determined by the current value of `GL_MIN_SAMPLE_SHADING_VALUE`. See `glMinSampleShading`.
+        #[cfg(feature = "gl41")]
         SampleShading => SAMPLE_SHADING,
 
         /// If enabled, the sample coverage mask generated for a fragment during rasterization will be ANDed with the
@@ -350,6 +384,7 @@ gl_enum! {
     pub enum IndexedStringName {
         Extensions => EXTENSIONS,
         ShadingLanguageVersion => SHADING_LANGUAGE_VERSION,
+        #[cfg(feature = "gl46")]
         SPIRVExtensions => SPIR_V_EXTENSIONS,
     }
 }
@@ -372,6 +407,7 @@ gl_enum! {
 }
 
 
+#[cfg(feature = "gl45")]
 gl_enum! {
     pub enum DebugSource {
         /// Calls to the OpenGL API.
@@ -389,6 +425,7 @@ gl_enum! {
     }
 }
 
+#[cfg(feature = "gl45")]
 gl_enum! {
     pub enum DebugType {
         /// An error, typically from the API.
@@ -412,6 +449,7 @@ gl_enum! {
     }
 }
 
+#[cfg(feature = "gl45")]
 gl_enum! {
     pub enum DebugSeverity {
         /// Any GL error; dangerous undefined behavior; any shader compiler and linker errors.
@@ -425,12 +463,14 @@ gl_enum! {
     }
 }
 
+#[cfg(feature = "gl45")]
 impl DebugSeverity {
     pub fn log_level(self) -> log::Level {
         self.into()
     }
 }
 
+#[cfg(feature = "gl45")]
 impl Into<log::Level> for DebugSeverity {
     fn into(self) -> log::Level {
         match self {
@@ -441,3 +481,1082 @@ impl Into<log::Level> for DebugSeverity {
         }
     }
 }
+
+
+#[cfg(feature = "gl45")]
+gl_enum! {
+    /// The kinds of objects that can be given a debug label with `glObjectLabel`.
+    pub enum ObjectIdentifier {
+        Buffer => BUFFER,
+        Shader => SHADER,
+        Program => PROGRAM,
+        VertexArray => VERTEX_ARRAY,
+        Query => QUERY,
+        ProgramPipeline => PROGRAM_PIPELINE,
+        TransformFeedback => TRANSFORM_FEEDBACK,
+        Sampler => SAMPLER,
+        Texture => TEXTURE,
+        Renderbuffer => RENDERBUFFER,
+        Framebuffer => FRAMEBUFFER,
+    }
+}
+
+
+gl_enum! {
+    /// Texture targets, which determine the dimensionality and type of a texture object.
+    pub enum TextureTarget {
+        Texture1D => TEXTURE_1D,
+        Texture2D => TEXTURE_2D,
+        Texture3D => TEXTURE_3D,
+        Texture1DArray => TEXTURE_1D_ARRAY,
+        Texture2DArray => TEXTURE_2D_ARRAY,
+        TextureRectangle => TEXTURE_RECTANGLE,
+        TextureCubeMap => TEXTURE_CUBE_MAP,
+        #[cfg(feature = "gl41")]
+        TextureCubeMapArray => TEXTURE_CUBE_MAP_ARRAY,
+        TextureBuffer => TEXTURE_BUFFER,
+        Texture2DMultisample => TEXTURE_2D_MULTISAMPLE,
+        Texture2DMultisampleArray => TEXTURE_2D_MULTISAMPLE_ARRAY,
+    }
+}
+
+
+gl_enum! {
+    /// The faces of a cube map texture.
+    ///
+    /// When using direct state access functions, cube maps are treated like 2D array textures with six layers, one for
+    /// each face, in the order that these variants are declared. See [`CubeMapFace::layer`].
+    pub enum CubeMapFace {
+        PositiveX => TEXTURE_CUBE_MAP_POSITIVE_X,
+        NegativeX => TEXTURE_CUBE_MAP_NEGATIVE_X,
+        PositiveY => TEXTURE_CUBE_MAP_POSITIVE_Y,
+        NegativeY => TEXTURE_CUBE_MAP_NEGATIVE_Y,
+        PositiveZ => TEXTURE_CUBE_MAP_POSITIVE_Z,
+        NegativeZ => TEXTURE_CUBE_MAP_NEGATIVE_Z,
+    }
+}
+
+impl CubeMapFace {
+    /// Returns the layer index of this face, for use as the `z` offset when uploading cube map data with
+    /// `glTextureSubImage3D`.
+    pub const fn layer(&self) -> usize {
+        (self.into_raw() - crate::raw::TEXTURE_CUBE_MAP_POSITIVE_X) as usize
+    }
+}
+
+
+gl_enum! {
+    /// Sized internal formats, used to allocate storage for textures and renderbuffers.
+    ///
+    /// The suffix on each format describes how its components are stored. No suffix means that they are unsigned
+    /// normalized integers, `SNorm` means signed normalized integers, `F` means floating-point, and `I`/`UI` mean
+    /// (un-normalized) signed and unsigned integers.
+    pub enum InternalFormat {
+        R8 => R8,
+        R8SNorm => R8_SNORM,
+        R16 => R16,
+        R16SNorm => R16_SNORM,
+        RG8 => RG8,
+        RG8SNorm => RG8_SNORM,
+        RG16 => RG16,
+        RG16SNorm => RG16_SNORM,
+        R3G3B2 => R3_G3_B2,
+        RGB4 => RGB4,
+        RGB5 => RGB5,
+        #[cfg(feature = "gl41")]
+        RGB565 => RGB565,
+        RGB8 => RGB8,
+        RGB8SNorm => RGB8_SNORM,
+        RGB10 => RGB10,
+        RGB12 => RGB12,
+        RGB16 => RGB16,
+        RGB16SNorm => RGB16_SNORM,
+        RGBA2 => RGBA2,
+        RGBA4 => RGBA4,
+        RGB5A1 => RGB5_A1,
+        RGBA8 => RGBA8,
+        RGBA8SNorm => RGBA8_SNORM,
+        RGB10A2 => RGB10_A2,
+        RGB10A2UI => RGB10_A2UI,
+        RGBA12 => RGBA12,
+        RGBA16 => RGBA16,
+        RGBA16SNorm => RGBA16_SNORM,
+
+        /// Red, green, and blue components stored in the sRGB color space. They are linearized when sampled.
+        SRGB8 => SRGB8,
+        /// Red, green, and blue components stored in the sRGB color space, with a linear alpha component.
+        SRGB8Alpha8 => SRGB8_ALPHA8,
+
+        R16F => R16F,
+        RG16F => RG16F,
+        RGB16F => RGB16F,
+        RGBA16F => RGBA16F,
+        R32F => R32F,
+        RG32F => RG32F,
+        RGB32F => RGB32F,
+        RGBA32F => RGBA32F,
+        R11FG11FB10F => R11F_G11F_B10F,
+        RGB9E5 => RGB9_E5,
+
+        R8I => R8I,
+        R8UI => R8UI,
+        R16I => R16I,
+        R16UI => R16UI,
+        R32I => R32I,
+        R32UI => R32UI,
+        RG8I => RG8I,
+        RG8UI => RG8UI,
+        RG16I => RG16I,
+        RG16UI => RG16UI,
+        RG32I => RG32I,
+        RG32UI => RG32UI,
+        RGB8I => RGB8I,
+        RGB8UI => RGB8UI,
+        RGB16I => RGB16I,
+        RGB16UI => RGB16UI,
+        RGB32I => RGB32I,
+        RGB32UI => RGB32UI,
+        RGBA8I => RGBA8I,
+        RGBA8UI => RGBA8UI,
+        RGBA16I => RGBA16I,
+        RGBA16UI => RGBA16UI,
+        RGBA32I => RGBA32I,
+        RGBA32UI => RGBA32UI,
+
+        DepthComponent16 => DEPTH_COMPONENT16,
+        DepthComponent24 => DEPTH_COMPONENT24,
+        DepthComponent32 => DEPTH_COMPONENT32,
+        DepthComponent32F => DEPTH_COMPONENT32F,
+        Depth24Stencil8 => DEPTH24_STENCIL8,
+        Depth32FStencil8 => DEPTH32F_STENCIL8,
+        StencilIndex8 => STENCIL_INDEX8,
+    }
+}
+
+
+gl_enum! {
+    /// The components present in pixel data being transferred to or from OpenGL.
+    pub enum PixelFormat {
+        Red => RED,
+        Green => GREEN,
+        Blue => BLUE,
+        RG => RG,
+        RGB => RGB,
+        BGR => BGR,
+        RGBA => RGBA,
+        BGRA => BGRA,
+        RedInteger => RED_INTEGER,
+        GreenInteger => GREEN_INTEGER,
+        BlueInteger => BLUE_INTEGER,
+        RGInteger => RG_INTEGER,
+        RGBInteger => RGB_INTEGER,
+        BGRInteger => BGR_INTEGER,
+        RGBAInteger => RGBA_INTEGER,
+        BGRAInteger => BGRA_INTEGER,
+        StencilIndex => STENCIL_INDEX,
+        DepthComponent => DEPTH_COMPONENT,
+        DepthStencil => DEPTH_STENCIL,
+    }
+}
+
+impl PixelFormat {
+    /// Returns the number of components in each pixel of this format.
+    ///
+    /// [`DepthStencil`][Self::DepthStencil] is counted as having two components, even though it can only be used with
+    /// [packed types][PixelType::is_packed] that fit both of them into a single value.
+    pub const fn components(&self) -> usize {
+        match self {
+            Self::Red | Self::Green | Self::Blue => 1,
+            Self::RedInteger | Self::GreenInteger | Self::BlueInteger => 1,
+            Self::StencilIndex | Self::DepthComponent => 1,
+            Self::RG | Self::RGInteger | Self::DepthStencil => 2,
+            Self::RGB | Self::BGR | Self::RGBInteger | Self::BGRInteger => 3,
+            Self::RGBA | Self::BGRA | Self::RGBAInteger | Self::BGRAInteger => 4,
+        }
+    }
+
+    /// Returns the size in bytes of a single pixel of this format when stored using the given type.
+    ///
+    /// This does not take any row alignment into account.
+    pub const fn pixel_size(&self, ty: PixelType) -> usize {
+        if ty.is_packed() {
+            ty.size()
+        } else {
+            self.components() * ty.size()
+        }
+    }
+}
+
+
+gl_enum! {
+    /// The data type of pixel data being transferred to or from OpenGL.
+    ///
+    /// Most types describe the type of each individual component. The _packed_ types (the ones with bit-counts in their
+    /// names) instead describe a single value that every component of a pixel is packed into.
+    pub enum PixelType {
+        UnsignedByte => UNSIGNED_BYTE,
+        Byte => BYTE,
+        UnsignedShort => UNSIGNED_SHORT,
+        Short => SHORT,
+        UnsignedInt => UNSIGNED_INT,
+        Int => INT,
+        HalfFloat => HALF_FLOAT,
+        Float => FLOAT,
+
+        UnsignedByte332 => UNSIGNED_BYTE_3_3_2,
+        UnsignedByte233Rev => UNSIGNED_BYTE_2_3_3_REV,
+        UnsignedShort565 => UNSIGNED_SHORT_5_6_5,
+        UnsignedShort565Rev => UNSIGNED_SHORT_5_6_5_REV,
+        UnsignedShort4444 => UNSIGNED_SHORT_4_4_4_4,
+        UnsignedShort4444Rev => UNSIGNED_SHORT_4_4_4_4_REV,
+        UnsignedShort5551 => UNSIGNED_SHORT_5_5_5_1,
+        UnsignedShort1555Rev => UNSIGNED_SHORT_1_5_5_5_REV,
+        UnsignedInt8888 => UNSIGNED_INT_8_8_8_8,
+        UnsignedInt8888Rev => UNSIGNED_INT_8_8_8_8_REV,
+        UnsignedInt1010102 => UNSIGNED_INT_10_10_10_2,
+        UnsignedInt2101010Rev => UNSIGNED_INT_2_10_10_10_REV,
+        UnsignedInt248 => UNSIGNED_INT_24_8,
+        UnsignedInt10F11F11FRev => UNSIGNED_INT_10F_11F_11F_REV,
+        UnsignedInt5999Rev => UNSIGNED_INT_5_9_9_9_REV,
+        Float32UnsignedInt248Rev => FLOAT_32_UNSIGNED_INT_24_8_REV,
+    }
+}
+
+impl PixelType {
+    /// Checks whether this is a packed type, where all of a pixel's components are stored in a single value.
+    pub const fn is_packed(&self) -> bool {
+        !matches!(
+            self,
+            Self::UnsignedByte
+                | Self::Byte
+                | Self::UnsignedShort
+                | Self::Short
+                | Self::UnsignedInt
+                | Self::Int
+                | Self::HalfFloat
+                | Self::Float
+        )
+    }
+
+    /// Returns the size in bytes of a single value of this type. For packed types, this is the size of an entire pixel;
+    /// for all others, it is the size of one component.
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::UnsignedByte | Self::Byte | Self::UnsignedByte332 | Self::UnsignedByte233Rev => 1,
+            Self::UnsignedShort | Self::Short | Self::HalfFloat => 2,
+            Self::UnsignedShort565 | Self::UnsignedShort565Rev => 2,
+            Self::UnsignedShort4444 | Self::UnsignedShort4444Rev => 2,
+            Self::UnsignedShort5551 | Self::UnsignedShort1555Rev => 2,
+            Self::UnsignedInt | Self::Int | Self::Float => 4,
+            Self::UnsignedInt8888 | Self::UnsignedInt8888Rev => 4,
+            Self::UnsignedInt1010102 | Self::UnsignedInt2101010Rev => 4,
+            Self::UnsignedInt248 | Self::UnsignedInt10F11F11FRev | Self::UnsignedInt5999Rev => 4,
+            Self::Float32UnsignedInt248Rev => 8,
+        }
+    }
+}
+
+
+gl_enum! {
+    /// Parameters that control how pixel data is packed into or unpacked from client memory.
+    ///
+    /// The `Pack` parameters affect pixel data being read back from OpenGL, and the `Unpack` parameters affect pixel
+    /// data being uploaded to it.
+    pub enum PixelStoreParameter {
+        PackSwapBytes => PACK_SWAP_BYTES,
+        PackLsbFirst => PACK_LSB_FIRST,
+        PackRowLength => PACK_ROW_LENGTH,
+        PackImageHeight => PACK_IMAGE_HEIGHT,
+        PackSkipPixels => PACK_SKIP_PIXELS,
+        PackSkipRows => PACK_SKIP_ROWS,
+        PackSkipImages => PACK_SKIP_IMAGES,
+        /// The alignment of the start of each row of pixel data in memory. Defaults to 4.
+        PackAlignment => PACK_ALIGNMENT,
+
+        UnpackSwapBytes => UNPACK_SWAP_BYTES,
+        UnpackLsbFirst => UNPACK_LSB_FIRST,
+        UnpackRowLength => UNPACK_ROW_LENGTH,
+        UnpackImageHeight => UNPACK_IMAGE_HEIGHT,
+        UnpackSkipPixels => UNPACK_SKIP_PIXELS,
+        UnpackSkipRows => UNPACK_SKIP_ROWS,
+        UnpackSkipImages => UNPACK_SKIP_IMAGES,
+        /// The alignment of the start of each row of pixel data in memory. Defaults to 4.
+        UnpackAlignment => UNPACK_ALIGNMENT,
+    }
+}
+
+
+gl_enum! {
+    /// Texture coordinates that can have their wrapping behaviour set independently.
+    pub enum TextureWrapAxis {
+        S => TEXTURE_WRAP_S,
+        T => TEXTURE_WRAP_T,
+        R => TEXTURE_WRAP_R,
+    }
+}
+
+
+gl_enum! {
+    /// How texture coordinates outside of the range `[0, 1]` are handled.
+    pub enum TextureWrap {
+        Repeat => REPEAT,
+        MirroredRepeat => MIRRORED_REPEAT,
+        ClampToEdge => CLAMP_TO_EDGE,
+        ClampToBorder => CLAMP_TO_BORDER,
+        #[cfg(feature = "gl45")]
+        MirrorClampToEdge => MIRROR_CLAMP_TO_EDGE,
+    }
+}
+
+
+gl_enum! {
+    /// Filtering used when a texture is minified (when one screen pixel covers many texels).
+    ///
+    /// The `Mipmap` filters pick either the nearest mipmap level or linearly blend between the nearest two; the first
+    /// half of their names is the filter used within each level.
+    pub enum TextureMinFilter {
+        Nearest => NEAREST,
+        Linear => LINEAR,
+        NearestMipmapNearest => NEAREST_MIPMAP_NEAREST,
+        LinearMipmapNearest => LINEAR_MIPMAP_NEAREST,
+        NearestMipmapLinear => NEAREST_MIPMAP_LINEAR,
+        LinearMipmapLinear => LINEAR_MIPMAP_LINEAR,
+    }
+}
+
+
+gl_enum! {
+    /// Filtering used when a texture is magnified (when one texel covers many screen pixels).
+    pub enum TextureMagFilter {
+        Nearest => NEAREST,
+        Linear => LINEAR,
+    }
+}
+
+
+gl_enum! {
+    /// Whether sampling a depth texture returns its depth values directly, or the result of comparing them against a
+    /// reference value.
+    pub enum TextureCompareMode {
+        /// Depth values are returned directly.
+        None => NONE,
+        /// Depth values are compared against the reference value (the texture coordinate after the last one used to
+        /// address the texture) with the sampler's [`CompareFunction`], returning `1.0` if the comparison passes and
+        /// `0.0` if it fails. This is used by `sampler*Shadow` uniforms.
+        CompareRefToTexture => COMPARE_REF_TO_TEXTURE,
+    }
+}
+
+
+gl_enum! {
+    /// Sources for each of the components returned when sampling a texture.
+    pub enum TextureSwizzle {
+        Red => RED,
+        Green => GREEN,
+        Blue => BLUE,
+        Alpha => ALPHA,
+        Zero => ZERO,
+        One => ONE,
+    }
+}
+
+
+gl_enum! {
+    /// Framebuffer binding targets.
+    pub enum FramebufferTarget {
+        /// Binds to both the draw and read framebuffer targets.
+        Framebuffer => FRAMEBUFFER,
+        /// The framebuffer that draw, clear, and blit-destination operations write to.
+        DrawFramebuffer => DRAW_FRAMEBUFFER,
+        /// The framebuffer that read and blit-source operations read from.
+        ReadFramebuffer => READ_FRAMEBUFFER,
+    }
+}
+
+
+gl_enum! {
+    /// Attachment points of a framebuffer object.
+    ///
+    /// Implementations are only required to support eight color attachments; see `GL_MAX_COLOR_ATTACHMENTS`.
+    pub enum FramebufferAttachment {
+        Color0 => COLOR_ATTACHMENT0,
+        Color1 => COLOR_ATTACHMENT1,
+        Color2 => COLOR_ATTACHMENT2,
+        Color3 => COLOR_ATTACHMENT3,
+        Color4 => COLOR_ATTACHMENT4,
+        Color5 => COLOR_ATTACHMENT5,
+        Color6 => COLOR_ATTACHMENT6,
+        Color7 => COLOR_ATTACHMENT7,
+        Color8 => COLOR_ATTACHMENT8,
+        Color9 => COLOR_ATTACHMENT9,
+        Color10 => COLOR_ATTACHMENT10,
+        Color11 => COLOR_ATTACHMENT11,
+        Color12 => COLOR_ATTACHMENT12,
+        Color13 => COLOR_ATTACHMENT13,
+        Color14 => COLOR_ATTACHMENT14,
+        Color15 => COLOR_ATTACHMENT15,
+        Depth => DEPTH_ATTACHMENT,
+        Stencil => STENCIL_ATTACHMENT,
+        /// Attaches a single image to both the depth and stencil attachment points.
+        DepthStencil => DEPTH_STENCIL_ATTACHMENT,
+    }
+}
+
+impl FramebufferAttachment {
+    /// Returns the color attachment with the given index, if it is one of the ones defined by OpenGL (`0..16`).
+    pub const fn color(index: u32) -> Option<Self> {
+        if index < 16 {
+            Self::from_raw(crate::raw::COLOR_ATTACHMENT0 + index)
+        } else {
+            None
+        }
+    }
+}
+
+
+gl_enum! {
+    /// Color buffers that can be selected for drawing or reading.
+    ///
+    /// The `Color` variants refer to the color attachments of a framebuffer object. The others refer to the buffers of
+    /// the default framebuffer; `Front` and `Back` are only valid for reading.
+    pub enum ColorBuffer {
+        None => NONE,
+        FrontLeft => FRONT_LEFT,
+        FrontRight => FRONT_RIGHT,
+        BackLeft => BACK_LEFT,
+        BackRight => BACK_RIGHT,
+        Front => FRONT,
+        Back => BACK,
+        Left => LEFT,
+        Right => RIGHT,
+        Color0 => COLOR_ATTACHMENT0,
+        Color1 => COLOR_ATTACHMENT1,
+        Color2 => COLOR_ATTACHMENT2,
+        Color3 => COLOR_ATTACHMENT3,
+        Color4 => COLOR_ATTACHMENT4,
+        Color5 => COLOR_ATTACHMENT5,
+        Color6 => COLOR_ATTACHMENT6,
+        Color7 => COLOR_ATTACHMENT7,
+        Color8 => COLOR_ATTACHMENT8,
+        Color9 => COLOR_ATTACHMENT9,
+        Color10 => COLOR_ATTACHMENT10,
+        Color11 => COLOR_ATTACHMENT11,
+        Color12 => COLOR_ATTACHMENT12,
+        Color13 => COLOR_ATTACHMENT13,
+        Color14 => COLOR_ATTACHMENT14,
+        Color15 => COLOR_ATTACHMENT15,
+    }
+}
+
+impl ColorBuffer {
+    /// Returns the color attachment buffer with the given index, if it is one of the ones defined by OpenGL (`0..16`).
+    pub const fn color(index: u32) -> Option<Self> {
+        if index < 16 {
+            Self::from_raw(crate::raw::COLOR_ATTACHMENT0 + index)
+        } else {
+            None
+        }
+    }
+}
+
+
+gl_enum! {
+    /// Filters used when a blit operation needs to stretch an image.
+    pub enum BlitFilter {
+        Nearest => NEAREST,
+        /// Linear filtering may only be used when blitting color buffers.
+        Linear => LINEAR,
+    }
+}
+
+
+gl_enum! {
+    /// The result of waiting on a sync object.
+    pub enum SyncStatus {
+        /// The sync object was already signaled when the wait began.
+        AlreadySignaled => ALREADY_SIGNALED,
+        /// The sync object was not signaled before the timeout expired.
+        TimeoutExpired => TIMEOUT_EXPIRED,
+        /// The sync object became signaled while waiting.
+        ConditionSatisfied => CONDITION_SATISFIED,
+        /// An error occurred while waiting.
+        WaitFailed => WAIT_FAILED,
+    }
+}
+
+impl SyncStatus {
+    /// Checks whether this status means that the sync object has been signaled.
+    pub const fn is_signaled(&self) -> bool {
+        matches!(self, Self::AlreadySignaled | Self::ConditionSatisfied)
+    }
+}
+
+
+gl_enum! {
+    /// Kinds of query objects, which measure something about the commands issued while they are active.
+    pub enum QueryTarget {
+        /// Counts the number of samples that pass the depth test.
+        SamplesPassed => SAMPLES_PASSED,
+        /// Checks whether any samples pass the depth test. The result is `1` if any did, and `0` otherwise.
+        AnySamplesPassed => ANY_SAMPLES_PASSED,
+        /// Like [`AnySamplesPassed`][Self::AnySamplesPassed], but may be faster at the cost of sometimes reporting that
+        /// samples passed when none actually did.
+        #[cfg(feature = "gl45")]
+        AnySamplesPassedConservative => ANY_SAMPLES_PASSED_CONSERVATIVE,
+        /// Counts the number of primitives generated by the vertex processing stages.
+        PrimitivesGenerated => PRIMITIVES_GENERATED,
+        /// Counts the number of primitives written by transform feedback.
+        TransformFeedbackPrimitivesWritten => TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN,
+        /// Measures the time that the GPU takes to execute the commands, in nanoseconds.
+        TimeElapsed => TIME_ELAPSED,
+        /// Records the GPU's time, in nanoseconds, once all previous commands have completed. Timestamp queries are not
+        /// begun and ended; they are recorded with [`GLContext::query_counter`][crate::GLContext::query_counter].
+        Timestamp => TIMESTAMP,
+    }
+}
+
+
+gl_enum! {
+    /// How [conditional rendering][crate::GLContext::begin_conditional_render] waits on the result of its query.
+    pub enum ConditionalRenderMode {
+        /// Waits for the query's result before deciding whether to render.
+        Wait => QUERY_WAIT,
+        /// Renders anyway if the query's result is not yet available.
+        NoWait => QUERY_NO_WAIT,
+        /// Waits for the query's result, which may be applied separately to each region of the framebuffer.
+        ByRegionWait => QUERY_BY_REGION_WAIT,
+        /// Renders anyway if the query's result is not yet available, and may apply it separately to each region of the
+        /// framebuffer.
+        ByRegionNoWait => QUERY_BY_REGION_NO_WAIT,
+        /// Like [`Wait`][Self::Wait], but renders only if the query found that no samples passed.
+        #[cfg(feature = "gl45")]
+        WaitInverted => QUERY_WAIT_INVERTED,
+        /// Like [`NoWait`][Self::NoWait], but renders only if the query found that no samples passed.
+        #[cfg(feature = "gl45")]
+        NoWaitInverted => QUERY_NO_WAIT_INVERTED,
+        /// Like [`ByRegionWait`][Self::ByRegionWait], but renders only if the query found that no samples passed.
+        #[cfg(feature = "gl45")]
+        ByRegionWaitInverted => QUERY_BY_REGION_WAIT_INVERTED,
+        /// Like [`ByRegionNoWait`][Self::ByRegionNoWait], but renders only if the query found that no samples passed.
+        #[cfg(feature = "gl45")]
+        ByRegionNoWaitInverted => QUERY_BY_REGION_NO_WAIT_INVERTED,
+    }
+}
+
+
+#[cfg(feature = "gl45")]
+gl_enum! {
+    /// An interface of a linked program whose active resources can be queried.
+    pub enum ProgramInterface {
+        /// Uniforms in the default uniform block, and in named uniform blocks.
+        Uniform => UNIFORM,
+        /// Named uniform blocks.
+        UniformBlock => UNIFORM_BLOCK,
+        /// Inputs to the first shader stage in the program; for most programs, the vertex attributes.
+        ProgramInput => PROGRAM_INPUT,
+        /// Outputs from the last shader stage in the program; for most programs, the fragment shader outputs.
+        ProgramOutput => PROGRAM_OUTPUT,
+        /// Named shader storage blocks.
+        ShaderStorageBlock => SHADER_STORAGE_BLOCK,
+        /// Variables in shader storage blocks.
+        BufferVariable => BUFFER_VARIABLE,
+        /// Atomic counter buffer binding points used by the program.
+        AtomicCounterBuffer => ATOMIC_COUNTER_BUFFER,
+        /// Outputs that are captured by transform feedback.
+        TransformFeedbackVarying => TRANSFORM_FEEDBACK_VARYING,
+        /// Transform feedback buffer binding points used by the program.
+        TransformFeedbackBuffer => TRANSFORM_FEEDBACK_BUFFER,
+    }
+}
+
+
+#[cfg(feature = "gl45")]
+gl_enum! {
+    /// Properties of a program resource that can be queried with [`GLContext::get_program_resource`]. Not all
+    /// properties apply to all [interfaces][ProgramInterface].
+    ///
+    /// Only properties that have a single value are included.
+    ///
+    /// [`GLContext::get_program_resource`]: crate::GLContext::get_program_resource
+    pub enum ProgramResourceProperty {
+        /// The length of the resource's name, including its NUL-terminator.
+        NameLength => NAME_LENGTH,
+        /// The data type of the resource, as a [`GlslType`].
+        Type => TYPE,
+        /// The number of elements in the resource if it is an array, or 1 if it is not.
+        ArraySize => ARRAY_SIZE,
+        /// The offset of the resource within its block, in bytes, or -1 if it is not in a block.
+        Offset => OFFSET,
+        /// The index of the block that the resource belongs to, or -1 if it is not in a block.
+        BlockIndex => BLOCK_INDEX,
+        /// The distance in bytes between the elements of the resource if it is an array in a block.
+        ArrayStride => ARRAY_STRIDE,
+        /// The distance in bytes between the columns (or rows) of the resource if it is a matrix in a block.
+        MatrixStride => MATRIX_STRIDE,
+        /// Whether the resource is a row-major matrix in a block.
+        IsRowMajor => IS_ROW_MAJOR,
+        /// The index of the atomic counter buffer that the resource belongs to.
+        AtomicCounterBufferIndex => ATOMIC_COUNTER_BUFFER_INDEX,
+        /// The binding point of a block.
+        BufferBinding => BUFFER_BINDING,
+        /// The minimum size of the buffer range bound to a block, in bytes.
+        BufferDataSize => BUFFER_DATA_SIZE,
+        /// The number of active variables in a block.
+        NumActiveVariables => NUM_ACTIVE_VARIABLES,
+        /// Whether the resource is used by the vertex shader.
+        ReferencedByVertexShader => REFERENCED_BY_VERTEX_SHADER,
+        /// Whether the resource is used by the tessellation control shader.
+        ReferencedByTessControlShader => REFERENCED_BY_TESS_CONTROL_SHADER,
+        /// Whether the resource is used by the tessellation evaluation shader.
+        ReferencedByTessEvaluationShader => REFERENCED_BY_TESS_EVALUATION_SHADER,
+        /// Whether the resource is used by the geometry shader.
+        ReferencedByGeometryShader => REFERENCED_BY_GEOMETRY_SHADER,
+        /// Whether the resource is used by the fragment shader.
+        ReferencedByFragmentShader => REFERENCED_BY_FRAGMENT_SHADER,
+        /// Whether the resource is used by the compute shader.
+        ReferencedByComputeShader => REFERENCED_BY_COMPUTE_SHADER,
+        /// The number of elements in the top-level array of a buffer variable, or 1 if it is not in an array.
+        TopLevelArraySize => TOP_LEVEL_ARRAY_SIZE,
+        /// The distance in bytes between the elements of the top-level array of a buffer variable.
+        TopLevelArrayStride => TOP_LEVEL_ARRAY_STRIDE,
+        /// The location assigned to the resource, or -1 if it does not have one.
+        Location => LOCATION,
+        /// The component assigned to a program input or output with a `component` layout qualifier.
+        LocationComponent => LOCATION_COMPONENT,
+    }
+}
+
+
+gl_enum! {
+    /// The data type of an active variable in a program, as reported by program introspection.
+    pub enum GlslType {
+        Float => FLOAT,
+        FloatVec2 => FLOAT_VEC2,
+        FloatVec3 => FLOAT_VEC3,
+        FloatVec4 => FLOAT_VEC4,
+        Double => DOUBLE,
+        #[cfg(feature = "gl41")]
+        DoubleVec2 => DOUBLE_VEC2,
+        #[cfg(feature = "gl41")]
+        DoubleVec3 => DOUBLE_VEC3,
+        #[cfg(feature = "gl41")]
+        DoubleVec4 => DOUBLE_VEC4,
+        Int => INT,
+        IntVec2 => INT_VEC2,
+        IntVec3 => INT_VEC3,
+        IntVec4 => INT_VEC4,
+        UnsignedInt => UNSIGNED_INT,
+        UnsignedIntVec2 => UNSIGNED_INT_VEC2,
+        UnsignedIntVec3 => UNSIGNED_INT_VEC3,
+        UnsignedIntVec4 => UNSIGNED_INT_VEC4,
+        Bool => BOOL,
+        BoolVec2 => BOOL_VEC2,
+        BoolVec3 => BOOL_VEC3,
+        BoolVec4 => BOOL_VEC4,
+        FloatMat2 => FLOAT_MAT2,
+        FloatMat3 => FLOAT_MAT3,
+        FloatMat4 => FLOAT_MAT4,
+        FloatMat2x3 => FLOAT_MAT2x3,
+        FloatMat2x4 => FLOAT_MAT2x4,
+        FloatMat3x2 => FLOAT_MAT3x2,
+        FloatMat3x4 => FLOAT_MAT3x4,
+        FloatMat4x2 => FLOAT_MAT4x2,
+        FloatMat4x3 => FLOAT_MAT4x3,
+        #[cfg(feature = "gl41")]
+        DoubleMat2 => DOUBLE_MAT2,
+        #[cfg(feature = "gl41")]
+        DoubleMat3 => DOUBLE_MAT3,
+        #[cfg(feature = "gl41")]
+        DoubleMat4 => DOUBLE_MAT4,
+        #[cfg(feature = "gl41")]
+        DoubleMat2x3 => DOUBLE_MAT2x3,
+        #[cfg(feature = "gl41")]
+        DoubleMat2x4 => DOUBLE_MAT2x4,
+        #[cfg(feature = "gl41")]
+        DoubleMat3x2 => DOUBLE_MAT3x2,
+        #[cfg(feature = "gl41")]
+        DoubleMat3x4 => DOUBLE_MAT3x4,
+        #[cfg(feature = "gl41")]
+        DoubleMat4x2 => DOUBLE_MAT4x2,
+        #[cfg(feature = "gl41")]
+        DoubleMat4x3 => DOUBLE_MAT4x3,
+
+        Sampler1D => SAMPLER_1D,
+        Sampler2D => SAMPLER_2D,
+        Sampler3D => SAMPLER_3D,
+        SamplerCube => SAMPLER_CUBE,
+        Sampler1DShadow => SAMPLER_1D_SHADOW,
+        Sampler2DShadow => SAMPLER_2D_SHADOW,
+        Sampler1DArray => SAMPLER_1D_ARRAY,
+        Sampler2DArray => SAMPLER_2D_ARRAY,
+        Sampler1DArrayShadow => SAMPLER_1D_ARRAY_SHADOW,
+        Sampler2DArrayShadow => SAMPLER_2D_ARRAY_SHADOW,
+        Sampler2DMultisample => SAMPLER_2D_MULTISAMPLE,
+        Sampler2DMultisampleArray => SAMPLER_2D_MULTISAMPLE_ARRAY,
+        SamplerCubeShadow => SAMPLER_CUBE_SHADOW,
+        SamplerBuffer => SAMPLER_BUFFER,
+        Sampler2DRect => SAMPLER_2D_RECT,
+        Sampler2DRectShadow => SAMPLER_2D_RECT_SHADOW,
+        #[cfg(feature = "gl41")]
+        SamplerCubeMapArray => SAMPLER_CUBE_MAP_ARRAY,
+        #[cfg(feature = "gl41")]
+        SamplerCubeMapArrayShadow => SAMPLER_CUBE_MAP_ARRAY_SHADOW,
+        IntSampler1D => INT_SAMPLER_1D,
+        IntSampler2D => INT_SAMPLER_2D,
+        IntSampler3D => INT_SAMPLER_3D,
+        IntSamplerCube => INT_SAMPLER_CUBE,
+        IntSampler1DArray => INT_SAMPLER_1D_ARRAY,
+        IntSampler2DArray => INT_SAMPLER_2D_ARRAY,
+        IntSampler2DMultisample => INT_SAMPLER_2D_MULTISAMPLE,
+        IntSampler2DMultisampleArray => INT_SAMPLER_2D_MULTISAMPLE_ARRAY,
+        IntSamplerBuffer => INT_SAMPLER_BUFFER,
+        IntSampler2DRect => INT_SAMPLER_2D_RECT,
+        #[cfg(feature = "gl41")]
+        IntSamplerCubeMapArray => INT_SAMPLER_CUBE_MAP_ARRAY,
+        UnsignedIntSampler1D => UNSIGNED_INT_SAMPLER_1D,
+        UnsignedIntSampler2D => UNSIGNED_INT_SAMPLER_2D,
+        UnsignedIntSampler3D => UNSIGNED_INT_SAMPLER_3D,
+        UnsignedIntSamplerCube => UNSIGNED_INT_SAMPLER_CUBE,
+        UnsignedIntSampler1DArray => UNSIGNED_INT_SAMPLER_1D_ARRAY,
+        UnsignedIntSampler2DArray => UNSIGNED_INT_SAMPLER_2D_ARRAY,
+        UnsignedIntSampler2DMultisample => UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE,
+        UnsignedIntSampler2DMultisampleArray => UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY,
+        UnsignedIntSamplerBuffer => UNSIGNED_INT_SAMPLER_BUFFER,
+        UnsignedIntSampler2DRect => UNSIGNED_INT_SAMPLER_2D_RECT,
+        #[cfg(feature = "gl41")]
+        UnsignedIntSamplerCubeMapArray => UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY,
+
+        #[cfg(feature = "gl45")]
+        Image1D => IMAGE_1D,
+        #[cfg(feature = "gl45")]
+        Image2D => IMAGE_2D,
+        #[cfg(feature = "gl45")]
+        Image3D => IMAGE_3D,
+        #[cfg(feature = "gl45")]
+        Image2DRect => IMAGE_2D_RECT,
+        #[cfg(feature = "gl45")]
+        ImageCube => IMAGE_CUBE,
+        #[cfg(feature = "gl45")]
+        ImageBuffer => IMAGE_BUFFER,
+        #[cfg(feature = "gl45")]
+        Image1DArray => IMAGE_1D_ARRAY,
+        #[cfg(feature = "gl45")]
+        Image2DArray => IMAGE_2D_ARRAY,
+        #[cfg(feature = "gl45")]
+        ImageCubeMapArray => IMAGE_CUBE_MAP_ARRAY,
+        #[cfg(feature = "gl45")]
+        Image2DMultisample => IMAGE_2D_MULTISAMPLE,
+        #[cfg(feature = "gl45")]
+        Image2DMultisampleArray => IMAGE_2D_MULTISAMPLE_ARRAY,
+        #[cfg(feature = "gl45")]
+        IntImage1D => INT_IMAGE_1D,
+        #[cfg(feature = "gl45")]
+        IntImage2D => INT_IMAGE_2D,
+        #[cfg(feature = "gl45")]
+        IntImage3D => INT_IMAGE_3D,
+        #[cfg(feature = "gl45")]
+        IntImage2DRect => INT_IMAGE_2D_RECT,
+        #[cfg(feature = "gl45")]
+        IntImageCube => INT_IMAGE_CUBE,
+        #[cfg(feature = "gl45")]
+        IntImageBuffer => INT_IMAGE_BUFFER,
+        #[cfg(feature = "gl45")]
+        IntImage1DArray => INT_IMAGE_1D_ARRAY,
+        #[cfg(feature = "gl45")]
+        IntImage2DArray => INT_IMAGE_2D_ARRAY,
+        #[cfg(feature = "gl45")]
+        IntImageCubeMapArray => INT_IMAGE_CUBE_MAP_ARRAY,
+        #[cfg(feature = "gl45")]
+        IntImage2DMultisample => INT_IMAGE_2D_MULTISAMPLE,
+        #[cfg(feature = "gl45")]
+        IntImage2DMultisampleArray => INT_IMAGE_2D_MULTISAMPLE_ARRAY,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage1D => UNSIGNED_INT_IMAGE_1D,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage2D => UNSIGNED_INT_IMAGE_2D,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage3D => UNSIGNED_INT_IMAGE_3D,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage2DRect => UNSIGNED_INT_IMAGE_2D_RECT,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImageCube => UNSIGNED_INT_IMAGE_CUBE,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImageBuffer => UNSIGNED_INT_IMAGE_BUFFER,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage1DArray => UNSIGNED_INT_IMAGE_1D_ARRAY,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage2DArray => UNSIGNED_INT_IMAGE_2D_ARRAY,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImageCubeMapArray => UNSIGNED_INT_IMAGE_CUBE_MAP_ARRAY,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage2DMultisample => UNSIGNED_INT_IMAGE_2D_MULTISAMPLE,
+        #[cfg(feature = "gl45")]
+        UnsignedIntImage2DMultisampleArray => UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY,
+
+        #[cfg(feature = "gl45")]
+        UnsignedIntAtomicCounter => UNSIGNED_INT_ATOMIC_COUNTER,
+    }
+}
+
+impl GlslType {
+    /// Checks whether this type is one of the opaque sampler types, which are set to the index of a texture unit.
+    pub const fn is_sampler(&self) -> bool {
+        match self {
+            Self::Sampler1D
+            | Self::Sampler2D
+            | Self::Sampler3D
+            | Self::SamplerCube
+            | Self::Sampler1DShadow
+            | Self::Sampler2DShadow
+            | Self::Sampler1DArray
+            | Self::Sampler2DArray
+            | Self::Sampler1DArrayShadow
+            | Self::Sampler2DArrayShadow
+            | Self::Sampler2DMultisample
+            | Self::Sampler2DMultisampleArray
+            | Self::SamplerCubeShadow
+            | Self::SamplerBuffer
+            | Self::Sampler2DRect
+            | Self::Sampler2DRectShadow
+            | Self::IntSampler1D
+            | Self::IntSampler2D
+            | Self::IntSampler3D
+            | Self::IntSamplerCube
+            | Self::IntSampler1DArray
+            | Self::IntSampler2DArray
+            | Self::IntSampler2DMultisample
+            | Self::IntSampler2DMultisampleArray
+            | Self::IntSamplerBuffer
+            | Self::IntSampler2DRect
+            | Self::UnsignedIntSampler1D
+            | Self::UnsignedIntSampler2D
+            | Self::UnsignedIntSampler3D
+            | Self::UnsignedIntSamplerCube
+            | Self::UnsignedIntSampler1DArray
+            | Self::UnsignedIntSampler2DArray
+            | Self::UnsignedIntSampler2DMultisample
+            | Self::UnsignedIntSampler2DMultisampleArray
+            | Self::UnsignedIntSamplerBuffer
+            | Self::UnsignedIntSampler2DRect => true,
+            #[cfg(feature = "gl41")]
+            Self::SamplerCubeMapArray
+            | Self::SamplerCubeMapArrayShadow
+            | Self::IntSamplerCubeMapArray
+            | Self::UnsignedIntSamplerCubeMapArray => true,
+            _ => false,
+        }
+    }
+
+    /// Checks whether this type is one of the opaque image types, which are set to the index of an image unit.
+    pub const fn is_image(&self) -> bool {
+        match self {
+            #[cfg(feature = "gl45")]
+            Self::Image1D
+            | Self::Image2D
+            | Self::Image3D
+            | Self::Image2DRect
+            | Self::ImageCube
+            | Self::ImageBuffer
+            | Self::Image1DArray
+            | Self::Image2DArray
+            | Self::ImageCubeMapArray
+            | Self::Image2DMultisample
+            | Self::Image2DMultisampleArray
+            | Self::IntImage1D
+            | Self::IntImage2D
+            | Self::IntImage3D
+            | Self::IntImage2DRect
+            | Self::IntImageCube
+            | Self::IntImageBuffer
+            | Self::IntImage1DArray
+            | Self::IntImage2DArray
+            | Self::IntImageCubeMapArray
+            | Self::IntImage2DMultisample
+            | Self::IntImage2DMultisampleArray
+            | Self::UnsignedIntImage1D
+            | Self::UnsignedIntImage2D
+            | Self::UnsignedIntImage3D
+            | Self::UnsignedIntImage2DRect
+            | Self::UnsignedIntImageCube
+            | Self::UnsignedIntImageBuffer
+            | Self::UnsignedIntImage1DArray
+            | Self::UnsignedIntImage2DArray
+            | Self::UnsignedIntImageCubeMapArray
+            | Self::UnsignedIntImage2DMultisample
+            | Self::UnsignedIntImage2DMultisampleArray => true,
+            _ => false,
+        }
+    }
+}
+
+
+gl_enum! {
+    /// How a shader may access an image bound to an image unit.
+    pub enum ImageAccess {
+        /// The shader may only read from the image.
+        ReadOnly => READ_ONLY,
+        /// The shader may only write to the image.
+        WriteOnly => WRITE_ONLY,
+        /// The shader may both read from and write to the image.
+        ReadWrite => READ_WRITE,
+    }
+}
+
+
+gl_enum! {
+    /// The formats that an image can be interpreted as when it is bound to an image unit for load/store access. These
+    /// correspond to the format layout qualifiers of GLSL `image` uniforms, such as `layout(rgba32f)`.
+    ///
+    /// The format does not have to match the texture's internal format exactly, but it must be the same size; see
+    /// section 8.26 of the OpenGL specification for the full compatibility rules.
+    pub enum ImageUnitFormat {
+        RGBA32F => RGBA32F,
+        RGBA16F => RGBA16F,
+        RG32F => RG32F,
+        RG16F => RG16F,
+        R11FG11FB10F => R11F_G11F_B10F,
+        R32F => R32F,
+        R16F => R16F,
+
+        RGBA32UI => RGBA32UI,
+        RGBA16UI => RGBA16UI,
+        RGB10A2UI => RGB10_A2UI,
+        RGBA8UI => RGBA8UI,
+        RG32UI => RG32UI,
+        RG16UI => RG16UI,
+        RG8UI => RG8UI,
+        R32UI => R32UI,
+        R16UI => R16UI,
+        R8UI => R8UI,
+
+        RGBA32I => RGBA32I,
+        RGBA16I => RGBA16I,
+        RGBA8I => RGBA8I,
+        RG32I => RG32I,
+        RG16I => RG16I,
+        RG8I => RG8I,
+        R32I => R32I,
+        R16I => R16I,
+        R8I => R8I,
+
+        RGBA16 => RGBA16,
+        RGB10A2 => RGB10_A2,
+        RGBA8 => RGBA8,
+        RG16 => RG16,
+        RG8 => RG8,
+        R16 => R16,
+        R8 => R8,
+
+        RGBA16SNorm => RGBA16_SNORM,
+        RGBA8SNorm => RGBA8_SNORM,
+        RG16SNorm => RG16_SNORM,
+        RG8SNorm => RG8_SNORM,
+        R16SNorm => R16_SNORM,
+        R8SNorm => R8_SNORM,
+    }
+}
+
+
+gl_enum! {
+    /// A factor that the source or destination color is multiplied by when blending.
+    ///
+    /// In the descriptions below, `S` is the source (incoming fragment) color, `D` is the destination (framebuffer)
+    /// color, `C` is the constant [blend color][crate::GLContext::blend_color], and `S1` is the fragment shader's
+    /// second color output for dual-source blending.
+    pub enum BlendFactor {
+        /// `(0, 0, 0, 0)`.
+        Zero => ZERO,
+        /// `(1, 1, 1, 1)`.
+        One => ONE,
+        /// `S`.
+        SrcColor => SRC_COLOR,
+        /// `1 - S`.
+        OneMinusSrcColor => ONE_MINUS_SRC_COLOR,
+        /// `D`.
+        DstColor => DST_COLOR,
+        /// `1 - D`.
+        OneMinusDstColor => ONE_MINUS_DST_COLOR,
+        /// `S.a`.
+        SrcAlpha => SRC_ALPHA,
+        /// `1 - S.a`.
+        OneMinusSrcAlpha => ONE_MINUS_SRC_ALPHA,
+        /// `D.a`.
+        DstAlpha => DST_ALPHA,
+        /// `1 - D.a`.
+        OneMinusDstAlpha => ONE_MINUS_DST_ALPHA,
+        /// `C`.
+        ConstantColor => CONSTANT_COLOR,
+        /// `1 - C`.
+        OneMinusConstantColor => ONE_MINUS_CONSTANT_COLOR,
+        /// `C.a`.
+        ConstantAlpha => CONSTANT_ALPHA,
+        /// `1 - C.a`.
+        OneMinusConstantAlpha => ONE_MINUS_CONSTANT_ALPHA,
+        /// `min(S.a, 1 - D.a)` for the color components, and `1` for alpha.
+        SrcAlphaSaturate => SRC_ALPHA_SATURATE,
+        /// `S1`.
+        Src1Color => SRC1_COLOR,
+        /// `1 - S1`.
+        OneMinusSrc1Color => ONE_MINUS_SRC1_COLOR,
+        /// `S1.a`.
+        Src1Alpha => SRC1_ALPHA,
+        /// `1 - S1.a`.
+        OneMinusSrc1Alpha => ONE_MINUS_SRC1_ALPHA,
+    }
+}
+
+
+gl_enum! {
+    /// How the weighted source and destination colors are combined when blending.
+    pub enum BlendEquation {
+        /// `source + destination`.
+        Add => FUNC_ADD,
+        /// `source - destination`.
+        Subtract => FUNC_SUBTRACT,
+        /// `destination - source`.
+        ReverseSubtract => FUNC_REVERSE_SUBTRACT,
+        /// The component-wise minimum of the source and destination. Blend factors are ignored.
+        Min => MIN,
+        /// The component-wise maximum of the source and destination. Blend factors are ignored.
+        Max => MAX,
+    }
+}
+
+
+gl_enum! {
+    /// A comparison between an incoming value and a stored one, used by depth and stencil tests and by depth texture
+    /// comparisons. Each variant passes when `incoming <op> stored`.
+    pub enum CompareFunction {
+        Never => NEVER,
+        Less => LESS,
+        Equal => EQUAL,
+        LessEqual => LEQUAL,
+        Greater => GREATER,
+        NotEqual => NOTEQUAL,
+        GreaterEqual => GEQUAL,
+        Always => ALWAYS,
+    }
+}
+
+
+gl_enum! {
+    /// An action taken on the stored stencil value depending on the outcome of the stencil and depth tests.
+    pub enum StencilOp {
+        /// Keeps the current value.
+        Keep => KEEP,
+        /// Sets the value to zero.
+        Zero => ZERO,
+        /// Sets the value to the reference value given to `glStencilFunc`.
+        Replace => REPLACE,
+        /// Increments the value, clamping at the maximum.
+        Increment => INCR,
+        /// Increments the value, wrapping to zero at the maximum.
+        IncrementWrap => INCR_WRAP,
+        /// Decrements the value, clamping at zero.
+        Decrement => DECR,
+        /// Decrements the value, wrapping to the maximum at zero.
+        DecrementWrap => DECR_WRAP,
+        /// Bitwise inverts the value.
+        Invert => INVERT,
+    }
+}
@@ -8,3 +8,96 @@ gl_bitfield! {
         pub const STENCIL = STENCIL_BUFFER_BIT;
     }
 }
+
+
+#[cfg(feature = "gl45")]
+gl_bitfield! {
+    /// Flags describing how an immutable buffer's data store may be used after it has been created with
+    /// `glNamedBufferStorage`.
+    pub struct BufferStorageFlags {
+        /// The contents of the data store may be updated after creation with `glNamedBufferSubData`. Without this flag,
+        /// the buffer can still be modified by the GL itself (e.g. with copies or clears) or through a mapping.
+        pub const DYNAMIC_STORAGE = DYNAMIC_STORAGE_BIT;
+        /// The data store may be mapped for reading.
+        pub const MAP_READ = MAP_READ_BIT;
+        /// The data store may be mapped for writing.
+        pub const MAP_WRITE = MAP_WRITE_BIT;
+        /// The data store may stay mapped while the GL uses the buffer.
+        pub const MAP_PERSISTENT = MAP_PERSISTENT_BIT;
+        /// Persistent mappings of the data store are coherent, meaning that writes become visible to the other side
+        /// without needing explicit barriers or flushes.
+        pub const MAP_COHERENT = MAP_COHERENT_BIT;
+        /// Hints that the data store should be kept in client-side memory.
+        pub const CLIENT_STORAGE = CLIENT_STORAGE_BIT;
+    }
+}
+
+
+gl_bitfield! {
+    /// Flags describing how a range of a buffer is to be mapped with `glMapNamedBufferRange`.
+    pub struct MapAccessFlags {
+        /// The mapping may be read from.
+        pub const READ = MAP_READ_BIT;
+        /// The mapping may be written to.
+        pub const WRITE = MAP_WRITE_BIT;
+        /// The mapping may stay active while the GL uses the buffer. The buffer must have been created with
+        /// [`BufferStorageFlags::MAP_PERSISTENT`].
+        #[cfg(feature = "gl45")]
+        pub const PERSISTENT = MAP_PERSISTENT_BIT;
+        /// The mapping is coherent. The buffer must have been created with [`BufferStorageFlags::MAP_COHERENT`].
+        #[cfg(feature = "gl45")]
+        pub const COHERENT = MAP_COHERENT_BIT;
+        /// The previous contents of the mapped range may be discarded. May not be used with [`READ`][Self::READ].
+        pub const INVALIDATE_RANGE = MAP_INVALIDATE_RANGE_BIT;
+        /// The previous contents of the entire buffer may be discarded. May not be used with [`READ`][Self::READ].
+        pub const INVALIDATE_BUFFER = MAP_INVALIDATE_BUFFER_BIT;
+        /// Modified ranges of the mapping must be flushed explicitly. Requires [`WRITE`][Self::WRITE].
+        pub const FLUSH_EXPLICIT = MAP_FLUSH_EXPLICIT_BIT;
+        /// The GL should not wait for pending operations on the buffer before mapping it.
+        pub const UNSYNCHRONIZED = MAP_UNSYNCHRONIZED_BIT;
+    }
+}
+
+
+#[cfg(feature = "gl45")]
+gl_bitfield! {
+    /// Flags describing which kinds of access to memory written by shaders must be synchronized by `glMemoryBarrier`.
+    ///
+    /// Each flag names the way that the data will be _read_ after the barrier, not the way that it was written. For
+    /// example, after a compute shader writes vertex positions into a shader storage buffer, a barrier with
+    /// [`VERTEX_ATTRIB_ARRAY`][Self::VERTEX_ATTRIB_ARRAY] is needed before drawing with that buffer as a vertex buffer.
+    pub struct MemoryBarrierFlags {
+        /// Vertex data sourced from buffers.
+        pub const VERTEX_ATTRIB_ARRAY = VERTEX_ATTRIB_ARRAY_BARRIER_BIT;
+        /// Indices sourced from element buffers.
+        pub const ELEMENT_ARRAY = ELEMENT_ARRAY_BARRIER_BIT;
+        /// Uniform blocks sourced from buffers.
+        pub const UNIFORM = UNIFORM_BARRIER_BIT;
+        /// Texture fetches through samplers, including from buffer textures.
+        pub const TEXTURE_FETCH = TEXTURE_FETCH_BARRIER_BIT;
+        /// Image load, store, and atomic operations.
+        pub const SHADER_IMAGE_ACCESS = SHADER_IMAGE_ACCESS_BARRIER_BIT;
+        /// Indirect draw and dispatch commands sourced from buffers.
+        pub const COMMAND = COMMAND_BARRIER_BIT;
+        /// Pixel reads and writes through pixel pack and unpack buffers.
+        pub const PIXEL_BUFFER = PIXEL_BUFFER_BARRIER_BIT;
+        /// Texture updates, such as `glTexSubImage*` and `glGetTexImage`.
+        pub const TEXTURE_UPDATE = TEXTURE_UPDATE_BARRIER_BIT;
+        /// Buffer updates, such as `glNamedBufferSubData`, copies, and reads through mappings.
+        pub const BUFFER_UPDATE = BUFFER_UPDATE_BARRIER_BIT;
+        /// Framebuffer reads and writes.
+        pub const FRAMEBUFFER = FRAMEBUFFER_BARRIER_BIT;
+        /// Transform feedback writes.
+        pub const TRANSFORM_FEEDBACK = TRANSFORM_FEEDBACK_BARRIER_BIT;
+        /// Atomic counter operations.
+        pub const ATOMIC_COUNTER = ATOMIC_COUNTER_BARRIER_BIT;
+        /// Shader storage block accesses.
+        pub const SHADER_STORAGE = SHADER_STORAGE_BARRIER_BIT;
+        /// Client-side access to persistently mapped buffers that are not coherent.
+        pub const CLIENT_MAPPED_BUFFER = CLIENT_MAPPED_BUFFER_BARRIER_BIT;
+        /// Query results written to buffers.
+        pub const QUERY_BUFFER = QUERY_BUFFER_BARRIER_BIT;
+        /// Every kind of access.
+        pub const ALL = ALL_BARRIER_BITS;
+    }
+}
@@ -0,0 +1,9 @@
+//! Enums and bitfields generated straight from the groups in the OpenGL registry.
+//!
+//! Only the groups on the allow-list in the build script (see `build/rename.rs`) are generated. Their variants are only
+//! documented with the name of the OpenGL value they map to; see the OpenGL reference pages for what each one means.
+
+use crate::macros::{gl_bitfield, gl_enum};
+
+
+include!(concat!(env!("OUT_DIR"), "/groups.rs"));
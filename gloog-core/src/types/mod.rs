@@ -1,8 +1,20 @@
+mod capabilities;
 mod enums;
 mod flags;
+mod groups;
+mod pipeline;
+mod sampler;
 
+use std::marker::PhantomData;
+
+use bytemuck::{Pod, Zeroable};
+
+pub use self::capabilities::*;
 pub use self::enums::*;
 pub use self::flags::*;
+pub use self::groups::*;
+pub use self::pipeline::*;
+pub use self::sampler::*;
 use crate::macros::gl_newtype;
 use crate::raw::types::*;
 
@@ -10,6 +22,19 @@ gl_newtype!(pub struct BufferID(GLuint));
 gl_newtype!(pub struct ShaderID(GLuint));
 gl_newtype!(pub struct ProgramID(GLuint));
 gl_newtype!(pub struct VertexArrayID(GLuint));
+gl_newtype!(pub struct TextureID(GLuint));
+gl_newtype!(pub struct SamplerID(GLuint));
+gl_newtype!(pub struct FramebufferID(GLuint));
+gl_newtype!(pub struct RenderbufferID(GLuint));
+gl_newtype!(pub struct SyncID(GLsync));
+gl_newtype!(pub struct QueryID(GLuint));
+
+#[cfg(feature = "arb-bindless-texture")]
+gl_newtype! {
+    /// A handle to a texture (and optionally a sampler) that shaders can sample from without binding it to a texture
+    /// unit. Handles come from `GL_ARB_bindless_texture`, and must be made resident before they are used.
+    pub struct TextureHandle(GLuint64);
+}
 
 gl_newtype!(pub struct UniformLocation(pub(crate) GLint));
 gl_newtype!(pub struct VertexAttribLocation(pub GLuint));
@@ -22,6 +47,55 @@ impl UniformLocation {
     }
 }
 
+impl FramebufferID {
+    /// The default framebuffer, which is provided by the windowing system.
+    pub const DEFAULT: FramebufferID = FramebufferID(0);
+}
+
+
+/// IDs of OpenGL objects which can be given a human-readable label with [`GLContext::object_label`].
+///
+/// Labels show up in debug messages and in graphics debuggers like RenderDoc, in place of raw object names.
+///
+/// [`GLContext::object_label`]: crate::GLContext::object_label
+#[cfg(feature = "gl45")]
+pub trait LabeledObject: Copy {
+    /// The kind of object that this ID refers to.
+    const IDENTIFIER: ObjectIdentifier;
+
+    /// Returns the raw name of this object, to be passed to `glObjectLabel`.
+    fn raw_name(self) -> GLuint;
+}
+
+#[cfg(feature = "gl45")]
+macro_rules! labeled_objects {
+    ($($id_type:ty => $identifier:ident),*$(,)?) => {
+        $(
+            impl LabeledObject for $id_type {
+                const IDENTIFIER: ObjectIdentifier = ObjectIdentifier::$identifier;
+
+                fn raw_name(self) -> GLuint {
+                    self.into_raw()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "gl45")]
+labeled_objects! {
+    BufferID => Buffer,
+    ShaderID => Shader,
+    ProgramID => Program,
+    VertexArrayID => VertexArray,
+    QueryID => Query,
+    SamplerID => Sampler,
+    TextureID => Texture,
+    RenderbufferID => Renderbuffer,
+    FramebufferID => Framebuffer,
+}
+
+
 impl Default for UniformLocation {
     fn default() -> Self {
         UniformLocation(-1)
@@ -29,6 +103,77 @@ impl Default for UniformLocation {
 }
 
 
+/// A [`UniformLocation`] that has been checked to refer to a uniform whose GLSL type can be set from a `T`.
+///
+/// These are created with [`GLContext::get_typed_uniform_location`], and set with [`GLContext::typed_uniform`], which
+/// only accepts values of type `T`. If the uniform is an array, the location refers to its first element, and
+/// [`GLContext::typed_uniform_array`] can be used to set several elements at once.
+///
+/// [`GLContext::get_typed_uniform_location`]: crate::GLContext::get_typed_uniform_location
+/// [`GLContext::typed_uniform`]: crate::GLContext::typed_uniform
+/// [`GLContext::typed_uniform_array`]: crate::GLContext::typed_uniform_array
+pub struct TypedUniformLocation<T> {
+    location: UniformLocation,
+    array_size: usize,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<T> TypedUniformLocation<T> {
+    /// Wraps a location that has already been checked against a program.
+    #[cfg(feature = "gl45")]
+    pub(crate) const fn new(location: UniformLocation, array_size: usize) -> Self {
+        let phantom = PhantomData;
+        Self { location, array_size, phantom }
+    }
+
+    /// Returns the underlying, untyped location.
+    pub const fn untyped(&self) -> UniformLocation {
+        self.location
+    }
+
+    /// Returns the number of array elements that can be set through this location, starting from the one it refers to;
+    /// or 1 if the uniform is not an array.
+    pub const fn array_size(&self) -> usize {
+        self.array_size
+    }
+}
+
+// Manual implementations to avoid `T: Clone`-style bounds.
+
+impl<T> Clone for TypedUniformLocation<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedUniformLocation<T> {}
+
+impl<T> PartialEq for TypedUniformLocation<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location && self.array_size == other.array_size
+    }
+}
+
+impl<T> Eq for TypedUniformLocation<T> {}
+
+impl<T> std::hash::Hash for TypedUniformLocation<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.location.hash(state);
+        self.array_size.hash(state);
+    }
+}
+
+impl<T> std::fmt::Debug for TypedUniformLocation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TypedUniformLocation")
+            .field("location", &self.location)
+            .field("array_size", &self.array_size)
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+
 impl Into<VertexAttribLocation> for GLuint {
     fn into(self) -> VertexAttribLocation {
         VertexAttribLocation(self)
@@ -36,6 +181,7 @@ impl Into<VertexAttribLocation> for GLuint {
 }
 
 
+#[cfg(feature = "gl45")]
 #[derive(Debug, Clone)]
 pub struct DebugMessage {
     pub id: u32,
@@ -45,18 +191,21 @@ pub struct DebugMessage {
     pub body: String,
 }
 
+#[cfg(feature = "gl45")]
 impl DebugMessage {
     pub fn as_str(&self) -> &str {
         &self.body[..]
     }
 }
 
+#[cfg(feature = "gl45")]
 impl AsRef<str> for DebugMessage {
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
+#[cfg(feature = "gl45")]
 #[derive(Debug, Clone, Copy)]
 pub enum DebugFilter<'a> {
     /// Enable or disable all messages whose source, type, and severity all match the provided values; a value of `None`
@@ -73,3 +222,240 @@ pub enum DebugFilter<'a> {
         ids: &'a [GLuint],
     },
 }
+
+
+/// The reasons that a framebuffer may fail a completeness check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FramebufferError {
+    /// The default framebuffer was checked, but it does not exist.
+    Undefined,
+    /// One or more of the framebuffer's attachments are incomplete, such as a texture with no storage or a depth
+    /// format attached to a color attachment point.
+    IncompleteAttachment,
+    /// The framebuffer does not have any images attached to it.
+    MissingAttachment,
+    /// One of the selected draw buffers has no image attached to it.
+    IncompleteDrawBuffer,
+    /// The selected read buffer has no image attached to it.
+    IncompleteReadBuffer,
+    /// The combination of attached image formats is not supported by the implementation.
+    Unsupported,
+    /// The attached images do not all have the same number of samples, or do not all have the same fixed sample
+    /// locations setting.
+    IncompleteMultisample,
+    /// Some attachments are layered and others are not, or the layered attachments do not all have the same target.
+    IncompleteLayerTargets,
+    /// OpenGL returned a status that was not recognized. A status of zero means that an error occurred while checking
+    /// completeness.
+    Unknown(u32),
+}
+
+impl FramebufferError {
+    /// Converts the result of `glCheckNamedFramebufferStatus` into a `Result`.
+    #[cfg(feature = "gl45")]
+    pub(crate) fn check(status: u32) -> Result<(), Self> {
+        use crate::raw::*;
+        match status {
+            FRAMEBUFFER_COMPLETE => Ok(()),
+            FRAMEBUFFER_UNDEFINED => Err(Self::Undefined),
+            FRAMEBUFFER_INCOMPLETE_ATTACHMENT => Err(Self::IncompleteAttachment),
+            FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => Err(Self::MissingAttachment),
+            FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => Err(Self::IncompleteDrawBuffer),
+            FRAMEBUFFER_INCOMPLETE_READ_BUFFER => Err(Self::IncompleteReadBuffer),
+            FRAMEBUFFER_UNSUPPORTED => Err(Self::Unsupported),
+            FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => Err(Self::IncompleteMultisample),
+            FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => Err(Self::IncompleteLayerTargets),
+            other => Err(Self::Unknown(other)),
+        }
+    }
+}
+
+impl std::fmt::Display for FramebufferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Undefined => write!(f, "framebuffer is undefined"),
+            Self::IncompleteAttachment => write!(f, "framebuffer has an incomplete attachment"),
+            Self::MissingAttachment => write!(f, "framebuffer has no attachments"),
+            Self::IncompleteDrawBuffer => write!(f, "framebuffer draw buffer has no attachment"),
+            Self::IncompleteReadBuffer => write!(f, "framebuffer read buffer has no attachment"),
+            Self::Unsupported => write!(f, "framebuffer attachment formats are unsupported"),
+            Self::IncompleteMultisample => write!(f, "framebuffer attachments have mismatched multisampling"),
+            Self::IncompleteLayerTargets => write!(f, "framebuffer attachments have mismatched layering"),
+            Self::Unknown(0) => write!(f, "error while checking framebuffer completeness"),
+            Self::Unknown(status) => write!(f, "unknown framebuffer status {status:#06x}"),
+        }
+    }
+}
+
+impl std::error::Error for FramebufferError {}
+
+
+/// An active uniform in a linked program, as reported by [`GLContext::get_active_uniforms`].
+///
+/// [`GLContext::get_active_uniforms`]: crate::GLContext::get_active_uniforms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveUniform {
+    /// The name of the uniform. Arrays are reported with a `[0]` suffix, and members of structs are reported
+    /// individually, such as `material.diffuse` or `lights[0].position`.
+    pub name: String,
+    /// The type of the uniform, or `None` if it is not a type that this crate recognizes.
+    pub ty: Option<GlslType>,
+    /// The number of elements in the uniform if it is an array, or 1 if it is not.
+    pub array_size: usize,
+    /// The location of the uniform. Uniforms in named uniform blocks do not have locations.
+    pub location: Option<UniformLocation>,
+    /// The index of the uniform block that this uniform belongs to, or `None` if it is in the default block.
+    pub block_index: Option<u32>,
+    /// The offset of this uniform from the start of its uniform block, in bytes, if it is in one.
+    pub offset: Option<usize>,
+    /// The distance in bytes between the elements of this uniform if it is an array in a uniform block.
+    pub array_stride: Option<usize>,
+    /// The distance in bytes between the columns of this uniform if it is a matrix in a uniform block.
+    pub matrix_stride: Option<usize>,
+}
+
+
+/// An active vertex attribute in a linked program, as reported by [`GLContext::get_active_attributes`].
+///
+/// [`GLContext::get_active_attributes`]: crate::GLContext::get_active_attributes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveAttribute {
+    /// The name of the attribute.
+    pub name: String,
+    /// The type of the attribute, or `None` if it is not a type that this crate recognizes.
+    pub ty: Option<GlslType>,
+    /// The number of elements in the attribute if it is an array, or 1 if it is not.
+    pub array_size: usize,
+    /// The location of the attribute. Built-in inputs like `gl_VertexID` do not have locations.
+    pub location: Option<VertexAttribLocation>,
+}
+
+
+/// An active uniform block or shader storage block in a linked program, as reported by
+/// [`GLContext::get_active_uniform_blocks`] or [`GLContext::get_active_shader_storage_blocks`].
+///
+/// [`GLContext::get_active_uniform_blocks`]: crate::GLContext::get_active_uniform_blocks
+/// [`GLContext::get_active_shader_storage_blocks`]: crate::GLContext::get_active_shader_storage_blocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveBlock {
+    /// The name of the block (not its instance name).
+    pub name: String,
+    /// The index of the block within its program.
+    pub index: u32,
+    /// The indexed binding point that the block reads from.
+    pub binding: u32,
+    /// The minimum size of the buffer range bound to the block, in bytes.
+    pub data_size: usize,
+    /// The number of active variables in the block.
+    pub num_variables: usize,
+}
+
+
+/// An active variable within a shader storage block, as reported by [`GLContext::get_active_buffer_variables`].
+///
+/// [`GLContext::get_active_buffer_variables`]: crate::GLContext::get_active_buffer_variables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveBufferVariable {
+    /// The name of the variable, including the name of the block's instance if it has one.
+    pub name: String,
+    /// The type of the variable, or `None` if it is not a type that this crate recognizes.
+    pub ty: Option<GlslType>,
+    /// The number of elements in the variable if it is an array, or 1 if it is not. Zero for a runtime-sized array.
+    pub array_size: usize,
+    /// The index of the shader storage block that this variable belongs to.
+    pub block_index: u32,
+    /// The offset of this variable from the start of its block, in bytes.
+    pub offset: usize,
+    /// The distance in bytes between the elements of this variable if it is an array.
+    pub array_stride: Option<usize>,
+    /// The distance in bytes between the columns of this variable if it is a matrix.
+    pub matrix_stride: Option<usize>,
+}
+
+
+/// The reasons that [`GLContext::get_typed_uniform_location`] may fail.
+///
+/// [`GLContext::get_typed_uniform_location`]: crate::GLContext::get_typed_uniform_location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UniformLocationError {
+    /// The program has no active uniform with the given name in its default uniform block. The uniform may be
+    /// misspelled, in a named uniform block, or unused and optimized away.
+    NotFound(String),
+    /// The uniform exists, but its GLSL type cannot be set from the requested Rust type.
+    TypeMismatch {
+        /// The name of the uniform.
+        name: String,
+        /// The GLSL type of the uniform, or `None` if it is not a type that this crate recognizes.
+        glsl_type: Option<GlslType>,
+        /// The name of the requested Rust type.
+        rust_type: &'static str,
+    },
+}
+
+impl std::fmt::Display for UniformLocationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(name) => write!(f, "no active uniform named `{name}`"),
+            Self::TypeMismatch { name, glsl_type, rust_type } => match glsl_type {
+                Some(ty) => write!(f, "uniform `{name}` has type {ty:?}, which cannot be set from a `{rust_type}`"),
+                None => write!(f, "uniform `{name}` has an unknown type, which cannot be set from a `{rust_type}`"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for UniformLocationError {}
+
+
+/// The parameters of a single draw in an indirect [`draw_arrays_indirect`] or [`multi_draw_arrays_indirect`] call,
+/// laid out as OpenGL expects to find them in a buffer bound to [`BufferTarget::DrawIndirectBuffer`].
+///
+/// [`draw_arrays_indirect`]: crate::GLContext::draw_arrays_indirect
+/// [`multi_draw_arrays_indirect`]: crate::GLContext::multi_draw_arrays_indirect
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+pub struct DrawArraysIndirectCommand {
+    /// The number of vertices to draw.
+    pub count: u32,
+    /// The number of instances to draw.
+    pub instance_count: u32,
+    /// The index of the first vertex to draw.
+    pub first: u32,
+    /// The instance number of the first instance, which affects which values are read from instanced attributes.
+    pub base_instance: u32,
+}
+
+/// The parameters of a single draw in an indirect [`draw_elements_indirect`] or [`multi_draw_elements_indirect`]
+/// call, laid out as OpenGL expects to find them in a buffer bound to [`BufferTarget::DrawIndirectBuffer`].
+///
+/// [`draw_elements_indirect`]: crate::GLContext::draw_elements_indirect
+/// [`multi_draw_elements_indirect`]: crate::GLContext::multi_draw_elements_indirect
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+pub struct DrawElementsIndirectCommand {
+    /// The number of indices to draw.
+    pub count: u32,
+    /// The number of instances to draw.
+    pub instance_count: u32,
+    /// The position of the first index to draw in the element buffer, counted in indices (**not** bytes).
+    pub first_index: u32,
+    /// A constant that is added to each index before reading from the vertex buffers.
+    pub base_vertex: i32,
+    /// The instance number of the first instance, which affects which values are read from instanced attributes.
+    pub base_instance: u32,
+}
+
+/// The parameters of an indirect [`dispatch_compute_indirect`] call, laid out as OpenGL expects to find them in a
+/// buffer bound to [`BufferTarget::DispatchIndirectBuffer`].
+///
+/// [`dispatch_compute_indirect`]: crate::GLContext::dispatch_compute_indirect
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+pub struct DispatchIndirectCommand {
+    /// The number of work groups to dispatch in the X dimension.
+    pub num_groups_x: u32,
+    /// The number of work groups to dispatch in the Y dimension.
+    pub num_groups_y: u32,
+    /// The number of work groups to dispatch in the Z dimension.
+    pub num_groups_z: u32,
+}
@@ -0,0 +1,352 @@
+//! Immutable descriptions of the fixed-function state used for drawing.
+
+use super::*;
+
+
+/// A complete description of the state that affects a draw call: which program and vertex array are bound, and how
+/// fragments are blended, depth-tested, stencil-tested, and rasterized.
+///
+/// Pipeline states are plain values that are built once, up front, and then applied to the context with
+/// [`GLContext::apply_pipeline_state`]. The context remembers the last state that was applied, and only issues the
+/// OpenGL calls for the parts of a new state that differ from it; so applying the same state many times in a row is
+/// cheap. The currently applied state can be inspected with [`GLContext::pipeline_state`].
+///
+/// Every field defaults to OpenGL's own initial state, except that no program or vertex array is bound.
+///
+/// ```ignore
+/// let opaque = PipelineState::new()
+///     .with_program(program)
+///     .with_vertex_array(vao)
+///     .with_depth(DepthState::LESS);
+///
+/// let translucent = opaque.with_blend(BlendState::ALPHA).with_depth(DepthState::READ_ONLY);
+///
+/// gl.apply_pipeline_state(&opaque);
+/// // draw opaque things...
+/// gl.apply_pipeline_state(&translucent); // only enables blending and disables depth writes
+/// // draw translucent things...
+/// ```
+///
+/// [`GLContext::apply_pipeline_state`]: crate::GLContext::apply_pipeline_state
+/// [`GLContext::pipeline_state`]: crate::GLContext::pipeline_state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PipelineState {
+    program: Option<ProgramID>,
+    vertex_array: Option<VertexArrayID>,
+    blend: BlendState,
+    depth: DepthState,
+    stencil: StencilState,
+    raster: RasterState,
+}
+
+impl PipelineState {
+    /// Creates a pipeline state with OpenGL's default fixed-function state and nothing bound.
+    pub const fn new() -> Self {
+        Self {
+            program: None,
+            vertex_array: None,
+            blend: BlendState::DISABLED,
+            depth: DepthState::DISABLED,
+            stencil: StencilState::DISABLED,
+            raster: RasterState::DEFAULT,
+        }
+    }
+
+    /// Returns a copy of this state that uses the given program.
+    pub const fn with_program(self, program: ProgramID) -> Self {
+        Self { program: Some(program), ..self }
+    }
+
+    /// Returns a copy of this state that uses the given vertex array.
+    pub const fn with_vertex_array(self, vertex_array: VertexArrayID) -> Self {
+        Self {
+            vertex_array: Some(vertex_array),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this state with different blending state.
+    pub const fn with_blend(self, blend: BlendState) -> Self {
+        Self { blend, ..self }
+    }
+
+    /// Returns a copy of this state with different depth state.
+    pub const fn with_depth(self, depth: DepthState) -> Self {
+        Self { depth, ..self }
+    }
+
+    /// Returns a copy of this state with different stencil state.
+    pub const fn with_stencil(self, stencil: StencilState) -> Self {
+        Self { stencil, ..self }
+    }
+
+    /// Returns a copy of this state with different rasterization state.
+    pub const fn with_raster(self, raster: RasterState) -> Self {
+        Self { raster, ..self }
+    }
+
+    /// The program used for drawing, if any.
+    pub const fn program(&self) -> Option<ProgramID> {
+        self.program
+    }
+
+    /// The vertex array used for drawing, if any.
+    pub const fn vertex_array(&self) -> Option<VertexArrayID> {
+        self.vertex_array
+    }
+
+    /// How fragments are blended into the framebuffer.
+    pub const fn blend(&self) -> &BlendState {
+        &self.blend
+    }
+
+    /// How fragments are depth-tested.
+    pub const fn depth(&self) -> &DepthState {
+        &self.depth
+    }
+
+    /// How fragments are stencil-tested.
+    pub const fn stencil(&self) -> &StencilState {
+        &self.stencil
+    }
+
+    /// How primitives are rasterized.
+    pub const fn raster(&self) -> &RasterState {
+        &self.raster
+    }
+}
+
+impl Default for PipelineState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// Blending state, applied to all draw buffers. See [`GLContext::blend_func_separate`].
+///
+/// [`GLContext::blend_func_separate`]: crate::GLContext::blend_func_separate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlendState {
+    /// Whether blending is enabled at all. When it is not, the rest of the fields are not applied.
+    pub enabled: bool,
+    pub src_rgb: BlendFactor,
+    pub dst_rgb: BlendFactor,
+    pub src_alpha: BlendFactor,
+    pub dst_alpha: BlendFactor,
+    pub equation_rgb: BlendEquation,
+    pub equation_alpha: BlendEquation,
+    /// The constant blend color.
+    pub color: [f32; 4],
+}
+
+impl BlendState {
+    /// Blending disabled; fragments replace what is in the framebuffer.
+    pub const DISABLED: Self = Self {
+        enabled: false,
+        src_rgb: BlendFactor::One,
+        dst_rgb: BlendFactor::Zero,
+        src_alpha: BlendFactor::One,
+        dst_alpha: BlendFactor::Zero,
+        equation_rgb: BlendEquation::Add,
+        equation_alpha: BlendEquation::Add,
+        color: [0.0; 4],
+    };
+
+    /// Standard transparency: `src × src.a + dst × (1 - src.a)`.
+    pub const ALPHA: Self = Self::new(BlendFactor::SrcAlpha, BlendFactor::OneMinusSrcAlpha);
+
+    /// Transparency for colors with premultiplied alpha: `src + dst × (1 - src.a)`.
+    pub const PREMULTIPLIED_ALPHA: Self = Self::new(BlendFactor::One, BlendFactor::OneMinusSrcAlpha);
+
+    /// Additive blending: `src + dst`.
+    pub const ADDITIVE: Self = Self::new(BlendFactor::One, BlendFactor::One);
+
+    /// Enabled blending with the same factors for the color and alpha components, added together.
+    pub const fn new(src: BlendFactor, dst: BlendFactor) -> Self {
+        Self {
+            enabled: true,
+            src_rgb: src,
+            dst_rgb: dst,
+            src_alpha: src,
+            dst_alpha: dst,
+            ..Self::DISABLED
+        }
+    }
+}
+
+impl Default for BlendState {
+    fn default() -> Self {
+        Self::DISABLED
+    }
+}
+
+
+/// Depth testing state. See [`GLContext::depth_func`].
+///
+/// [`GLContext::depth_func`]: crate::GLContext::depth_func
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DepthState {
+    /// Whether the depth test is enabled. When it is not, fragments do not write their depth either, and `func` is not
+    /// applied.
+    pub test: bool,
+    /// Whether passing fragments write their depth into the depth buffer. This is applied even when the test is
+    /// disabled, since it also controls whether [`GLContext::clear`] clears the depth buffer.
+    ///
+    /// [`GLContext::clear`]: crate::GLContext::clear
+    pub write: bool,
+    pub func: CompareFunction,
+}
+
+impl DepthState {
+    /// Depth testing disabled.
+    pub const DISABLED: Self = Self {
+        test: false,
+        write: true,
+        func: CompareFunction::Less,
+    };
+
+    /// The usual depth test: nearer fragments pass, and write their depth.
+    pub const LESS: Self = Self {
+        test: true,
+        write: true,
+        func: CompareFunction::Less,
+    };
+
+    /// Like [`LESS`][Self::LESS], but fragments at an equal depth also pass, and the depth buffer is not written to.
+    /// This is useful after a depth pre-pass.
+    pub const LESS_EQUAL_READ_ONLY: Self = Self {
+        test: true,
+        write: false,
+        func: CompareFunction::LessEqual,
+    };
+
+    /// Depth tested, but not written; the usual state for drawing translucent objects.
+    pub const READ_ONLY: Self = Self {
+        test: true,
+        write: false,
+        func: CompareFunction::Less,
+    };
+}
+
+impl Default for DepthState {
+    fn default() -> Self {
+        Self::DISABLED
+    }
+}
+
+
+/// Stencil testing state. See [`GLContext::stencil_func_separate`].
+///
+/// [`GLContext::stencil_func_separate`]: crate::GLContext::stencil_func_separate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StencilState {
+    /// Whether the stencil test is enabled. When it is not, the rest of the fields are not applied, except for the
+    /// write masks.
+    pub enabled: bool,
+    /// The stencil state used for front-facing polygons (and all non-polygon primitives).
+    pub front: StencilFaceState,
+    /// The stencil state used for back-facing polygons.
+    pub back: StencilFaceState,
+}
+
+impl StencilState {
+    /// Stencil testing disabled.
+    pub const DISABLED: Self = Self {
+        enabled: false,
+        front: StencilFaceState::DEFAULT,
+        back: StencilFaceState::DEFAULT,
+    };
+
+    /// Enabled stencil testing, with the same state for both faces.
+    pub const fn new(face: StencilFaceState) -> Self {
+        Self {
+            enabled: true,
+            front: face,
+            back: face,
+        }
+    }
+}
+
+impl Default for StencilState {
+    fn default() -> Self {
+        Self::DISABLED
+    }
+}
+
+
+/// The stencil state for one polygon face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StencilFaceState {
+    pub func: CompareFunction,
+    pub reference: i32,
+    /// The mask that the reference and stored values are ANDed with before they are compared.
+    pub read_mask: u32,
+    /// The mask of bits that may be written into the stencil buffer, either by the stencil test or by
+    /// [`GLContext::clear`]. This is applied even when the stencil test is disabled.
+    ///
+    /// [`GLContext::clear`]: crate::GLContext::clear
+    pub write_mask: u32,
+    pub stencil_fail: StencilOp,
+    pub depth_fail: StencilOp,
+    pub pass: StencilOp,
+}
+
+impl StencilFaceState {
+    /// OpenGL's initial stencil state: always passes, and never modifies the stencil buffer.
+    pub const DEFAULT: Self = Self {
+        func: CompareFunction::Always,
+        reference: 0,
+        read_mask: !0,
+        write_mask: !0,
+        stencil_fail: StencilOp::Keep,
+        depth_fail: StencilOp::Keep,
+        pass: StencilOp::Keep,
+    };
+}
+
+impl Default for StencilFaceState {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+
+/// Rasterization state: face culling, polygon mode and offset, scissoring, and color writes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterState {
+    /// Which faces are culled, or `None` to disable face culling.
+    pub cull_face: Option<TriangleFace>,
+    pub front_face: FrontFaceDirection,
+    pub polygon_mode: PolygonMode,
+    /// The `factor` and `units` of the polygon offset applied to filled polygons, or `None` to disable it.
+    pub polygon_offset: Option<(f32, f32)>,
+    /// The scissor rectangle, as `[x, y, width, height]`, or `None` to disable the scissor test.
+    pub scissor: Option<[i32; 4]>,
+    /// Which of the red, green, blue, and alpha components may be written to.
+    pub color_mask: [bool; 4],
+}
+
+impl RasterState {
+    /// OpenGL's initial rasterization state.
+    pub const DEFAULT: Self = Self {
+        cull_face: None,
+        front_face: FrontFaceDirection::CCW,
+        polygon_mode: PolygonMode::Fill,
+        polygon_offset: None,
+        scissor: None,
+        color_mask: [true; 4],
+    };
+
+    /// The default state, with back faces culled.
+    pub const CULL_BACK: Self = Self {
+        cull_face: Some(TriangleFace::Back),
+        ..Self::DEFAULT
+    };
+}
+
+impl Default for RasterState {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,194 @@
+//! Descriptions of texture sampling state, for configuring sampler objects.
+
+use std::hash::{Hash, Hasher};
+
+use super::*;
+
+
+/// The color returned when sampling outside of a texture with [`TextureWrap::ClampToBorder`].
+#[derive(Debug, Clone, Copy, Default)]
+pub enum BorderColor {
+    /// `(0, 0, 0, 0)`. This is OpenGL's default.
+    #[default]
+    TransparentBlack,
+    /// `(0, 0, 0, 1)`.
+    OpaqueBlack,
+    /// `(1, 1, 1, 1)`.
+    OpaqueWhite,
+    /// Any other color, for textures with floating-point or normalized formats.
+    Float([f32; 4]),
+    /// A color for textures with signed integer formats.
+    Int([i32; 4]),
+    /// A color for textures with unsigned integer formats.
+    UnsignedInt([u32; 4]),
+}
+
+impl BorderColor {
+    /// Returns the raw bits of each component of this color, for comparing and hashing.
+    const fn to_bits(self) -> (u8, [u32; 4]) {
+        match self {
+            Self::TransparentBlack => Self::Float([0.0, 0.0, 0.0, 0.0]).to_bits(),
+            Self::OpaqueBlack => Self::Float([0.0, 0.0, 0.0, 1.0]).to_bits(),
+            Self::OpaqueWhite => Self::Float([1.0, 1.0, 1.0, 1.0]).to_bits(),
+            Self::Float([r, g, b, a]) => (0, [r.to_bits(), g.to_bits(), b.to_bits(), a.to_bits()]),
+            Self::Int([r, g, b, a]) => (1, [r as u32, g as u32, b as u32, a as u32]),
+            Self::UnsignedInt(color) => (2, color),
+        }
+    }
+}
+
+// Floating-point colors are compared bit-for-bit, so that they can be used as keys in a hash map.
+
+impl PartialEq for BorderColor {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl Eq for BorderColor {}
+
+impl Hash for BorderColor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+}
+
+
+/// A complete description of the state of a sampler object: how a texture is filtered, how coordinates outside of it
+/// are wrapped, and how depth textures are compared.
+///
+/// Descriptions are plain values which implement [`Eq`] and [`Hash`] (with floating-point fields compared bit-for-bit),
+/// so that identical samplers can be shared through a cache such as [`SamplerCache`][crate::objects::SamplerCache]
+/// rather than being created over and over. A sampler object can be created from a description with
+/// [`GLContext::create_sampler_from_desc`].
+///
+/// Every field defaults to OpenGL's initial state for sampler objects.
+///
+/// ```ignore
+/// let desc = SamplerDesc::new()
+///     .with_filter(TextureMinFilter::LinearMipmapLinear, TextureMagFilter::Linear)
+///     .with_wrap(TextureWrap::ClampToEdge)
+///     .with_max_anisotropy(8.0);
+///
+/// let sampler = gl.create_sampler_from_desc(&desc);
+/// gl.bind_sampler(0, sampler);
+/// ```
+///
+/// [`GLContext::create_sampler_from_desc`]: crate::GLContext::create_sampler_from_desc
+#[derive(Debug, Clone, Copy)]
+pub struct SamplerDesc {
+    pub min_filter: TextureMinFilter,
+    pub mag_filter: TextureMagFilter,
+    /// How each texture coordinate is wrapped, in `[s, t, r]` order.
+    pub wrap: [TextureWrap; 3],
+    /// The comparison used when sampling depth textures, or `None` to return depth values directly.
+    pub compare: Option<CompareFunction>,
+    /// A bias added to the level of detail computed for each sample, before it is clamped to `lod_range`.
+    pub lod_bias: f32,
+    /// The range, as `[min, max]`, that the level of detail of each sample is clamped to.
+    pub lod_range: [f32; 2],
+    /// The maximum degree of anisotropy used when filtering. A value of `1.0` disables anisotropic filtering.
+    pub max_anisotropy: f32,
+    pub border_color: BorderColor,
+}
+
+impl SamplerDesc {
+    /// OpenGL's initial sampler state: trilinear filtering with repeating coordinates.
+    pub const DEFAULT: Self = Self {
+        min_filter: TextureMinFilter::NearestMipmapLinear,
+        mag_filter: TextureMagFilter::Linear,
+        wrap: [TextureWrap::Repeat; 3],
+        compare: None,
+        lod_bias: 0.0,
+        lod_range: [-1000.0, 1000.0],
+        max_anisotropy: 1.0,
+        border_color: BorderColor::TransparentBlack,
+    };
+
+    /// Creates a description of OpenGL's initial sampler state.
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Returns a copy of this description with different minification and magnification filters.
+    pub const fn with_filter(self, min_filter: TextureMinFilter, mag_filter: TextureMagFilter) -> Self {
+        Self { min_filter, mag_filter, ..self }
+    }
+
+    /// Returns a copy of this description with the same wrapping mode for every texture coordinate.
+    pub const fn with_wrap(self, mode: TextureWrap) -> Self {
+        Self { wrap: [mode; 3], ..self }
+    }
+
+    /// Returns a copy of this description with a different wrapping mode for one texture coordinate.
+    pub const fn with_wrap_axis(self, axis: TextureWrapAxis, mode: TextureWrap) -> Self {
+        let mut wrap = self.wrap;
+        match axis {
+            TextureWrapAxis::S => wrap[0] = mode,
+            TextureWrapAxis::T => wrap[1] = mode,
+            TextureWrapAxis::R => wrap[2] = mode,
+        }
+        Self { wrap, ..self }
+    }
+
+    /// Returns a copy of this description that compares depth textures with the given function.
+    pub const fn with_compare(self, func: CompareFunction) -> Self {
+        Self { compare: Some(func), ..self }
+    }
+
+    /// Returns a copy of this description with a different level-of-detail bias.
+    pub const fn with_lod_bias(self, lod_bias: f32) -> Self {
+        Self { lod_bias, ..self }
+    }
+
+    /// Returns a copy of this description with a different range of levels of detail.
+    pub const fn with_lod_range(self, min: f32, max: f32) -> Self {
+        Self { lod_range: [min, max], ..self }
+    }
+
+    /// Returns a copy of this description with a different maximum degree of anisotropy.
+    pub const fn with_max_anisotropy(self, max_anisotropy: f32) -> Self {
+        Self { max_anisotropy, ..self }
+    }
+
+    /// Returns a copy of this description with a different border color.
+    pub const fn with_border_color(self, border_color: BorderColor) -> Self {
+        Self { border_color, ..self }
+    }
+
+    /// Returns the raw bits of this description's floating-point fields, for comparing and hashing.
+    fn float_bits(&self) -> [u32; 4] {
+        let [min_lod, max_lod] = self.lod_range;
+        [self.lod_bias, min_lod, max_lod, self.max_anisotropy].map(f32::to_bits)
+    }
+}
+
+impl Default for SamplerDesc {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl PartialEq for SamplerDesc {
+    fn eq(&self, other: &Self) -> bool {
+        self.min_filter == other.min_filter
+            && self.mag_filter == other.mag_filter
+            && self.wrap == other.wrap
+            && self.compare == other.compare
+            && self.float_bits() == other.float_bits()
+            && self.border_color == other.border_color
+    }
+}
+
+impl Eq for SamplerDesc {}
+
+impl Hash for SamplerDesc {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.min_filter.hash(state);
+        self.mag_filter.hash(state);
+        self.wrap.hash(state);
+        self.compare.hash(state);
+        self.float_bits().hash(state);
+        self.border_color.hash(state);
+    }
+}
@@ -0,0 +1,263 @@
+//! Descriptions of the layout of vertex data, for configuring vertex array objects.
+//!
+//! Rather than setting up every vertex attribute by hand with the offsets of each field, a `#[repr(C)]` vertex struct
+//! can implement [`VertexLayout`] to list its attributes once; then a vertex array can be configured for it in a single
+//! call to [`GLContext::vertex_array_layout`][crate::GLContext::vertex_array_layout]. The trait is normally implemented
+//! with the [`vertex_layout!`][crate::vertex_layout] macro:
+//!
+//! ```ignore
+//! #[repr(C)]
+//! #[derive(Clone, Copy, Zeroable, Pod)]
+//! struct Vertex {
+//!     position: Vec3,
+//!     normal: Vec3,
+//!     color: [u8; 4],
+//! }
+//!
+//! gloog_core::vertex_layout! {
+//!     Vertex {
+//!         0 => position,
+//!         1 => normal,
+//!         2 => color as Normalized,
+//!     }
+//! }
+//!
+//! gl.vertex_array_vertex_buffer(vao, 0, vbo, 0, std::mem::size_of::<Vertex>());
+//! gl.vertex_array_layout::<Vertex>(vao, 0);
+//! ```
+
+use bytemuck::Pod;
+use gloog_math::{Vec2, Vec3, Vec4};
+
+use crate::types::VertexAttribType;
+
+
+/// How the components of a vertex attribute are presented to a shader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttribKind {
+    /// The attribute is read as floating-point values, with integer components converted directly (`1` becomes `1.0`).
+    /// This corresponds to [`glVertexArrayAttribFormat`] with `normalized` set to false.
+    ///
+    /// [`glVertexArrayAttribFormat`]: crate::GLContext::vertex_array_attrib_format
+    Float,
+    /// The attribute is read as floating-point values, with integer components mapped to `[0, 1]` (for unsigned types)
+    /// or `[-1, 1]` (for signed types).
+    Normalized,
+    /// The attribute is read as integers, for `int`, `uint`, `ivecN`, and `uvecN` shader inputs. This corresponds to
+    /// [`glVertexArrayAttribIFormat`].
+    ///
+    /// [`glVertexArrayAttribIFormat`]: crate::GLContext::vertex_array_attrib_i_format
+    Integer,
+    /// The attribute is read as 64-bit doubles, for `double` and `dvecN` shader inputs. This corresponds to
+    /// [`glVertexArrayAttribLFormat`].
+    ///
+    /// [`glVertexArrayAttribLFormat`]: crate::GLContext::vertex_array_attrib_l_format
+    Double,
+}
+
+impl AttribKind {
+    /// Returns the way that components of the given type are read when no other kind is requested: integers as
+    /// integers, doubles as doubles, and everything else as floats.
+    pub const fn default_for(ty: VertexAttribType) -> Self {
+        use VertexAttribType::*;
+        match ty {
+            Byte | UnsignedByte | Short | UnsignedShort | Int | UnsignedInt => Self::Integer,
+            Double => Self::Double,
+            Float | HalfFloat | SignedIntFourPack | UnsignedIntFourPack | FloatThreePack => Self::Float,
+            #[cfg(feature = "gl41")]
+            Fixed => Self::Float,
+        }
+    }
+}
+
+
+/// The format and location of a single attribute within a vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VertexAttrib {
+    /// The attribute index that the shader reads this attribute from; its `layout(location = N)`.
+    pub location: u32,
+    /// The type of each of this attribute's components.
+    pub ty: VertexAttribType,
+    /// The number of components in this attribute, from 1 to 4.
+    pub size: usize,
+    /// How this attribute's components are presented to the shader.
+    pub kind: AttribKind,
+    /// The offset of this attribute from the start of the vertex, in bytes.
+    pub offset: usize,
+}
+
+impl VertexAttrib {
+    /// Describes the attribute stored in a given field of a vertex struct, using the field's [`VertexAttribute`]
+    /// implementation to determine its type and size. The `field` function is never called; it only exists to name
+    /// the field's type.
+    ///
+    /// This is used by the [`vertex_layout!`][crate::vertex_layout] macro.
+    pub const fn of_field<V, F: VertexAttribute>(
+        _field: fn(&V) -> &F,
+        location: u32,
+        offset: usize,
+        kind: Option<AttribKind>,
+    ) -> Self {
+        let kind = match kind {
+            Some(kind) => kind,
+            None => AttribKind::default_for(F::TYPE),
+        };
+
+        let (ty, size) = (F::TYPE, F::SIZE);
+        Self { location, ty, size, kind, offset }
+    }
+}
+
+
+/// A `#[repr(C)]` vertex type whose attributes can be read straight out of a vertex buffer.
+///
+/// This trait should generally be implemented with the [`vertex_layout!`][crate::vertex_layout] macro rather than by
+/// hand.
+pub trait VertexLayout: Pod {
+    /// All of the attributes in this vertex.
+    const ATTRIBUTES: &'static [VertexAttrib];
+
+    /// The distance between consecutive vertices in a buffer, in bytes.
+    const STRIDE: usize = std::mem::size_of::<Self>();
+}
+
+
+/// A type that can be stored as a single vertex attribute.
+///
+/// # Safety
+///
+/// The type must consist of exactly [`SIZE`][Self::SIZE] tightly packed components of type [`TYPE`][Self::TYPE].
+pub unsafe trait VertexAttribute: Pod {
+    /// The type of each component.
+    const TYPE: VertexAttribType;
+
+    /// The number of components, from 1 to 4.
+    const SIZE: usize;
+}
+
+
+macro_rules! impl_vertex_attribute {
+    ($rs_type:ty, $gl_type:ident) => {
+        impl_vertex_attribute!($rs_type, $gl_type, 1);
+        impl_vertex_attribute!([$rs_type; 1], $gl_type, 1);
+        impl_vertex_attribute!([$rs_type; 2], $gl_type, 2);
+        impl_vertex_attribute!([$rs_type; 3], $gl_type, 3);
+        impl_vertex_attribute!([$rs_type; 4], $gl_type, 4);
+    };
+    ($rs_type:ty, $gl_type:ident, $size:literal) => {
+        unsafe impl VertexAttribute for $rs_type {
+            const TYPE: VertexAttribType = VertexAttribType::$gl_type;
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+impl_vertex_attribute!(f32, Float);
+impl_vertex_attribute!(f64, Double);
+impl_vertex_attribute!(i8, Byte);
+impl_vertex_attribute!(u8, UnsignedByte);
+impl_vertex_attribute!(i16, Short);
+impl_vertex_attribute!(u16, UnsignedShort);
+impl_vertex_attribute!(i32, Int);
+impl_vertex_attribute!(u32, UnsignedInt);
+
+impl_vertex_attribute!(Vec2, Float, 2);
+impl_vertex_attribute!(Vec3, Float, 3);
+impl_vertex_attribute!(Vec4, Float, 4);
+
+
+/// Implements [`VertexLayout`][crate::vertex::VertexLayout] for an existing `#[repr(C)]` vertex struct, by listing the
+/// attribute location of each of its fields.
+///
+/// Every listed field's type must implement [`VertexAttribute`][crate::vertex::VertexAttribute], and fields that are
+/// not listed are skipped over. By default, integer fields are read as integers; a field can be followed by
+/// `as <kind>`, with any [`AttribKind`][crate::vertex::AttribKind], to read it some other way (most often
+/// `as Normalized`, for colors stored as bytes).
+///
+/// # Syntax
+///
+/// ```ignore
+/// gloog_core::vertex_layout! {
+///     Vertex {
+///         0 => position,
+///         1 => normal,
+///         2 => color as Normalized,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! vertex_layout {
+    (
+        $name:ident {
+            $( $location:literal => $field:ident $(as $kind:ident)? ),*
+            $(,)?
+        }
+    ) => {
+        impl $crate::vertex::VertexLayout for $name {
+            const ATTRIBUTES: &'static [$crate::vertex::VertexAttrib] = &[
+                $(
+                    $crate::vertex::VertexAttrib::of_field(
+                        |v: &$name| &v.$field,
+                        $location,
+                        ::std::mem::offset_of!($name, $field),
+                        $crate::vertex_layout!(@kind $($kind)?),
+                    ),
+                )*
+            ];
+        }
+    };
+
+    (@kind) => (None);
+    (@kind $kind:ident) => (Some($crate::vertex::AttribKind::$kind));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::mem::{offset_of, size_of};
+
+    use bytemuck::{Pod, Zeroable};
+
+    use super::*;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Zeroable, Pod)]
+    struct Vertex {
+        position: Vec3,
+        color: [u8; 4],
+        id: u32,
+        uv: Vec2,
+    }
+
+    crate::vertex_layout! {
+        Vertex {
+            0 => position,
+            1 => uv,
+            2 => color as Normalized,
+        }
+    }
+
+
+    #[test]
+    fn layout_matches_struct() {
+        assert_eq!(Vertex::STRIDE, size_of::<Vertex>());
+
+        let attributes = Vertex::ATTRIBUTES;
+        let offsets = attributes.iter().map(|attrib| attrib.offset).collect::<Vec<_>>();
+        assert_eq!(offsets, [
+            offset_of!(Vertex, position),
+            offset_of!(Vertex, uv),
+            offset_of!(Vertex, color)
+        ]);
+
+        assert_eq!(attributes[0], VertexAttrib {
+            location: 0,
+            ty: VertexAttribType::Float,
+            size: 3,
+            kind: AttribKind::Float,
+            offset: 0,
+        });
+        assert_eq!(attributes[2].ty, VertexAttribType::UnsignedByte);
+        assert_eq!(attributes[2].kind, AttribKind::Normalized);
+    }
+}
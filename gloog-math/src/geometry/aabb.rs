@@ -0,0 +1,76 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::Vec3;
+
+
+/// An axis-aligned bounding box.
+///
+/// This struct is `repr(C)`, so it is guaranteed to be identical to `[Vec3; 2]` (or `[f32; 6]`), with the minimum
+/// corner first.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Creates a new bounding box from its minimum and maximum corners.
+    #[inline]
+    pub const fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a new bounding box from its center point and its half-extents (the distance from the center to each of
+    /// the box's faces along each axis).
+    #[inline]
+    pub fn from_center(center: Vec3, half_extents: Vec3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Computes the smallest bounding box that contains all of the given points.
+    ///
+    /// Returns `None` if the iterator is empty.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Vec3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        Some(points.fold(Self::new(first, first), |aabb, p| aabb.expand(p)))
+    }
+
+    /// Computes a new bounding box that has been grown just enough to include the given point.
+    #[inline]
+    pub fn expand(&self, point: &Vec3) -> Self {
+        Self {
+            min: Vec3::new(self.min.x.min(point.x), self.min.y.min(point.y), self.min.z.min(point.z)),
+            max: Vec3::new(self.max.x.max(point.x), self.max.y.max(point.y), self.max.z.max(point.z)),
+        }
+    }
+
+    /// Computes the point at the center of this box.
+    #[inline]
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Computes the distance from the center of this box to each of its faces.
+    #[inline]
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Checks whether or not the given point lies inside of (or on the surface of) this box.
+    #[inline]
+    pub fn contains(&self, point: &Vec3) -> bool {
+        (self.min.x <= point.x && point.x <= self.max.x)
+            && (self.min.y <= point.y && point.y <= self.max.y)
+            && (self.min.z <= point.z && point.z <= self.max.z)
+    }
+
+    /// Checks whether or not this box overlaps another. Boxes that are only touching are considered to be overlapping.
+    #[inline]
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        (self.min.x <= other.max.x && other.min.x <= self.max.x)
+            && (self.min.y <= other.max.y && other.min.y <= self.max.y)
+            && (self.min.z <= other.max.z && other.min.z <= self.max.z)
+    }
+}
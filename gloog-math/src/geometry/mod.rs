@@ -1,3 +1,6 @@
+/// Axis-aligned bounding boxes.
+mod aabb;
+
 /// Lines.
 mod line;
 
@@ -7,6 +10,15 @@ mod plane;
 /// Quaternions.
 mod quat;
 
-pub use line::*;
-pub use plane::*;
-pub use quat::*;
+/// Triangles.
+mod triangle;
+
+#[cfg(test)] mod tests;
+
+pub use aabb::*;
+pub use triangle::*;
+
+// These modules are still empty; re-export them once they actually have something in them.
+// pub use line::*;
+// pub use plane::*;
+// pub use quat::*;
@@ -0,0 +1,154 @@
+use crate::Vec3;
+
+fn cmp_f32(a: f32, b: f32) -> bool {
+    let abs_diff = (a - b).abs();
+    abs_diff <= 0.001
+}
+
+fn cmp_vec3(expect: &Vec3, actual: &Vec3) {
+    for i in 0..3 {
+        assert!(cmp_f32(expect[i], actual[i]), "expect != actual --- {expect:?} != {actual:?}");
+    }
+}
+
+mod triangle {
+    use super::super::{Aabb, Triangle};
+    use super::{cmp_f32, cmp_vec3};
+    use crate::{Vec2, Vec3};
+
+    fn right_triangle() -> Triangle {
+        Triangle::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0), Vec3::new(0.0, 3.0, 0.0))
+    }
+
+    #[test]
+    fn area_and_normal() {
+        let tri = right_triangle();
+        assert!(cmp_f32(tri.area(), 6.0));
+        cmp_vec3(&Vec3::UNIT_Z, &tri.normal());
+
+        // Flipping the winding order flips the normal
+        let flipped = Triangle::new(tri.a, tri.c, tri.b);
+        cmp_vec3(&-Vec3::UNIT_Z, &flipped.normal());
+
+        // Degenerate triangles have no normal
+        let line = Triangle::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 2.0, 2.0));
+        assert_eq!(line.normal(), Vec3::default());
+        assert!(cmp_f32(line.area(), 0.0));
+    }
+
+    #[test]
+    fn barycentric() {
+        let tri = Triangle::new(Vec3::new(1.0, -2.0, 0.5), Vec3::new(3.5, 1.0, -1.0), Vec3::new(-2.0, 4.0, 2.0));
+
+        cmp_vec3(&Vec3::new(1.0, 0.0, 0.0), &tri.barycentric(&tri.a).unwrap());
+        cmp_vec3(&Vec3::new(0.0, 1.0, 0.0), &tri.barycentric(&tri.b).unwrap());
+        cmp_vec3(&Vec3::new(0.0, 0.0, 1.0), &tri.barycentric(&tri.c).unwrap());
+
+        let uvw = Vec3::new(0.2, 0.5, 0.3);
+        let p = tri.from_barycentric(&uvw);
+        cmp_vec3(&uvw, &tri.barycentric(&p).unwrap());
+        cmp_vec3(&tri.centroid(), &tri.from_barycentric(&Vec3::new(1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0)));
+
+        let line = Triangle::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(line.barycentric(&Vec3::new(0.5, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn closest_point() {
+        let tri = right_triangle();
+
+        // Above the face: straight down onto it
+        cmp_vec3(&Vec3::new(1.0, 1.0, 0.0), &tri.closest_point(&Vec3::new(1.0, 1.0, 5.0)));
+        // Vertex regions
+        cmp_vec3(&tri.a, &tri.closest_point(&Vec3::new(-1.0, -1.0, 0.0)));
+        cmp_vec3(&tri.b, &tri.closest_point(&Vec3::new(6.0, -1.0, 2.0)));
+        cmp_vec3(&tri.c, &tri.closest_point(&Vec3::new(-1.0, 5.0, -2.0)));
+        // Edge regions
+        cmp_vec3(&Vec3::new(2.0, 0.0, 0.0), &tri.closest_point(&Vec3::new(2.0, -3.0, 1.0)));
+        cmp_vec3(&Vec3::new(0.0, 1.5, 0.0), &tri.closest_point(&Vec3::new(-2.0, 1.5, 0.0)));
+        cmp_vec3(&Vec3::new(2.0, 1.5, 0.0), &tri.closest_point(&Vec3::new(5.0, 5.5, 0.0)));
+    }
+
+    #[test]
+    fn triangle_triangle() {
+        let tri = right_triangle();
+
+        // Crosses straight through the first triangle
+        let crossing = Triangle::new(Vec3::new(1.0, 1.0, -1.0), Vec3::new(1.0, 1.0, 1.0), Vec3::new(1.0, -2.0, 0.0));
+        assert!(tri.intersects(&crossing));
+        assert!(crossing.intersects(&tri));
+
+        // Same shape, but shifted so it only passes by the hypotenuse
+        let near_miss = Triangle::new(Vec3::new(3.0, 3.0, -1.0), Vec3::new(3.0, 3.0, 1.0), Vec3::new(6.0, 3.0, 0.0));
+        assert!(!tri.intersects(&near_miss));
+
+        // Parallel planes
+        let above = Triangle::new(tri.a + Vec3::UNIT_Z, tri.b + Vec3::UNIT_Z, tri.c + Vec3::UNIT_Z);
+        assert!(!tri.intersects(&above));
+
+        // Coplanar: overlapping, and disjoint (but with overlapping bounding boxes)
+        let overlapping = Triangle::new(Vec3::new(1.0, 1.0, 0.0), Vec3::new(5.0, 1.0, 0.0), Vec3::new(1.0, 4.0, 0.0));
+        assert!(tri.intersects(&overlapping));
+        let disjoint = Triangle::new(Vec3::new(4.0, 3.0, 0.0), Vec3::new(3.0, 3.0, 0.0), Vec3::new(4.0, 2.0, 0.0));
+        assert!(!tri.intersects(&disjoint));
+
+        // Sharing only a vertex counts as touching
+        let touching = Triangle::new(tri.b, Vec3::new(5.0, 0.0, 0.0), Vec3::new(5.0, 1.0, 1.0));
+        assert!(tri.intersects(&touching));
+    }
+
+    #[test]
+    fn triangle_aabb() {
+        let tri = right_triangle();
+
+        let unit = Aabb::from_center(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.5, 0.5, 0.5));
+        assert!(tri.intersects_aabb(&unit));
+
+        // Box sits entirely inside the triangle's face
+        let inside = Aabb::from_center(Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.1, 0.1, 0.1));
+        assert!(tri.intersects_aabb(&inside));
+
+        // Box is off the hypotenuse: its bounding box overlaps the triangle's, but the triangle itself misses it
+        let diagonal = Aabb::from_center(Vec3::new(3.5, 2.5, 0.0), Vec3::new(0.25, 0.25, 0.25));
+        assert!(tri.aabb().intersects(&diagonal));
+        assert!(!tri.intersects_aabb(&diagonal));
+
+        // Box is above the triangle's plane
+        let above = Aabb::from_center(Vec3::new(1.0, 1.0, 1.0), Vec3::new(0.5, 0.5, 0.5));
+        assert!(!tri.intersects_aabb(&above));
+    }
+
+    #[test]
+    fn tangents() {
+        let tri = right_triangle();
+        let uvs = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)];
+
+        let (t, b) = tri.tangents(&uvs).unwrap();
+        cmp_vec3(&Vec3::new(4.0, 0.0, 0.0), &t);
+        cmp_vec3(&Vec3::new(0.0, 3.0, 0.0), &b);
+        cmp_vec3(&Vec3::UNIT_X, &t.norm());
+        cmp_vec3(&Vec3::UNIT_Y, &b.norm());
+
+        let flat_uvs = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0)];
+        assert_eq!(tri.tangents(&flat_uvs), None);
+    }
+}
+
+mod aabb {
+    use super::super::Aabb;
+    use super::cmp_vec3;
+    use crate::Vec3;
+
+    #[test]
+    fn from_points() {
+        let points = [Vec3::new(1.0, -2.0, 3.0), Vec3::new(-1.0, 4.0, 0.0), Vec3::new(0.5, 0.5, -5.0)];
+        let aabb = Aabb::from_points(&points).unwrap();
+
+        cmp_vec3(&Vec3::new(-1.0, -2.0, -5.0), &aabb.min);
+        cmp_vec3(&Vec3::new(1.0, 4.0, 3.0), &aabb.max);
+        assert!(points.iter().all(|p| aabb.contains(p)));
+        assert!(!aabb.contains(&Vec3::new(0.0, 0.0, 4.0)));
+
+        assert_eq!(Aabb::from_points(&[]), None);
+    }
+}
@@ -0,0 +1,314 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::Aabb;
+use crate::{Vec2, Vec3};
+
+
+/// A triangle in 3D space, made up of three vertices.
+///
+/// The "front" of a triangle is determined with a counter-clockwise winding order (the same as OpenGL's default), so
+/// its [normal][Self::normal] points in the direction of `(b - a) × (c - a)`.
+///
+/// This struct is `repr(C)`, so it is guaranteed to be identical to `[Vec3; 3]` (or `[f32; 9]`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct Triangle {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
+
+impl Triangle {
+    /// Creates a new triangle.
+    #[inline]
+    pub const fn new(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns this triangle's vertices as an array.
+    #[inline]
+    pub const fn vertices(&self) -> [Vec3; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Returns this triangle's three edge vectors: `b - a`, `c - b`, and `a - c`.
+    #[inline]
+    pub fn edges(&self) -> [Vec3; 3] {
+        [self.b - self.a, self.c - self.b, self.a - self.c]
+    }
+
+    /// Computes the cross product `(b - a) × (c - a)`.
+    ///
+    /// This vector points in the same direction as the triangle's [normal][Self::normal], but its magnitude is twice
+    /// the area of the triangle. This makes it useful for area-weighted averaging of vertex normals.
+    #[inline]
+    pub fn scaled_normal(&self) -> Vec3 {
+        (self.b - self.a).cross(&(self.c - self.a))
+    }
+
+    /// Computes this triangle's unit-length normal vector.
+    ///
+    /// Degenerate triangles (those with zero area) have no well-defined normal; for those, the zero vector is returned
+    /// instead of a vector full of `NaN`s.
+    pub fn normal(&self) -> Vec3 {
+        let n = self.scaled_normal();
+        let mag = n.mag();
+        if mag > 0.0 {
+            n / mag
+        } else {
+            Vec3::default()
+        }
+    }
+
+    /// Computes the area of this triangle.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        self.scaled_normal().mag() * 0.5
+    }
+
+    /// Computes the point at the center of mass of this triangle.
+    #[inline]
+    pub fn centroid(&self) -> Vec3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// Computes the smallest [axis-aligned bounding box][Aabb] that contains this triangle.
+    #[inline]
+    pub fn aabb(&self) -> Aabb {
+        Aabb::new(self.a, self.a).expand(&self.b).expand(&self.c)
+    }
+
+    /// Computes the barycentric coordinates `(u, v, w)` of the given point with respect to this triangle, such that
+    /// `p = u·a + v·b + w·c` and `u + v + w = 1`. They are returned packed into a [`Vec3`] as `(x, y, z) = (u, v, w)`.
+    ///
+    /// If `p` does not lie in the plane of the triangle, the returned coordinates are those of its projection onto that
+    /// plane. Returns `None` if this triangle is degenerate.
+    pub fn barycentric(&self, p: &Vec3) -> Option<Vec3> {
+        // See section 3.4 (p. 47) [Real-Time Collision Detection, Ericson]
+        let v0 = self.b - self.a;
+        let v1 = self.c - self.a;
+        let v2 = p - self.a;
+
+        let d00 = v0.dot(&v0);
+        let d01 = v0.dot(&v1);
+        let d11 = v1.dot(&v1);
+        let d20 = v2.dot(&v0);
+        let d21 = v2.dot(&v1);
+
+        // The denominator is `|v0|²|v1|² sin²θ`; compare against the product of the lengths to remain scale-invariant.
+        let denom = d00 * d11 - d01 * d01;
+        if denom.abs() <= f32::EPSILON * d00 * d11 {
+            return None;
+        }
+
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Some(Vec3::new(1.0 - v - w, v, w))
+    }
+
+    /// Computes the point described by the given barycentric coordinates, `u·a + v·b + w·c`.
+    ///
+    /// This is the inverse of [`barycentric`][Self::barycentric].
+    #[inline]
+    pub fn from_barycentric(&self, uvw: &Vec3) -> Vec3 {
+        self.a * uvw.x + self.b * uvw.y + self.c * uvw.z
+    }
+
+    /// Computes the point on (or inside) this triangle that is closest to the given point.
+    pub fn closest_point(&self, p: &Vec3) -> Vec3 {
+        // See section 5.1.5 (p. 141) [Real-Time Collision Detection, Ericson]. The point is tested against each of the
+        // triangle's Voronoi regions in turn: first the three vertices, then the three edges, and finally the face.
+        let Triangle { a, b, c } = self;
+
+        let ab = b - a;
+        let ac = c - a;
+
+        // Vertex region outside `a`
+        let ap = p - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return *a;
+        }
+
+        // Vertex region outside `b`
+        let bp = p - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return *b;
+        }
+
+        // Edge region of `ab`
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            let v = d1 / (d1 - d3);
+            return a + ab * v;
+        }
+
+        // Vertex region outside `c`
+        let cp = p - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return *c;
+        }
+
+        // Edge region of `ac`
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            let w = d2 / (d2 - d6);
+            return a + ac * w;
+        }
+
+        // Edge region of `bc`
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return b + (c - b) * w;
+        }
+
+        // Inside the face region
+        let denom = 1.0 / (va + vb + vc);
+        let v = vb * denom;
+        let w = vc * denom;
+        a + ab * v + ac * w
+    }
+
+    /// Checks whether or not this triangle overlaps another. Triangles that are only touching are considered to be
+    /// overlapping.
+    ///
+    /// This check is done using the separating axis theorem. Coplanar triangles are supported.
+    pub fn intersects(&self, other: &Triangle) -> bool {
+        let verts_a = self.vertices();
+        let verts_b = other.vertices();
+        let edges_a = self.edges();
+        let edges_b = other.edges();
+        let norm_a = self.scaled_normal();
+        let norm_b = other.scaled_normal();
+
+        let separated_on = |axis: Vec3| {
+            let (min_a, max_a) = project(&axis, &verts_a);
+            let (min_b, max_b) = project(&axis, &verts_b);
+            max_a < min_b || max_b < min_a
+        };
+
+        // Both face normals...
+        if separated_on(norm_a) || separated_on(norm_b) {
+            return false;
+        }
+
+        // ...the cross products of each pair of edges (for triangles that are not coplanar)...
+        for ea in &edges_a {
+            for eb in &edges_b {
+                if let Some(axis) = cross_axis(ea, eb) {
+                    if separated_on(axis) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        // ...and the in-plane normals of each edge (for triangles that are).
+        let in_plane_a = edges_a.iter().filter_map(|e| cross_axis(&norm_a, e));
+        let in_plane_b = edges_b.iter().filter_map(|e| cross_axis(&norm_b, e));
+        !in_plane_a.chain(in_plane_b).any(separated_on)
+    }
+
+    /// Checks whether or not this triangle overlaps the given [axis-aligned bounding box][Aabb]. Triangles that are
+    /// only touching the box are considered to be overlapping.
+    ///
+    /// This check is done using the separating axis theorem, as presented by Tomas Akenine-Möller in "Fast 3D
+    /// Triangle-Box Overlap Testing" (2001).
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        // Move everything so that the box is centered at the origin
+        let center = aabb.center();
+        let extents = aabb.half_extents();
+        let verts = self.vertices().map(|v| v - center);
+
+        let separated_on = |axis: Vec3| {
+            // The box's projection onto the axis is centered at zero, with this radius:
+            let r = extents.x * axis.x.abs() + extents.y * axis.y.abs() + extents.z * axis.z.abs();
+            let (min, max) = project(&axis, &verts);
+            min > r || max < -r
+        };
+
+        // Box's face normals (the coordinate axes)
+        if separated_on(Vec3::UNIT_X) || separated_on(Vec3::UNIT_Y) || separated_on(Vec3::UNIT_Z) {
+            return false;
+        }
+
+        // Triangle's face normal
+        if separated_on(self.scaled_normal()) {
+            return false;
+        }
+
+        // Cross products of the box's edges (the coordinate axes, again) and the triangle's edges
+        let axes = [Vec3::UNIT_X, Vec3::UNIT_Y, Vec3::UNIT_Z];
+        let edges = self.edges();
+        !axes
+            .iter()
+            .flat_map(|u| edges.iter().filter_map(move |e| cross_axis(u, e)))
+            .any(separated_on)
+    }
+
+    /// Computes the tangent and bitangent vectors of this triangle, given texture coordinates for each of its vertices.
+    ///
+    /// The tangent points in the direction of increasing `u` across the surface of the triangle, and the bitangent
+    /// points in the direction of increasing `v`. The returned vectors are **not** normalized or orthogonalized, so
+    /// that they can be summed across all of the triangles that share a vertex before being normalized.
+    ///
+    /// Returns `None` if the texture coordinates are degenerate (they all lie on a line).
+    pub fn tangents(&self, uvs: &[Vec2; 3]) -> Option<(Vec3, Vec3)> {
+        // See section 7.5 (p. 180) [Foundations of Game Development, Vol. 2]
+        let e1 = self.b - self.a;
+        let e2 = self.c - self.a;
+
+        let Vec2 { x: x1, y: y1 } = uvs[1] - uvs[0];
+        let Vec2 { x: x2, y: y2 } = uvs[2] - uvs[0];
+
+        let det = x1 * y2 - x2 * y1;
+        if det == 0.0 {
+            return None;
+        }
+
+        let r = 1.0 / det;
+        let t = (e1 * y2 - e2 * y1) * r;
+        let b = (e2 * x1 - e1 * x2) * r;
+        Some((t, b))
+    }
+}
+
+impl From<[Vec3; 3]> for Triangle {
+    fn from(value: [Vec3; 3]) -> Self {
+        Triangle::new(value[0], value[1], value[2])
+    }
+}
+
+impl From<Triangle> for [Vec3; 3] {
+    fn from(value: Triangle) -> Self {
+        value.vertices()
+    }
+}
+
+
+/// Projects a set of points onto an axis, returning the minimum and maximum distances along it.
+///
+/// The axis does not need to be normalized, since the separating axis tests only ever compare projections along the
+/// same axis with one another.
+fn project(axis: &Vec3, points: &[Vec3]) -> (f32, f32) {
+    points.iter().map(|p| p.dot(axis)).fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), d| {
+        (min.min(d), max.max(d))
+    })
+}
+
+/// Computes the cross product of two vectors to be used as a separating axis, or returns `None` if the vectors are
+/// (nearly) parallel.
+///
+/// Parallel vectors give an axis that is very close to zero, which would make every projection onto it collapse onto
+/// the origin and give false positives or negatives depending on rounding.
+fn cross_axis(u: &Vec3, v: &Vec3) -> Option<Vec3> {
+    let axis = u.cross(v);
+    let threshold = f32::EPSILON * u.mag_sq() * v.mag_sq();
+    (axis.mag_sq() > threshold).then_some(axis)
+}
@@ -9,8 +9,8 @@ mod base;
 // /// - Transformation matrices, built on top of 4D matrices, but with a fourth row of `[0 0 0 1]`.
 // pub mod transforms;
 
-// /// Models for geometric objects such as lines, planes, etc.
-// pub mod geometry;
+/// Models for geometric objects such as lines, planes, triangles, etc.
+pub mod geometry;
 
 
 pub use base::*;
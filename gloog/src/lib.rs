@@ -92,16 +92,18 @@ impl SceneObject {
 
     /// Decodes a colour into an object's ID.
     ///
-    /// See [`encode_id`][Self::encode_id] for the inverse of this function.
+    /// See [`encode_id`][Self::encode_id] for the inverse of this function. To decode an ID that was read back from an
+    /// 8-bit RGBA framebuffer (e.g. with `GLContext::read_pixels_rgba`), divide each byte by 255 to get the colour.
     pub fn decode_id(id_data: Vec4) -> u32 {
         // 1. multiply each value to go from [0, 1] -> [0, 255]
-        // 2. convert to u32, now between 0x00000000 and 0x000000ff
+        // 2. round and convert to u32, now between 0x00000000 and 0x000000ff (rounding is needed because values read
+        //    back from the framebuffer won't always multiply back out to exact integers)
         // 3. subtract from 255 to return to original (eg., `18` would have gone to `237`; `255-237 = 18` again)
         // 4. shift back into place to construct u32
-        let a = 255 - ((id_data.x * 255.0) as u32);
-        let r = 255 - ((id_data.y * 255.0) as u32);
-        let g = 255 - ((id_data.z * 255.0) as u32);
-        let b = 255 - ((id_data.w * 255.0) as u32);
+        let a = 255 - ((id_data.w * 255.0).round() as u32);
+        let r = 255 - ((id_data.x * 255.0).round() as u32);
+        let g = 255 - ((id_data.y * 255.0).round() as u32);
+        let b = 255 - ((id_data.z * 255.0).round() as u32);
         (a << 24) | (r << 16) | (g << 8) | (b << 0)
     }
 
@@ -149,3 +151,22 @@ pub trait RawModelData {
     /// performance and cache data/allocations aggressively.
     fn decompose(self) -> Self::Model;
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_round_trip() {
+        for id in [0, 1, 0xFF_FFFF, 0x1234_5678, u32::MAX] {
+            let colour = SceneObject::encode_id(id);
+            assert_eq!(SceneObject::decode_id(colour), id, "{id:#x} should survive encoding");
+
+            // IDs read back from an 8-bit framebuffer have been through bytes first
+            let bytes = [colour.x, colour.y, colour.z, colour.w].map(|c| (c * 255.0).round() as u8);
+            let [x, y, z, w] = bytes.map(|b| b as f32 / 255.0);
+            assert_eq!(SceneObject::decode_id(Vec4 { x, y, z, w }), id, "{id:#x} should survive an RGBA8 read");
+        }
+    }
+}
@@ -24,6 +24,8 @@ use std::sync::{Arc, Weak};
 use arrayvec::ArrayVec;
 use bytemuck::{Pod, Zeroable};
 use gloog_core::raw::types::GLuint;
+use gloog_core::types::DrawElementsIndirectCommand;
+use gloog_math::geometry::Triangle;
 use gloog_math::{Vec2, Vec3};
 use image::{ImageBuffer, Luma, Rgba};
 use log::{debug, info, log, trace};
@@ -98,6 +100,25 @@ impl ObjModel {
     pub fn groups(&self) -> &[ObjGroup] {
         &self.groups[..]
     }
+
+    /// Builds one indirect draw command per group, so that the whole model can be drawn with a single call to
+    /// [`GLContext::multi_draw_elements_indirect`][gloog_core::GLContext::multi_draw_elements_indirect]. The commands
+    /// are in the same order as [`groups`][Self::groups], and each one's `base_instance` is the index of its group, so
+    /// a shader can look up each group's material with `gl_BaseInstance` even if the commands are split up or
+    /// re-ordered.
+    pub fn draw_commands(&self) -> Vec<DrawElementsIndirectCommand> {
+        self.groups
+            .iter()
+            .enumerate()
+            .map(|(i, group)| DrawElementsIndirectCommand {
+                count: group.index_range.len() as u32,
+                instance_count: 1,
+                first_index: group.index_range.start as u32,
+                base_vertex: 0,
+                base_instance: i as u32,
+            })
+            .collect()
+    }
 }
 
 
@@ -125,11 +146,12 @@ pub struct ObjVertex {
     tex_coord: Vec2,
 }
 
-impl ObjVertex {
-    pub const STRIDE: isize = std::mem::size_of::<ObjVertex>() as isize;
-    pub const OFFSET_POSITION: usize = std::mem::offset_of!(ObjVertex, position);
-    pub const OFFSET_TEX_COORD: usize = std::mem::offset_of!(ObjVertex, tex_coord);
-    pub const OFFSET_NORMAL: usize = std::mem::offset_of!(ObjVertex, normal);
+gloog_core::vertex_layout! {
+    ObjVertex {
+        0 => position,
+        1 => normal,
+        2 => tex_coord,
+    }
 }
 
 /// Used to configure uniforms before executing draw call.
@@ -337,9 +359,7 @@ impl ObjModel {
                 let a = v_data[vert_indices[0].0.get() - 1];
                 let b = v_data[vert_indices[1].0.get() - 1];
                 let c = v_data[vert_indices[2].0.get() - 1];
-                let ab = b - a;
-                let ac = c - a;
-                let norm = ab.cross(&ac);
+                let norm = Triangle::new(a, b, c).normal();
 
                 // All the vertices in this face now have new surface normals; we have to push these into our data list
                 // and update these vertices reference numbers.
@@ -0,0 +1,185 @@
+//! Reads OpenGL traces recorded with `GLContext::start_trace`.
+//!
+//! ```text
+//! gltrace print <trace>        Prints every call in a trace.
+//! gltrace diff <trace> <trace> Prints the calls that differ between two traces.
+//! gltrace replay <trace>       Re-issues a trace in a new window, and shows the result until the window is closed.
+//! ```
+//!
+//! Replaying passes recorded arrays and offsets straight to OpenGL, so only replay traces from programs you trust.
+
+use std::error::Error;
+use std::fs::File;
+use std::process::ExitCode;
+
+use glfw::{Action, Context, Key, OpenGlProfileHint, WindowEvent, WindowHint, WindowMode};
+use gloog_core::trace::{Call, Replayer, TraceReader};
+use gloog_core::{GLContext, InitFailureMode};
+use log::{debug, info, log, warn};
+
+
+/// How far ahead [`diff`] looks for a matching call before treating two calls as changed.
+const DIFF_LOOKAHEAD: usize = 64;
+
+/// The number of replay failures that are logged individually before they're only counted.
+const MAX_LOGGED_FAILURES: usize = 20;
+
+
+pub fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    graphics_2::init_logger();
+
+    let result = match args[..] {
+        ["print", path] => print(path).map(|_| true),
+        ["diff", a, b] => diff(a, b),
+        ["replay", path] => replay(path).map(|_| true),
+        _ => {
+            eprintln!("Usage: gltrace print <trace> | diff <trace> <trace> | replay <trace>");
+            return ExitCode::FAILURE;
+        },
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("encountered error: {e}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+
+fn read_trace(path: &str) -> Result<Vec<Call>, Box<dyn Error>> {
+    let reader = TraceReader::new(File::open(path)?)?;
+    let calls = reader.collect::<Result<Vec<_>, _>>()?;
+    debug!("read {} calls from {path}", calls.len());
+    Ok(calls)
+}
+
+
+fn print(path: &str) -> Result<(), Box<dyn Error>> {
+    // Read calls one at a time, so that big traces can be piped into `less` right away.
+    for (i, call) in TraceReader::new(File::open(path)?)?.enumerate() {
+        println!("{i:>8}  {}", call?);
+    }
+
+    Ok(())
+}
+
+
+/// Prints the differences between two traces, like `diff`. Returns whether they're the same.
+///
+/// Calls are compared by how they print, so pointers that differ between runs don't count as differences, but the
+/// contents of blobs do (by their hash).
+fn diff(path_a: &str, path_b: &str) -> Result<bool, Box<dyn Error>> {
+    let a = read_trace(path_a)?.iter().map(Call::to_string).collect::<Vec<_>>();
+    let b = read_trace(path_b)?.iter().map(Call::to_string).collect::<Vec<_>>();
+
+    let (mut i, mut j) = (0, 0);
+    let mut differences = 0;
+
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        differences += 1;
+
+        // Look for the closest point where the traces line up again: either some calls were removed from `a`, or some
+        // were added in `b`.
+        let removed = (1..=DIFF_LOOKAHEAD).find(|&n| j < b.len() && a.get(i + n) == Some(&b[j]));
+        let added = (1..=DIFF_LOOKAHEAD).find(|&n| i < a.len() && b.get(j + n) == Some(&a[i]));
+
+        match (removed, added) {
+            (Some(n), m) if m.is_none_or(|m| n <= m) => {
+                for (k, call) in a.iter().enumerate().skip(i).take(n) {
+                    println!("-{k:>8}  {call}");
+                }
+                i += n;
+            },
+            (_, Some(n)) => {
+                for (k, call) in b.iter().enumerate().skip(j).take(n) {
+                    println!("+{k:>8}  {call}");
+                }
+                j += n;
+            },
+            _ => {
+                if let Some(call) = a.get(i) {
+                    println!("-{i:>8}  {call}");
+                    i += 1;
+                }
+                if let Some(call) = b.get(j) {
+                    println!("+{j:>8}  {call}");
+                    j += 1;
+                }
+            },
+        }
+    }
+
+    if differences == 0 {
+        info!("traces are identical ({} calls)", a.len());
+    } else {
+        info!("found {differences} differences");
+    }
+
+    Ok(differences == 0)
+}
+
+
+fn replay(path: &str) -> Result<(), Box<dyn Error>> {
+    let calls = read_trace(path)?;
+
+    let mut glfw = glfw::init(glfw::FAIL_ON_ERRORS)?;
+    glfw.window_hint(WindowHint::ContextVersion(4, 6));
+    glfw.window_hint(WindowHint::OpenGlProfile(OpenGlProfileHint::Core));
+    glfw.window_hint(WindowHint::Samples(Some(4)));
+
+    // Traced programs usually make their windows 512×512, so the default viewport should match theirs
+    let (mut window, events) = glfw
+        .create_window(512, 512, &format!("Replay of {path}"), WindowMode::Windowed)
+        .ok_or("could not create the window")?;
+
+    window.set_key_polling(true);
+    window.make_current();
+
+    let mut gl = GLContext::init(|symbol| window.get_proc_address(symbol), InitFailureMode::WarnAndContinue)?;
+    gl.debug_message_callback(|message| log!(message.severity.log_level(), "{}", message.body));
+    let gl = gl; // un-mut
+
+    let mut replayer = Replayer::new(&gl);
+    let (mut replayed, mut skipped, mut failed) = (0, 0, 0);
+
+    for (i, call) in calls.iter().enumerate() {
+        // SAFETY: traces are assumed to come from `GLContext::start_trace`. Replaying one from anywhere else is as
+        // unsafe as running the program that made it.
+        match unsafe { replayer.replay(call) } {
+            Ok(true) => replayed += 1,
+            Ok(false) => skipped += 1,
+            Err(err) => {
+                if failed < MAX_LOGGED_FAILURES {
+                    warn!("could not replay call {i}: {err}");
+                }
+                failed += 1;
+            },
+        }
+    }
+
+    info!("replayed {replayed} calls ({skipped} skipped, {failed} failed)");
+    window.swap_buffers();
+
+    while !window.should_close() {
+        glfw.wait_events();
+        for (_, event) in glfw::flush_messages(&events) {
+            if let WindowEvent::Key(Key::Escape, _, Action::Press, _) = event {
+                window.set_should_close(true);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -2,38 +2,55 @@ use std::error::Error;
 use std::process::ExitCode;
 use std::sync::mpsc::Receiver;
 
+use bytemuck::{Pod, Zeroable};
 use glfw::{Context, Glfw, OpenGlProfileHint, SwapInterval, Window, WindowEvent, WindowHint, WindowMode};
 use gloog::loader;
-use gloog::loader::obj::{ObjModel, ObjVertex};
+use gloog::loader::obj::{ObjGroup, ObjMaterial, ObjModel, ObjVertex};
+use gloog_core::objects::GpuProfiler;
 use gloog_core::types::{
+    BlendState,
     BufferID,
     BufferTarget,
     BufferUsage,
     ClearMask,
+    DepthState,
+    DrawElementsIndirectCommand,
     DrawElementsType,
     DrawMode,
     EnableCap,
+    IndexedBufferTarget,
+    PipelineState,
     ProgramID,
     ShaderType,
-    StringName,
     UniformLocation,
     VertexArrayID,
-    VertexAttribType,
 };
+use gloog_core::vertex::VertexLayout;
 use gloog_core::{GLContext, InitFailureMode};
 use gloog_math::{Mat4, Vec3, Vec4};
 use log::{debug, info, log};
 
 
 pub fn main() -> ExitCode {
-    let Some(model_path) = std::env::args().skip(1).next() else {
+    let mut args = std::env::args().skip(1);
+    let Some(model_path) = args.next() else {
         eprintln!("Missing model filepath");
         return ExitCode::FAILURE;
     };
 
+    // `--trace <file>` records every OpenGL call until F12 is pressed, for reading back with `gltrace`
+    let trace_path = match (args.next().as_deref(), args.next()) {
+        (None, _) => None,
+        (Some("--trace"), Some(path)) => Some(path),
+        _ => {
+            eprintln!("Usage: model-test <model> [--trace <file>]");
+            return ExitCode::FAILURE;
+        },
+    };
+
     graphics_2::init_logger();
 
-    match run(model_path) {
+    match run(model_path, trace_path) {
         Ok(_) => ExitCode::SUCCESS,
         Err(e) => {
             eprintln!("encountered error: {e}");
@@ -43,17 +60,21 @@ pub fn main() -> ExitCode {
 }
 
 
-fn run(model_path: String) -> Result<(), Box<dyn Error>> {
+fn run(model_path: String, trace_path: Option<String>) -> Result<(), Box<dyn Error>> {
     // Attempt to load the model to catch parsing errors before we even bother booting OpenGL
     let model = loader::obj::ObjModel::from_file(model_path, None)?;
 
     let (mut glfw, mut window, events, mut gl) = init_gl()?;
 
+    // Start tracing before anything is created, so that the trace can be replayed on its own
+    if let Some(path) = &trace_path {
+        start_trace(&gl, path)?;
+    }
+
     gl.debug_message_callback(|msg| log::log!(msg.severity.log_level(), "{}", msg.as_str()));
     let gl = gl; // un-mut
 
     gl.clear_color(0.20, 0.20, 0.20, 1.0);
-    gl.enable(EnableCap::DepthTest);
     gl.enable(EnableCap::PrimitiveRestartFixedIndex);
     gl.enable(EnableCap::Multisample);
     gl.enable(EnableCap::DebugOutput);
@@ -65,7 +86,7 @@ fn run(model_path: String) -> Result<(), Box<dyn Error>> {
     let uniforms = AllUniforms::get(&gl, program);
 
     // Now initialize the model's data
-    let mut model = Thingy::init(&gl, &model);
+    let mut model = Thingy::init(&gl, &model, program);
 
     let view_matrix = look_at(&Vec3::new(0.0, 0.5, 2.0), &Vec3::new(0.0, 0.5, 0.0));
     let proj_matrix = perspective(60.0, 1.00, 0.25, 50.0);
@@ -78,6 +99,11 @@ fn run(model_path: String) -> Result<(), Box<dyn Error>> {
         /* ... */
     ];
 
+    let mut profiler = GpuProfiler::new(&gl);
+    let mut last_report = glfw.get_time();
+
+    let mut stop_trace_after_frame = false;
+
     let mut time1 = glfw.get_time() as f32;
     while !window.should_close() {
         gl.clear(ClearMask::COLOR | ClearMask::DEPTH);
@@ -97,9 +123,24 @@ fn run(model_path: String) -> Result<(), Box<dyn Error>> {
             gl.uniform(uniforms.lights[i].position, &lp3_vs);
         }
 
-        model.draw(&view_matrix, &uniforms);
+        model.draw(&view_matrix, &uniforms, &mut profiler);
+        profiler.end_frame();
+
+        if glfw.get_time() - last_report >= 5.0 {
+            for timing in profiler.timings() {
+                debug!("{:indent$}{}: {:?}", "", timing.name, timing.duration, indent = timing.depth * 2);
+            }
+            last_report = glfw.get_time();
+        }
 
         window.swap_buffers();
+
+        // F12 was pressed during the last frame, so this frame is the one that the user wanted to capture
+        if stop_trace_after_frame {
+            stop_trace(&gl)?;
+            stop_trace_after_frame = false;
+        }
+
         glfw.poll_events();
 
         let time2 = (glfw.get_time() % f32::MAX as f64) as f32;
@@ -107,14 +148,52 @@ fn run(model_path: String) -> Result<(), Box<dyn Error>> {
         model.rot.y += (d_time * 15.0).to_radians();
 
         for (_, event) in glfw::flush_messages(&events) {
-            if let WindowEvent::Key(glfw::Key::Escape, _, glfw::Action::Press, _) = event {
-                window.set_should_close(true);
+            match event {
+                WindowEvent::Key(glfw::Key::Escape, _, glfw::Action::Press, _) => window.set_should_close(true),
+                WindowEvent::Key(glfw::Key::F12, _, glfw::Action::Press, _) => stop_trace_after_frame = true,
+                _ => {},
             }
         }
 
         time1 = time2;
     }
 
+    stop_trace(&gl)?;
+    Ok(())
+}
+
+
+/// Starts writing every OpenGL call to a file. Files ending in `.jsonl` are written as JSON-lines, and everything else
+/// is written in the binary format.
+#[cfg(feature = "trace")]
+fn start_trace(gl: &GLContext, path: &str) -> Result<(), Box<dyn Error>> {
+    use gloog_core::trace::TraceFormat;
+
+    let format = if path.ends_with(".jsonl") { TraceFormat::JsonLines } else { TraceFormat::Binary };
+    gl.start_trace(std::fs::File::create(path)?, format);
+
+    info!("tracing OpenGL calls to {path}, press F12 to stop after the next frame");
+    Ok(())
+}
+
+#[cfg(not(feature = "trace"))]
+fn start_trace(_gl: &GLContext, _path: &str) -> Result<(), Box<dyn Error>> {
+    Err("tracing requires building with `--features trace`".into())
+}
+
+/// Stops the trace started by [`start_trace`], if it's still running.
+#[cfg(feature = "trace")]
+fn stop_trace(gl: &GLContext) -> Result<(), Box<dyn Error>> {
+    if gl.is_tracing() {
+        gl.stop_trace()?;
+        info!("trace finished");
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "trace"))]
+fn stop_trace(_gl: &GLContext) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
@@ -148,9 +227,7 @@ fn init_gl() -> Result<(Glfw, Window, Receiver<(f64, WindowEvent)>, GLContext),
         log!(lvl, "{str}");
     });
 
-    let ver = gl.get_string(StringName::Version);
-    let vnd = gl.get_string(StringName::Vendor);
-    debug!("loaded OpenGL version {ver} with vendor {vnd}");
+    debug!("loaded {}", gl.capabilities());
 
     glfw.set_swap_interval(SwapInterval::Sync(1));
     window.set_resizable(false);
@@ -171,35 +248,62 @@ struct Thingy<'gl, 'a> {
     pub pos: Vec3,
     pub rot: Vec3,
     pub scl: Vec3,
-    vao: VertexArrayID,
+    opaque_state: PipelineState,
+    translucent_state: PipelineState,
+    /// How many of the draw commands at the start of `commands` are for opaque groups. The rest are translucent.
+    opaque_draws: usize,
+    materials: BufferID,
+    commands: BufferID,
+    _vao: VertexArrayID,
     _vbo: BufferID,
     _ebo: BufferID,
 }
 
 impl<'gl, 'a> Thingy<'gl, 'a> {
-    fn init(gl: &'gl GLContext, model: &'a ObjModel) -> Self {
+    fn init(gl: &'gl GLContext, model: &'a ObjModel, program: ProgramID) -> Self {
         let vao = gl.create_vertex_array();
-        let buffers = gl.create_buffers(2);
+        let buffers = gl.create_buffers(4);
         let vbo = buffers[0];
         let ebo = buffers[1];
+        let materials = buffers[2];
+        let commands = buffers[3];
 
-        gl.bind_vertex_array(vao);
-
-        gl.bind_buffer(BufferTarget::ArrayBuffer, vbo);
-        gl.buffer_data(BufferTarget::ArrayBuffer, bytemuck::cast_slice(model.vertex_data()), BufferUsage::StaticDraw);
-
-        gl.vertex_attrib_pointer(0, 3, VertexAttribType::Float, false, ObjVertex::STRIDE, ObjVertex::OFFSET_POSITION);
-        gl.vertex_attrib_pointer(1, 3, VertexAttribType::Float, false, ObjVertex::STRIDE, ObjVertex::OFFSET_NORMAL);
-        gl.vertex_attrib_pointer(2, 2, VertexAttribType::Float, false, ObjVertex::STRIDE, ObjVertex::OFFSET_TEX_COORD);
-
-        gl.enable_vertex_attrib_array(0);
-        gl.enable_vertex_attrib_array(1);
-        gl.enable_vertex_attrib_array(2);
-
-        gl.bind_buffer(BufferTarget::ElementArrayBuffer, ebo);
+        gl.named_buffer_data(vbo, bytemuck::cast_slice(model.vertex_data()), BufferUsage::StaticDraw);
         gl.named_buffer_data(ebo, bytemuck::cast_slice(model.index_data()), BufferUsage::StaticDraw);
 
-        gl.unbind_vertex_array();
+        // Every group's material goes into one storage buffer, which the shaders index with each command's base
+        // instance. Opaque groups are drawn before translucent ones, so their commands are moved to the front.
+        let material_data = model.groups().iter().map(|group| MaterialData::new(&group.material));
+        let material_data = material_data.collect::<Vec<_>>();
+        let (opaque, translucent): (Vec<_>, Vec<_>) = model
+            .draw_commands()
+            .into_iter()
+            .zip(model.groups())
+            .partition(|(_, group)| is_opaque(group));
+        let opaque_draws = opaque.len();
+        let command_data = opaque.into_iter().chain(translucent).map(|(command, _)| command);
+        let command_data = command_data.collect::<Vec<_>>();
+
+        gl.named_buffer_data(materials, bytemuck::cast_slice(&material_data), BufferUsage::StaticDraw);
+        gl.named_buffer_data(commands, bytemuck::cast_slice(&command_data), BufferUsage::StaticDraw);
+
+        gl.vertex_array_vertex_buffer(vao, 0, vbo, 0, ObjVertex::STRIDE);
+        gl.vertex_array_layout::<ObjVertex>(vao, 0);
+        gl.vertex_array_element_buffer(vao, ebo);
+
+        gl.object_label(vao, "model vertex array");
+        gl.object_label(vbo, "model vertices");
+        gl.object_label(ebo, "model indices");
+        gl.object_label(materials, "model materials");
+        gl.object_label(commands, "model draw commands");
+
+        let opaque_state = PipelineState::new()
+            .with_program(program)
+            .with_vertex_array(vao)
+            .with_depth(DepthState::LESS);
+
+        // Translucent groups are blended over top of everything else, without letting them hide each other.
+        let translucent_state = opaque_state.with_blend(BlendState::ALPHA).with_depth(DepthState::READ_ONLY);
 
         Self {
             gl,
@@ -207,46 +311,86 @@ impl<'gl, 'a> Thingy<'gl, 'a> {
             pos: Vec3::new(0., 0., 0.),
             rot: Vec3::new(0., 0., 0.),
             scl: Vec3::new(0.5, 0.5, 0.5),
-            vao,
+            opaque_state,
+            translucent_state,
+            opaque_draws,
+            materials,
+            commands,
+            _vao: vao,
             _vbo: vbo,
             _ebo: ebo,
         }
     }
 
-    fn draw(&self, view_matrix: &Mat4, uniforms: &AllUniforms) {
-        let &Self { gl, model, vao, .. } = self;
+    fn draw(&self, view_matrix: &Mat4, uniforms: &AllUniforms, profiler: &mut GpuProfiler) {
+        let &Self { gl, model, .. } = self;
 
         let model_matrix = model_matrix(&self.pos, &self.rot, &self.scl);
         let normal_matrix = (view_matrix * model_matrix).inverse().transpose().to_mat3();
 
+        gl.apply_pipeline_state(&self.opaque_state);
         gl.uniform(uniforms.matrix.model, &model_matrix);
         gl.uniform(uniforms.matrix.normal, &normal_matrix);
 
-        gl.bind_vertex_array(vao);
+        gl.bind_buffer_base(IndexedBufferTarget::ShaderStorageBuffer, 0, self.materials);
+        gl.bind_buffer(BufferTarget::DrawIndirectBuffer, self.commands);
 
-        for group in model.groups() {
-            let diffuse = group.material.diffuse.unwrap_or(Vec3::new(1., 1., 1.));
-            let ambient = group
-                .material
-                .ambient
-                .or(group.material.diffuse.map(|v| v * 0.5))
-                .unwrap_or(Vec3::new(1., 1., 1.));
-            let specular = group.material.specular.unwrap_or(Vec3::new(1., 1., 1.));
-            let spec_pow = group.material.spec_pow.unwrap_or(30.0);
-            let alpha = group.material.alpha.unwrap_or(1.0);
-
-            gl.uniform(uniforms.material.diffuse, &diffuse);
-            gl.uniform(uniforms.material.ambient, &ambient);
-            gl.uniform(uniforms.material.specular, &specular);
-            gl.uniform(uniforms.material.spec_pow, &spec_pow);
-            gl.uniform(uniforms.material.alpha, &alpha);
-
-            let offset = group.indices().start * 4; // !! DrawElements wants a ptr offset not an index offset !!
-            let count = group.indices().count();
-            gl.draw_elements(DrawMode::TriangleFan, count, DrawElementsType::UnsignedInt, offset);
+        // Draw opaque groups first, then translucent ones over top of them.
+        let (mode, ty) = (DrawMode::TriangleFan, DrawElementsType::UnsignedInt);
+        let translucent_draws = model.groups().len() - self.opaque_draws;
+        let translucent_offset = self.opaque_draws * size_of::<DrawElementsIndirectCommand>();
+
+        {
+            let _group = gl.debug_group("opaque");
+            profiler.begin("opaque");
+            gl.multi_draw_elements_indirect(mode, ty, 0, self.opaque_draws, 0);
+            profiler.end();
         }
 
-        gl.unbind_vertex_array();
+        {
+            let _group = gl.debug_group("translucent");
+            profiler.begin("translucent");
+            gl.apply_pipeline_state(&self.translucent_state);
+            gl.multi_draw_elements_indirect(mode, ty, translucent_offset, translucent_draws, 0);
+            profiler.end();
+        }
+
+        // `glClear` respects the depth mask, so depth writes need to be turned back on before the next frame.
+        gl.apply_pipeline_state(&self.opaque_state);
+    }
+}
+
+
+fn is_opaque(group: &ObjGroup) -> bool {
+    group.material.alpha.unwrap_or(1.0) >= 1.0
+}
+
+
+/// A group's material, laid out to match the `Material` struct in `main.frag` under `std430` rules.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct MaterialData {
+    diffuse: Vec3,
+    spec_pow: f32,
+    ambient: Vec3,
+    alpha: f32,
+    specular: Vec3,
+    _padding: f32,
+}
+
+impl MaterialData {
+    pub fn new(material: &ObjMaterial) -> Self {
+        Self {
+            diffuse: material.diffuse.unwrap_or(Vec3::new(1., 1., 1.)),
+            spec_pow: material.spec_pow.unwrap_or(30.0),
+            ambient: material
+                .ambient
+                .or(material.diffuse.map(|v| v * 0.5))
+                .unwrap_or(Vec3::new(1., 1., 1.)),
+            alpha: material.alpha.unwrap_or(1.0),
+            specular: material.specular.unwrap_or(Vec3::new(1., 1., 1.)),
+            _padding: 0.0,
+        }
     }
 }
 
@@ -301,6 +445,7 @@ fn setup_program(gl: &GLContext) -> Result<ProgramID, String> {
     gl.compile_shader(frag)?;
 
     let program = gl.create_program();
+    gl.object_label(program, "model program");
     gl.attach_shader(program, vert);
     gl.attach_shader(program, frag);
 
@@ -407,15 +552,6 @@ fn model_matrix(pos: &Vec3, rot: &Vec3, scl: &Vec3) -> Mat4 {
     translation * rotation * scale
 }
 
-#[derive(Debug, Clone)]
-struct MaterialUniforms {
-    diffuse: UniformLocation,
-    ambient: UniformLocation,
-    specular: UniformLocation,
-    spec_pow: UniformLocation,
-    alpha: UniformLocation,
-}
-
 #[derive(Debug, Clone)]
 struct MatrixUniforms {
     proj: UniformLocation,
@@ -437,7 +573,6 @@ struct LightUniforms {
 #[derive(Debug, Clone)]
 struct AllUniforms {
     matrix: MatrixUniforms,
-    material: MaterialUniforms,
     num_lights: UniformLocation,
     lights: [LightUniforms; 8],
 }
@@ -451,13 +586,6 @@ impl AllUniforms {
                 model: gl.get_uniform_location(program, "uModelMatrix").unwrap_or_default(),
                 normal: gl.get_uniform_location(program, "uNormMatrix").unwrap_or_default(),
             },
-            material: MaterialUniforms {
-                diffuse: gl.get_uniform_location(program, "uMaterial.diffuse").unwrap_or_default(),
-                ambient: gl.get_uniform_location(program, "uMaterial.ambient").unwrap_or_default(),
-                specular: gl.get_uniform_location(program, "uMaterial.specular").unwrap_or_default(),
-                spec_pow: gl.get_uniform_location(program, "uMaterial.specPow").unwrap_or_default(),
-                alpha: gl.get_uniform_location(program, "uMaterial.alpha").unwrap_or_default(),
-            },
             num_lights: gl.get_uniform_location(program, "uNumLights").unwrap_or_default(),
             lights: std::array::from_fn(|i| {
                 let u_pos = format!("uLights[{i}].position");
@@ -5,13 +5,15 @@
 in vec3 vPosition;
 in vec3 vNormal;
 in vec2 vTexCoord;
+flat in int vMaterial;
 
+// Laid out to match `MaterialData` in `main.rs`
 struct Material {
     vec3 diffuse;
-    vec3 ambient;
-    vec3 specular;
     float specPow;
+    vec3 ambient;
     float alpha;
+    vec3 specular;
 };
 
 struct Light {
@@ -21,7 +23,10 @@ struct Light {
     vec3 position;
 };
 
-uniform Material uMaterial;
+layout (std430, binding = 0) readonly buffer Materials {
+    Material uMaterials[];
+};
+
 uniform Light uLights[MAX_LIGHTS];
 uniform int uNumLights = 0;
 
@@ -54,11 +59,12 @@ vec4 blinnPhong(Material material, Light light) {
 
 
 void main() {
+    Material material = uMaterials[vMaterial];
     fColor = vec4(0.0);
 
     for (int i = 0; i < uNumLights && i < MAX_LIGHTS; i++) {
-        fColor += blinnPhong(uMaterial, uLights[i]);
+        fColor += blinnPhong(material, uLights[i]);
     }
 
-    fColor.a = 1.0;
+    fColor.a = material.alpha;
 }
@@ -12,6 +12,7 @@ uniform mat3 uNormMatrix = mat3(1.0);
 out vec3 vPosition;
 out vec3 vNormal;
 out vec2 vTexCoord;
+flat out int vMaterial;
 
 void main() {
     vec4 aPos4 = vec4(aPosition, 1.0);
@@ -19,6 +20,7 @@ void main() {
     vPosition = vec3(uViewMatrix * uModelMatrix * aPos4);
     vNormal = uNormMatrix * aNormal;
     vTexCoord = aTexCoord;
+    vMaterial = gl_BaseInstance; // each group's draw command starts at its own "instance"
 
     gl_Position = uProjMatrix * uViewMatrix * uModelMatrix * aPos4;
 }
@@ -14,8 +14,8 @@ use gloog_math::{Mat4, Vec3, Vec4};
 
 use crate::{scale_matrix, trans_matrix};
 
-#[allow(unused)]
-const MAX_LIGHTS: u32 = 16;
+/// Must match `MAX_LIGHTS` in `teapot.frag`.
+pub const MAX_LIGHTS: usize = 16;
 
 
 pub struct Light<'gl> {
@@ -27,7 +27,6 @@ pub struct Light<'gl> {
     pub position: Vec3,
     pub draw_color: Vec4,
     info: &'static StaticLightInfo,
-    uniforms: LightUniforms,
 }
 
 struct StaticLightInfo {
@@ -39,11 +38,15 @@ struct StaticLightInfo {
     u_projection_matrix: UniformLocation,
 }
 
-struct LightUniforms {
-    diffuse: UniformLocation,
-    ambient: UniformLocation,
-    specular: UniformLocation,
-    position: UniformLocation,
+gloog_core::block_struct! {
+    /// A light as it is laid out in the `LightBlock` uniform block in `teapot.frag`.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct LightData {
+        pub diffuse: Vec4,
+        pub ambient: Vec4,
+        pub specular: Vec4,
+        pub position: Vec3,
+    }
 }
 
 
@@ -59,7 +62,6 @@ impl<'gl> Light<'gl> {
 
     pub fn new(
         gl: &'gl GLContext,
-        program: ProgramID,
         position: Vec3,
         diffuse: Vec4,
         ambient: Vec4,
@@ -68,7 +70,6 @@ impl<'gl> Light<'gl> {
     ) -> Self {
         let info = LIGHT_INFO.get_or_init(|| Self::init(gl));
         let id = Self::next_id();
-        let uniforms = Self::get_uniforms(gl, id, program);
 
         Self {
             gl,
@@ -77,28 +78,12 @@ impl<'gl> Light<'gl> {
             diffuse,
             specular,
             position,
-            uniforms,
             draw_color: draw_color_override.unwrap_or(diffuse),
             info,
         }
     }
 
 
-    fn get_uniforms(gl: &GLContext, id: usize, program: ProgramID) -> LightUniforms {
-        let diffuse = gl.get_uniform_location(program, &format!("lights[{id}].diffuse")).unwrap_or_default();
-        let ambient = gl.get_uniform_location(program, &format!("lights[{id}].ambient")).unwrap_or_default();
-        let specular = gl.get_uniform_location(program, &format!("lights[{id}].specular")).unwrap_or_default();
-        let position = gl.get_uniform_location(program, &format!("lights[{id}].position")).unwrap_or_default();
-
-        LightUniforms {
-            diffuse,
-            ambient,
-            specular,
-            position,
-        }
-    }
-
-
     fn next_id() -> usize {
         let id = NEXT_LIGHT_ID.get_or_init(|| Mutex::new(0));
         let mut id = id.lock().expect("mutex poisoned");
@@ -184,16 +169,17 @@ impl<'gl> Light<'gl> {
         }
     }
 
-    pub fn set_uniforms(&self, view_matrix: &Mat4) {
-        let &Self { gl, ref uniforms, .. } = self;
-
-        gl.uniform(uniforms.diffuse, &self.diffuse);
-        gl.uniform(uniforms.ambient, &self.ambient);
-        gl.uniform(uniforms.specular, &self.specular);
-
+    /// Gets this light's data for the `LightBlock` uniform block, with its position in view space.
+    pub fn block_data(&self, view_matrix: &Mat4) -> LightData {
         let position4 = self.position.to_vec4(1.0);
         let vs_position = (view_matrix * position4).to_vec3();
-        gl.uniform(uniforms.position, &vs_position);
+
+        LightData {
+            diffuse: self.diffuse,
+            ambient: self.ambient,
+            specular: self.specular,
+            position: vs_position,
+        }
     }
 
     pub fn draw(&self, view_matrix: &Mat4, proj_matrix: &Mat4) {
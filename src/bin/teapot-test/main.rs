@@ -6,7 +6,7 @@ use std::fmt::Debug;
 use std::sync::mpsc::Receiver;
 
 use glfw::{Action, Context, Glfw, Key, OpenGlProfileHint, SwapInterval, Window, WindowEvent, WindowHint, WindowMode};
-use gloog_core::types::{ClearMask, DebugFilter, DebugSource, DebugType, EnableCap, ProgramID, ShaderType, StringName};
+use gloog_core::types::{ClearMask, DebugFilter, DebugSource, DebugType, EnableCap, ProgramID, ShaderType};
 use gloog_core::{GLContext, InitFailureMode};
 use gloog_math::{Mat4, Vec3, Vec4};
 use light::Light;
@@ -157,12 +157,10 @@ fn main() {
         })
         .collect::<Vec<_>>();
 
-    let teapot_program = Teapot::program().expect("Teapot has been initialized by now");
     let mut lights = Vec::with_capacity(1 + LIGHT_COLORS.len());
 
     lights.push(Light::new(
         &gl,
-        teapot_program,
         Vec3::new(0.0, 0.0, 0.0),
         color(0xFFFFFF, 0.50),
         color(0x434343, 0.25),
@@ -180,7 +178,6 @@ fn main() {
 
         Light::new(
             &gl,
-            teapot_program,
             pos,
             color(light_color, 0.50),
             color(light_color, 0.25),
@@ -306,9 +303,7 @@ fn setup_window() -> (Glfw, Window, Receiver<(f64, WindowEvent)>, GLContext) {
     let (width, height) = window.get_framebuffer_size();
     gl.viewport(0, 0, width, height);
 
-    let ver = gl.get_string(StringName::Version);
-    let vnd = gl.get_string(StringName::Vendor);
-    info!("loaded OpenGL version {ver} with vendor {vnd}");
+    info!("loaded {}", gl.capabilities());
 
     (glfw, window, events, gl)
 }
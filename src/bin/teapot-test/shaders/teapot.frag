@@ -19,8 +19,11 @@ struct Light {
     vec3 position;
 };
 
+layout(std140, binding = 0) uniform LightBlock {
+    Light lights[MAX_LIGHTS];
+};
+
 uniform Material material;
-uniform Light lights[MAX_LIGHTS];
 uniform int numLights = 0;
 
 out vec4 fColor;
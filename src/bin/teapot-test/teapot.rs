@@ -1,9 +1,13 @@
 use std::sync::OnceLock;
 
+use gloog_core::layout::{MemoryLayout, Std140};
 use gloog_core::types::{
+    BufferID,
+    BufferStorageFlags,
     BufferTarget,
     BufferUsage,
     DrawMode,
+    IndexedBufferTarget,
     ProgramID,
     UniformLocation,
     VertexArrayID,
@@ -14,10 +18,14 @@ use gloog_math::{Mat4, Vec3, Vec4};
 use rand::distributions::Uniform;
 use rand::Rng;
 
-use crate::light::Light;
+use crate::light::{Light, LightData, MAX_LIGHTS};
 use crate::{rotate_matrix, scale_matrix, trans_matrix};
 
 
+/// Must match the `binding` of `LightBlock` in `teapot.frag`.
+const LIGHT_BLOCK_BINDING: u32 = 0;
+
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 struct TeapotVertex {
@@ -56,6 +64,7 @@ pub struct Teapot<'gl> {
 
 struct StaticTeapotInfo {
     program: ProgramID,
+    light_buffer: BufferID,
 
     u_num_lights: UniformLocation,
 
@@ -145,6 +154,10 @@ impl<'gl> Teapot<'gl> {
         const FRAG_SRC: &str = include_str!("./shaders/teapot.frag");
         let program = super::setup_program(gl, VERT_SRC, FRAG_SRC);
 
+        let light_buffer = gl.create_buffer();
+        let light_buffer_size = Std140::size_of::<[LightData; MAX_LIGHTS]>();
+        gl.named_buffer_storage_empty(light_buffer, light_buffer_size, BufferStorageFlags::DYNAMIC_STORAGE);
+
         let u_num_lights = gl.get_uniform_location(program, "numLights").unwrap_or_default();
 
         let u_model_view_matrix = gl.get_uniform_location(program, "uModelViewMatrix").unwrap_or_default();
@@ -158,6 +171,7 @@ impl<'gl> Teapot<'gl> {
 
         StaticTeapotInfo {
             program,
+            light_buffer,
             u_num_lights,
             u_model_view_matrix,
             u_projection_matrix,
@@ -197,17 +211,18 @@ impl<'gl> Teapot<'gl> {
         (vao, model.num_vertices())
     }
 
-    pub fn program() -> Option<ProgramID> {
-        STATIC_INFO.get().map(|info| info.program)
-    }
-
     pub fn pre_draw(gl: &GLContext, view_matrix: &Mat4, lights: &[Light]) {
         let info = STATIC_INFO.get().unwrap();
         gl.use_program(info.program);
         gl.uniform(info.u_num_lights, &(lights.len() as i32));
-        for light in lights {
-            light.set_uniforms(view_matrix);
+
+        let mut light_data = [LightData::default(); MAX_LIGHTS];
+        for (data, light) in light_data.iter_mut().zip(lights) {
+            *data = light.block_data(view_matrix);
         }
+
+        gl.named_buffer_sub_data(info.light_buffer, 0, &Std140::to_bytes(&light_data));
+        gl.bind_buffer_base(IndexedBufferTarget::UniformBuffer, LIGHT_BLOCK_BINDING, info.light_buffer);
     }
 
     pub fn draw(&self, view_matrix: &Mat4, proj_matrix: &Mat4) {